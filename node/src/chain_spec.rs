@@ -4,9 +4,12 @@ use sc_service::ChainType;
 use serde::{Deserialize, Serialize};
 use sp_core::{sr25519, Pair, Public};
 use sp_runtime::traits::{IdentifyAccount, Verify};
-use totem_parachain_runtime::{AccountId, AuraId, Signature, EXISTENTIAL_DEPOSIT};
+use totem_parachain_runtime::{AccountId, AuraId, Balance, Signature, EXISTENTIAL_DEPOSIT};
 
-use totem_primitives::LedgerBalance;
+use totem_primitives::{
+	accounting::{CurrentAssets, Indicator, Ledger, A, B, E},
+	LedgerBalance,
+};
 
 /// Specialized `ChainSpec` for the normal parachain runtime.
 pub type ChainSpec =
@@ -17,6 +20,9 @@ pub type DummyChainSpec = sc_service::GenericChainSpec<(), Extensions>;
 /// The default XCM version to set in genesis config.
 const SAFE_XCM_VERSION: u32 = xcm::prelude::XCM_VERSION;
 
+/// The free balance of each endowed account.
+const ENDOWMENT: Balance = 1 << 60;
+
 /// Helper function to generate a crypto pair from seed
 pub fn get_from_seed<TPublic: Public>(seed: &str) -> <TPublic::Pair as Pair>::Public {
 	TPublic::Pair::from_string(&format!("//{}", seed), None)
@@ -206,7 +212,7 @@ pub fn local_testnet_config() -> ChainSpec {
 fn testnet_genesis(
 	invulnerables: Vec<(AccountId, AuraId)>,
 	endowed_accounts: Vec<AccountId>,
-	opening_balances: Vec<(AccountId, LedgerBalance)>,
+	opening_balances: Vec<(AccountId, Ledger, LedgerBalance, Indicator)>,
 	id: ParaId,
 	root_key: AccountId,
 ) -> totem_parachain_runtime::GenesisConfig {
@@ -218,13 +224,32 @@ fn testnet_genesis(
 		},
 		sudo: totem_parachain_runtime::SudoConfig { key: Some(root_key) },
 		balances: totem_parachain_runtime::BalancesConfig {
-			balances: endowed_accounts.iter().cloned().map(|k| (k, 1 << 60)).collect(),
+			balances: endowed_accounts.iter().cloned().map(|k| (k, ENDOWMENT)).collect(),
 		},
 		accounting: totem_parachain_runtime::AccountingConfig {
-			opening_balances: opening_balances
+			// The endowments open the internal balances, followed by any other opening balances.
+			opening_balances: endowed_accounts
 				.iter()
 				.cloned()
-				.map(|(acc, bal)| (acc, bal))
+				.flat_map(|k| {
+					[
+						(
+							k.clone(),
+							Ledger::BalanceSheet(B::Assets(A::CurrentAssets(
+								CurrentAssets::InternalBalance,
+							))),
+							ENDOWMENT as LedgerBalance,
+							Indicator::Debit,
+						),
+						(
+							k,
+							Ledger::BalanceSheet(B::Equity(E::NetworkReserves)),
+							ENDOWMENT as LedgerBalance,
+							Indicator::Credit,
+						),
+					]
+				})
+				.chain(opening_balances)
 				.collect(),
 		},
 		parachain_info: totem_parachain_runtime::ParachainInfoConfig { parachain_id: id },
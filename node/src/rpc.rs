@@ -7,7 +7,9 @@
 
 use std::sync::Arc;
 
-use totem_parachain_runtime::{opaque::Block, AccountId, Balance, Index as Nonce};
+use totem_parachain_runtime::{
+	opaque::Block, AccountId, Balance, BlockNumber, Hash, Index as Nonce,
+};
 
 use sc_client_api::AuxStore;
 pub use sc_rpc::{DenyUnsafe, SubscriptionTaskExecutor};
@@ -43,9 +45,11 @@ where
 		+ 'static,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
+	C::Api: pallet_accounting_rpc::AccountingRuntimeApi<Block, AccountId, Hash, BlockNumber>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + Sync + Send + 'static,
 {
+	use pallet_accounting_rpc::{Accounting, AccountingApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 
@@ -53,6 +57,7 @@ where
 	let FullDeps { client, pool, deny_unsafe } = deps;
 
 	module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
-	module.merge(TransactionPayment::new(client).into_rpc())?;
+	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(Accounting::new(client).into_rpc())?;
 	Ok(module)
 }
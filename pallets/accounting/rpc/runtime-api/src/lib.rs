@@ -0,0 +1,77 @@
+//                              Næ§@@@ÑÉ©
+//                        æ@@@@@@@@@@@@@@@@@@
+//                    Ñ@@@@?.?@@@@@@@@@@@@@@@@@@@N
+//                 ¶@@@@@?^%@@.=@@@@@@@@@@@@@@@@@@@@
+//               N@@@@@@@?^@@@»^@@@@@@@@@@@@@@@@@@@@@@
+//               @@@@@@@@?^@@@».............?@@@@@@@@@É
+//              Ñ@@@@@@@@?^@@@@@@@@@@@@@@@@@@'?@@@@@@@@Ñ
+//              @@@@@@@@@?^@@@»..............»@@@@@@@@@@
+//              @@@@@@@@@?^@@@»^@@@@@@@@@@@@@@@@@@@@@@@@
+//              @@@@@@@@@?^ë@@&.@@@@@@@@@@@@@@@@@@@@@@@@
+//               @@@@@@@@?^´@@@o.%@@@@@@@@@@@@@@@@@@@@©
+//                @@@@@@@?.´@@@@@ë.........*.±@@@@@@@æ
+//                 @@@@@@@@?´.I@@@@@@@@@@@@@@.&@@@@@N
+//                  N@@@@@@@@@@ë.*=????????=?@@@@@Ñ
+//                    @@@@@@@@@@@@@@@@@@@@@@@@@@@¶
+//                        É@@@@@@@@@@@@@@@@Ñ¶
+//                             Næ§@@@ÑÉ©
+
+// Copyright 2020 Chris D'Costa
+// This file is part of Totem Live Accounting.
+// Authors:
+// - Félix Daudré-Vignier   email: felix@totemaccounting.com
+// - Chris D'Costa          email: chris.dcosta@totemaccounting.com
+
+// Totem is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Totem is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Totem.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the accounting pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::prelude::*;
+
+pub use totem_primitives::{
+	accounting::{Detail, Ledger, TrialBalance, TrialBalanceLine},
+	LedgerBalance, PostingIndex,
+};
+
+sp_api::decl_runtime_apis! {
+	pub trait AccountingApi<AccountId, Hash, BlockNumber> where
+		AccountId: Codec,
+		Hash: Codec,
+		BlockNumber: Codec,
+	{
+		/// The balance of an account on a ledger, if the account has ever posted to it.
+		fn balance(account: AccountId, ledger: Ledger) -> Option<LedgerBalance>;
+
+		/// All the ledgers used by an account, with their balances.
+		fn balances(account: AccountId) -> Vec<(Ledger, LedgerBalance)>;
+
+		/// The trial balance of an account.
+		fn trial_balance(account: AccountId) -> TrialBalance;
+
+		/// The balances of every ledger across all accounts.
+		fn global_ledger() -> Vec<(Ledger, LedgerBalance)>;
+
+		/// The detail of at most `limit` postings of an account to a ledger, following the posting
+		/// `start_after`, in storage order.
+		fn postings(
+			account: AccountId,
+			ledger: Ledger,
+			start_after: Option<PostingIndex>,
+			limit: u32,
+		) -> Vec<(PostingIndex, Detail<AccountId, Hash, BlockNumber>)>;
+	}
+}
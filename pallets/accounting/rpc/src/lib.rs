@@ -0,0 +1,197 @@
+//                              Næ§@@@ÑÉ©
+//                        æ@@@@@@@@@@@@@@@@@@
+//                    Ñ@@@@?.?@@@@@@@@@@@@@@@@@@@N
+//                 ¶@@@@@?^%@@.=@@@@@@@@@@@@@@@@@@@@
+//               N@@@@@@@?^@@@»^@@@@@@@@@@@@@@@@@@@@@@
+//               @@@@@@@@?^@@@».............?@@@@@@@@@É
+//              Ñ@@@@@@@@?^@@@@@@@@@@@@@@@@@@'?@@@@@@@@Ñ
+//              @@@@@@@@@?^@@@»..............»@@@@@@@@@@
+//              @@@@@@@@@?^@@@»^@@@@@@@@@@@@@@@@@@@@@@@@
+//              @@@@@@@@@?^ë@@&.@@@@@@@@@@@@@@@@@@@@@@@@
+//               @@@@@@@@?^´@@@o.%@@@@@@@@@@@@@@@@@@@@©
+//                @@@@@@@?.´@@@@@ë.........*.±@@@@@@@æ
+//                 @@@@@@@@?´.I@@@@@@@@@@@@@@.&@@@@@N
+//                  N@@@@@@@@@@ë.*=????????=?@@@@@Ñ
+//                    @@@@@@@@@@@@@@@@@@@@@@@@@@@¶
+//                        É@@@@@@@@@@@@@@@@Ñ¶
+//                             Næ§@@@ÑÉ©
+
+// Copyright 2020 Chris D'Costa
+// This file is part of Totem Live Accounting.
+// Authors:
+// - Félix Daudré-Vignier   email: felix@totemaccounting.com
+// - Chris D'Costa          email: chris.dcosta@totemaccounting.com
+
+// Totem is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Totem is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Totem.  If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC interface for the accounting pallet.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use pallet_accounting_rpc_runtime_api::AccountingApi as AccountingRuntimeApi;
+use pallet_accounting_rpc_runtime_api::{
+	Detail, Ledger, LedgerBalance, PostingIndex, TrialBalance,
+};
+
+/// The largest number of posting details returned by a single call.
+const MAX_POSTINGS: u32 = 1000;
+
+#[rpc(client, server)]
+pub trait AccountingApi<BlockHash, AccountId, Hash, BlockNumber> {
+	#[method(name = "accounting_balance")]
+	fn balance(
+		&self,
+		account: AccountId,
+		ledger: Ledger,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<LedgerBalance>>;
+
+	#[method(name = "accounting_balances")]
+	fn balances(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(Ledger, LedgerBalance)>>;
+
+	#[method(name = "accounting_trialBalance")]
+	fn trial_balance(&self, account: AccountId, at: Option<BlockHash>) -> RpcResult<TrialBalance>;
+
+	#[method(name = "accounting_globalLedger")]
+	fn global_ledger(&self, at: Option<BlockHash>) -> RpcResult<Vec<(Ledger, LedgerBalance)>>;
+
+	#[method(name = "accounting_postings")]
+	fn postings(
+		&self,
+		account: AccountId,
+		ledger: Ledger,
+		start_after: Option<PostingIndex>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(PostingIndex, Detail<AccountId, Hash, BlockNumber>)>>;
+}
+
+/// Provides RPC methods to query the ledgers, trial balances and posting details.
+pub struct Accounting<C, P> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> Accounting<C, P> {
+	/// Creates a new instance of the Accounting Rpc helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+fn runtime_error(message: &str, e: sp_api::ApiError) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(Error::RuntimeError.into(), message, Some(e.to_string())))
+		.into()
+}
+
+#[async_trait]
+impl<C, Block, AccountId, Hash, BlockNumber>
+	AccountingApiServer<<Block as BlockT>::Hash, AccountId, Hash, BlockNumber> for Accounting<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: AccountingRuntimeApi<Block, AccountId, Hash, BlockNumber>,
+	AccountId: Codec + Send + Sync + 'static,
+	Hash: Codec + Send + Sync + 'static,
+	BlockNumber: Codec + Send + Sync + 'static,
+{
+	fn balance(
+		&self,
+		account: AccountId,
+		ledger: Ledger,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<LedgerBalance>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.balance(&at, account, ledger)
+			.map_err(|e| runtime_error("Unable to query ledger balance.", e))
+	}
+
+	fn balances(
+		&self,
+		account: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<(Ledger, LedgerBalance)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.balances(&at, account)
+			.map_err(|e| runtime_error("Unable to query ledger balances.", e))
+	}
+
+	fn trial_balance(
+		&self,
+		account: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<TrialBalance> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.trial_balance(&at, account)
+			.map_err(|e| runtime_error("Unable to query trial balance.", e))
+	}
+
+	fn global_ledger(&self, at: Option<Block::Hash>) -> RpcResult<Vec<(Ledger, LedgerBalance)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.global_ledger(&at)
+			.map_err(|e| runtime_error("Unable to query global ledger.", e))
+	}
+
+	fn postings(
+		&self,
+		account: AccountId,
+		ledger: Ledger,
+		start_after: Option<PostingIndex>,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<(PostingIndex, Detail<AccountId, Hash, BlockNumber>)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.postings(&at, account, ledger, start_after, limit.min(MAX_POSTINGS))
+			.map_err(|e| runtime_error("Unable to query posting details.", e))
+	}
+}
@@ -1,22 +1,252 @@
-#![cfg(feature = "runtime-benchmarks")]
+//                              Næ§@@@ÑÉ©
+//                        æ@@@@@@@@@@@@@@@@@@
+//                    Ñ@@@@?.?@@@@@@@@@@@@@@@@@@@N
+//                 ¶@@@@@?^%@@.=@@@@@@@@@@@@@@@@@@@@
+//               N@@@@@@@?^@@@»^@@@@@@@@@@@@@@@@@@@@@@
+//               @@@@@@@@?^@@@».............?@@@@@@@@@É
+//              Ñ@@@@@@@@?^@@@@@@@@@@@@@@@@@@'?@@@@@@@@Ñ
+//              @@@@@@@@@?^@@@»..............»@@@@@@@@@@
+//              @@@@@@@@@?^@@@»^@@@@@@@@@@@@@@@@@@@@@@@@
+//              @@@@@@@@@?^ë@@&.@@@@@@@@@@@@@@@@@@@@@@@@
+//               @@@@@@@@?^´@@@o.%@@@@@@@@@@@@@@@@@@@@©
+//                @@@@@@@?.´@@@@@ë.........*.±@@@@@@@æ
+//                 @@@@@@@@?´.I@@@@@@@@@@@@@@.&@@@@@N
+//                  N@@@@@@@@@@ë.*=????????=?@@@@@Ñ
+//                    @@@@@@@@@@@@@@@@@@@@@@@@@@@¶
+//                        É@@@@@@@@@@@@@@@@Ñ¶
+//                             Næ§@@@ÑÉ©
+
+// Copyright 2020 Chris D'Costa
+// This file is part of Totem Live Accounting.
+// Authors:
+// - Félix Daudré-Vignier   email: felix@totemaccounting.com
+// - Chris D'Costa          email: chris.dcosta@totemaccounting.com
+
+// Totem is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Totem is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
 
-//! Benchmarking setup for pallet-template
+// You should have received a copy of the GNU General Public License
+// along with Totem.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmarks for the accounting pallet.
+
+#![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
 
-#[allow(unused)]
-use crate::Pallet as Template;
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use crate::Pallet as Accounting;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::BoundedVec;
 use frame_system::RawOrigin;
+use sp_std::prelude::*;
+use totem_primitives::{accounting::*, LedgerBalance};
+
+const SEED: u32 = 0;
+/// The largest number of records benchmarked in one posting.
+const MAX_RECORDS: u32 = 100;
+
+fn internal_balance() -> Ledger {
+	Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::InternalBalance)))
+}
+
+fn network_reserves() -> Ledger {
+	Ledger::BalanceSheet(B::Equity(E::NetworkReserves))
+}
+
+fn accrued_expenses() -> Ledger {
+	Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(
+		CurrentLiabilities::OtherAccruedExpensesPayable,
+	)))
+}
+
+/// `count` balanced records, each pair on its own account so that every record touches its own
+/// balances. With an odd count the first account posts its debit against two credits.
+fn balanced_records<T: Config>(count: u32) -> Vec<Record<T::AccountId, T::Hash, T::BlockNumber>> {
+	let current_block = frame_system::Pallet::<T>::block_number();
+	let record = |party: u32, ledger: Ledger, amount: LedgerBalance, debit_credit: Indicator| {
+		let party: T::AccountId = account("party", party, SEED);
+		Record {
+			primary_party: party.clone(),
+			counterparty: party,
+			ledger,
+			amount,
+			debit_credit,
+			reference_hash: Default::default(),
+			changed_on_blocknumber: current_block,
+			applicable_period_blocknumber: current_block,
+		}
+	};
+
+	let mut records = (0..count / 2)
+		.flat_map(|party| {
+			[
+				record(party, internal_balance(), 10, Indicator::Debit),
+				record(party, network_reserves(), 10, Indicator::Credit),
+			]
+		})
+		.collect::<Vec<_>>();
+	if count % 2 == 1 {
+		records[0].amount = 20;
+		records.push(record(
+			0,
+			Ledger::BalanceSheet(B::Equity(E::BalanceAdjustmentSuspense)),
+			10,
+			Indicator::Credit,
+		));
+	}
+
+	records
+}
+
+/// `count` balanced journal lines: expenses on distinct ledgers, accrued in one line.
+fn journal_lines<T: Config>(
+	count: u32,
+) -> BoundedVec<JournalLine<T::AccountId, T::BlockNumber>, T::MaxJournalLines> {
+	let current_block = frame_system::Pallet::<T>::block_number();
+	let line = |ledger: Ledger, amount: LedgerBalance, debit_credit: Indicator| JournalLine {
+		ledger,
+		counterparty: account("counterparty", 0, SEED),
+		amount,
+		debit_credit,
+		applicable_period_blocknumber: current_block,
+	};
+
+	let expenses = count.saturating_sub(1);
+	let mut lines = LEDGERS
+		.iter()
+		.filter(|ledger| matches!(ledger, Ledger::ProfitLoss(P::Expenses(_))))
+		.take(expenses as usize)
+		.map(|ledger| line(*ledger, 10, Indicator::Debit))
+		.collect::<Vec<_>>();
+	lines.push(line(accrued_expenses(), 10 * expenses as LedgerBalance, Indicator::Credit));
+
+	lines
+		.try_into()
+		.expect("the benchmarks do not exceed the maximum number of lines")
+}
+
+fn set_fiscal_calendar<T: Config>(who: &T::AccountId) {
+	FiscalCalendars::<T>::insert(
+		who,
+		FiscalCalendar {
+			period_length: 10u32.into(),
+			year_end: 10u32.into(),
+			periods_per_year: 12,
+		},
+	);
+}
 
 benchmarks! {
-	do_something {
-		let s in 0 .. 100;
+	handle_multiposting_amounts {
+		let r in 2 .. MAX_RECORDS;
+		let records = balanced_records::<T>(r);
+	}: {
+		Accounting::<T>::handle_multiposting_amounts(
+			&records,
+			PostingSource::Pallet { pallet_index: 0, call_index: 0 },
+		)?;
+	}
+	verify {
+		assert_eq!(PostingNumber::<T>::get(), 1);
+	}
+
+	post_journal_entry {
+		let l in 2 .. T::MaxJournalLines::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let lines = journal_lines::<T>(l);
+	}: _(RawOrigin::Signed(caller.clone()), lines)
+	verify {
+		assert_eq!(PostingNumber::<T>::get(), 1);
+	}
+
+	reverse_posting {
+		let l in 2 .. T::MaxJournalLines::get();
+		let caller: T::AccountId = whitelisted_caller();
+		Accounting::<T>::post_journal_entry(
+			RawOrigin::Signed(caller.clone()).into(),
+			journal_lines::<T>(l),
+		)?;
+	}: _(RawOrigin::Signed(caller.clone()), 1, l)
+	verify {
+		assert!(ReversedBy::<T>::contains_key(&caller, 1));
+	}
+
+	set_fiscal_calendar {
+		let caller: T::AccountId = whitelisted_caller();
+		let calendar = FiscalCalendar { period_length: 10u32.into(), year_end: 10u32.into(), periods_per_year: 12 };
+	}: _(RawOrigin::Signed(caller.clone()), calendar)
+	verify {
+		assert_eq!(FiscalCalendars::<T>::get(&caller), Some(calendar));
+	}
+
+	close_period {
 		let caller: T::AccountId = whitelisted_caller();
-	}: _(RawOrigin::Signed(caller), s)
+		set_fiscal_calendar::<T>(&caller);
+		frame_system::Pallet::<T>::set_block_number(20u32.into());
+	}: _(RawOrigin::Signed(caller.clone()), 5u32.into())
+	verify {
+		assert_eq!(ClosedUntil::<T>::get(&caller), Some(10u32.into()));
+	}
+
+	reopen_period {
+		let caller: T::AccountId = whitelisted_caller();
+		set_fiscal_calendar::<T>(&caller);
+		frame_system::Pallet::<T>::set_block_number(20u32.into());
+		Accounting::<T>::close_period(RawOrigin::Signed(caller.clone()).into(), 5u32.into())?;
+	}: _(RawOrigin::Signed(caller.clone()), 5u32.into())
+	verify {
+		assert!(ReopenedPeriods::<T>::contains_key(&caller, T::BlockNumber::from(10u32)));
+	}
+
+	close_year {
+		let l in 2 .. T::MaxJournalLines::get();
+		let caller: T::AccountId = whitelisted_caller();
+		set_fiscal_calendar::<T>(&caller);
+		frame_system::Pallet::<T>::set_block_number(5u32.into());
+		Accounting::<T>::post_journal_entry(
+			RawOrigin::Signed(caller.clone()).into(),
+			journal_lines::<T>(l),
+		)?;
+		frame_system::Pallet::<T>::set_block_number(20u32.into());
+	}: _(RawOrigin::Signed(caller.clone()), 5u32.into(), l)
+	verify {
+		assert!(YearEndResults::<T>::contains_key(&caller, T::BlockNumber::from(10u32)));
+	}
+
+	retry_failed_posting {
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		// Queued first, so that the failed posting is counted in the deferred changes of the beneficiary.
+		Accounting::<T>::defer_posting(
+			DeferredPosting::Unreserve { beneficiary: beneficiary.clone(), amount: 10u32.into() },
+		);
+		let posting = DeferredPostings::<T>::take(0).expect("the posting has just been deferred");
+		FailedPostings::<T>::insert(0, posting);
+		FailedPostingCount::<T>::put(1);
+	}: _(RawOrigin::Root, 0)
+	verify {
+		assert_eq!(FailedPostingCount::<T>::get(), 0);
+		assert!(!DeferredChanges::<T>::contains_key(&beneficiary));
+	}
+	open_books {
+		let l in 2 .. T::MaxJournalLines::get();
+		let who: T::AccountId = account("company", 0, SEED);
+		let lines: BoundedVec<_, T::MaxJournalLines> = journal_lines::<T>(l)
+			.into_iter()
+			.map(|line| (line.ledger, line.amount, line.debit_credit))
+			.collect::<Vec<_>>()
+			.try_into()
+			.expect("the benchmarks do not exceed the maximum number of lines");
+	}: _(RawOrigin::Root, who.clone(), lines)
 	verify {
-		assert_eq!(Something::<T>::get(), Some(s));
+		assert_eq!(OpenedBooks::<T>::get(&who), Some(1));
 	}
 }
 
-impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test,);
+impl_benchmark_test_suite!(Accounting, crate::mock::new_test_ext(), crate::mock::Test);
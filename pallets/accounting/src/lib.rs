@@ -58,11 +58,19 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-//pub mod benchmarking;
-//pub mod mock;
-//pub mod tests;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod migration;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+#[cfg(test)]
+mod tests_model;
+pub mod weights;
 
 pub use pallet::*;
+pub use weights::WeightInfo;
 
 #[frame_support::pallet]
 mod pallet {
@@ -71,11 +79,15 @@ mod pallet {
 		dispatch::DispatchResult,
 		fail,
 		pallet_prelude::*,
-		traits::{Currency, Randomness, StorageVersion},
+		storage::{with_transaction, TransactionOutcome},
+		traits::{Currency, StorageVersion},
 	};
 	use frame_system::pallet_prelude::*;
-	use sp_runtime::traits::{Convert, Hash, Zero};
-	use sp_std::prelude::*;
+	use sp_runtime::traits::{Convert, Hash, One, Saturating, Zero};
+	use sp_std::{
+		collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+		prelude::*,
+	};
 
 	use totem_common::TryConvert;
 	use totem_primitives::accounting::*;
@@ -84,9 +96,11 @@ mod pallet {
 
 	type CurrencyBalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	type DeferredPostingOf<T> =
+		DeferredPosting<<T as frame_system::Config>::AccountId, CurrencyBalanceOf<T>>;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
@@ -138,11 +152,147 @@ mod pallet {
 	pub type GlobalLedger<T: Config> =
 		StorageMap<_, Blake2_128Concat, Ledger, LedgerBalance, ValueQuery>;
 
+	/// Where on chain each posting was made, and by which call.
+	#[pallet::storage]
+	#[pallet::getter(fn posting_reference)]
+	pub type PostingReferences<T: Config> =
+		StorageMap<_, Blake2_128Concat, PostingIndex, PostingReference<T::BlockNumber>>;
+
+	/// The postings whose lines carry a reference hash.
+	/// Keys: Reference Hash, Posting Index
+	#[pallet::storage]
+	pub type PostingsByReference<T: Config> =
+		StorageDoubleMap<_, Identity, T::Hash, Blake2_128Concat, PostingIndex, ()>;
+
+	/// The postings made by an extrinsic.
+	/// Keys: (Block Number, Extrinsic Index), Posting Index
+	#[pallet::storage]
+	pub type PostingsByExtrinsic<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(T::BlockNumber, u32),
+		Blake2_128Concat,
+		PostingIndex,
+		(),
+	>;
+
+	/// The reversal posted for a posting, by the account whose lines were reversed.
+	/// Keys: AccountId, Posting Index of the reversed posting
+	#[pallet::storage]
+	#[pallet::getter(fn reversed_by)]
+	pub type ReversedBy<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		PostingIndex,
+		PostingIndex,
+	>;
+
+	/// The posting reversed by a reversal, by the account whose lines were reversed.
+	/// Keys: AccountId, Posting Index of the reversal
+	#[pallet::storage]
+	#[pallet::getter(fn reversal_of)]
+	pub type ReversalOf<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		PostingIndex,
+		PostingIndex,
+	>;
+
+	/// The fiscal calendar declared by an account.
+	#[pallet::storage]
+	#[pallet::getter(fn fiscal_calendar)]
+	pub type FiscalCalendars<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, FiscalCalendar<T::BlockNumber>>;
+
+	/// The last block of the latest period closed by an account.
+	/// Every period ending on or before this block is closed, unless it has been reopened.
+	#[pallet::storage]
+	#[pallet::getter(fn closed_until)]
+	pub type ClosedUntil<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::BlockNumber>;
+
+	/// Closed periods that have been reopened for posting.
+	/// Keys: AccountId, last block of the period
+	#[pallet::storage]
+	#[pallet::getter(fn reopened_period)]
+	pub type ReopenedPeriods<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::BlockNumber, ()>;
+
+	/// Balances of the profit and loss ledgers of an account before its year-end close.
+	/// Keys: (AccountId, last block of the year), Ledger
+	#[pallet::storage]
+	#[pallet::getter(fn year_end_balance)]
+	pub type YearEndBalances<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(T::AccountId, T::BlockNumber),
+		Blake2_128Concat,
+		Ledger,
+		LedgerBalance,
+	>;
+
+	/// Net profit (or loss, when negative) booked to retained earnings by a year-end close.
+	/// Keys: AccountId, last block of the year
+	#[pallet::storage]
+	#[pallet::getter(fn year_end_result)]
+	pub type YearEndResults<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::BlockNumber,
+		LedgerBalance,
+	>;
+
+	/// The index of the next deferred posting.
+	#[pallet::storage]
+	pub type NextDeferredIndex<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// Recipes waiting to be posted in `on_idle`, by deferred index.
+	#[pallet::storage]
+	#[pallet::getter(fn deferred_posting)]
+	pub type DeferredPostings<T: Config> = StorageMap<_, Twox64Concat, u64, DeferredPostingOf<T>>;
+
+	/// The changes that the queued and failed deferred recipes of an account will make to its internal balance
+	/// and to its internal reserved balance, once they are posted.
+	#[pallet::storage]
+	#[pallet::getter(fn deferred_changes)]
+	pub type DeferredChanges<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (LedgerBalance, LedgerBalance), ValueQuery>;
+
+	/// Deferred recipes that could not be posted in `on_idle`, by deferred index.
+	/// They stay here, and in the deferred changes of their beneficiary, until they are retried with
+	/// `retry_failed_posting`.
+	#[pallet::storage]
+	#[pallet::getter(fn failed_posting)]
+	pub type FailedPostings<T: Config> = StorageMap<_, Twox64Concat, u64, DeferredPostingOf<T>>;
+
+	/// The number of deferred recipes that could not be posted.
+	#[pallet::storage]
+	#[pallet::getter(fn failed_posting_count)]
+	pub type FailedPostingCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The reconciliation of the internal balance ledgers with the balances, while it is in progress.
+	/// It is started by a runtime upgrade and continued in `on_idle`.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_reconciliation)]
+	pub type PendingReconciliation<T: Config> = StorageValue<_, Reconciliation<T::AccountId>>;
+
+	/// The posting index of the opening balances of an account, once its books have been opened.
+	#[pallet::storage]
+	#[pallet::getter(fn opened_books)]
+	pub type OpenedBooks<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, PostingIndex>;
+
 	// The genesis config type.
-	// The Balances here should be exactly the same as configured in the Balances Pallet to set the opening balances correctly
+	// Each line is the balance of a ledger of an account and the side it stands on, as in a trial balance. The
+	// lines of each account must balance, and its internal balance must be exactly the free balance configured in
+	// the Balances Pallet.
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
-		pub opening_balances: Vec<(T::AccountId, LedgerBalance)>,
+		pub opening_balances: Vec<(T::AccountId, Ledger, LedgerBalance, Indicator)>,
 	}
 
 	// The default value for the genesis config type.
@@ -160,67 +310,82 @@ mod pallet {
 			let reference_hash: T::Hash = T::Hashing::hash(input.encode().as_slice());
 			let block_number: T::BlockNumber = 0u32.into();
 			let posting_index: PostingIndex = 0;
-			// Reserves is a Debit Balance Account
-			let mint_to: Ledger =
-				Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::InternalBalance)));
-			// Reserves is a Credit Balance Account
-			let account_for: Ledger = Ledger::BalanceSheet(B::Equity(E::NetworkReserves));
-			let total = self
+
+			let keys = self
 				.opening_balances
 				.iter()
-				.fold(Zero::zero(), |account_balance: LedgerBalance, &(_, n)| account_balance + n);
+				.map(|(account, ledger, amount, debit_credit)| {
+					Pallet::<T>::opening_record(
+						account.clone(),
+						*ledger,
+						*amount,
+						*debit_credit,
+						reference_hash,
+						block_number,
+					)
+					.expect("the opening balances cannot be negative")
+				})
+				.collect::<Vec<_>>();
+			Pallet::<T>::ensure_parties_balanced(&keys)
+				.expect("the opening balances of each account must balance");
+			Pallet::<T>::ensure_distinct_ledgers(&keys)
+				.expect("an account can only open each ledger once");
 
 			<PostingNumber<T>>::put(&posting_index);
-			<GlobalLedger<T>>::insert(&mint_to, total.clone());
-			<GlobalLedger<T>>::insert(&account_for, total);
-
-			for (address, balance) in &self.opening_balances {
-				let account_balance_key_debit = (address.clone(), mint_to.clone());
-				let account_balance_key_credit = (address.clone(), account_for.clone());
-
-				let detail_debit = Detail {
-					counterparty: address.clone(),
-					amount: balance.clone(),
-					debit_credit: Indicator::Debit,
-					reference_hash,
-					changed_on_blocknumber: block_number,
-					applicable_period_blocknumber: block_number,
-				};
-
-				let detail_credit = Detail {
-					counterparty: address.clone(),
-					amount: balance.clone(),
-					debit_credit: Indicator::Credit,
-					reference_hash,
-					changed_on_blocknumber: block_number,
-					applicable_period_blocknumber: block_number,
-				};
+			for key in keys.iter().cloned() {
+				<OpenedBooks<T>>::insert(&key.primary_party, posting_index);
+				Pallet::<T>::post_amounts(key, posting_index)
+					.expect("the opening balances cannot overflow");
+			}
 
-				<BalanceByLedger<T>>::insert(&address, &mint_to, balance.clone());
-				<BalanceByLedger<T>>::insert(&address, &account_for, balance);
-				<PostingDetail<T>>::insert(
-					&account_balance_key_debit,
-					&posting_index,
-					detail_debit,
-				);
-				<PostingDetail<T>>::insert(
-					&account_balance_key_credit,
-					&posting_index,
-					detail_credit,
-				);
+			// The Balances Pallet builds its genesis first, so the internal balances can be checked against it.
+			let internal_balance =
+				Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::InternalBalance)));
+			let internal_reserved_balance = Ledger::BalanceSheet(B::Assets(A::CurrentAssets(
+				CurrentAssets::InternalReservedBalance,
+			)));
+			let accounts = frame_system::Account::<T>::iter_keys()
+				.chain(keys.into_iter().map(|key| key.primary_party))
+				.collect::<BTreeSet<_>>();
+			for who in accounts {
+				let free = T::Currency::free_balance(&who);
+				let reserved = T::Currency::total_balance(&who).saturating_sub(free);
+				for (ledger, balance) in
+					[(internal_balance, free), (internal_reserved_balance, reserved)]
+				{
+					let balance = T::AccountingConverter::try_convert(balance)
+						.expect("the balances fit in a ledger balance");
+					assert!(
+						<BalanceByLedger<T>>::get(&who, &ledger).unwrap_or_default() == balance,
+						"the opening internal balances of an account must be its balances",
+					);
+				}
 			}
 		}
 	}
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
-		// type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
-		// type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		type AccountingConverter: TryConvert<CurrencyBalanceOf<Self>, LedgerBalance>
 			+ Convert<[u8; 32], Self::AccountId>;
 		type Currency: Currency<Self::AccountId>;
-		type RandomThing: Randomness<Self::Hash, Self::BlockNumber>;
+		/// When set, the debits must equal the credits for the records of each primary party in a posting,
+		/// not only for the posting as a whole.
+		#[pallet::constant]
+		type EnforcePartyBalance: Get<bool>;
+		/// The maximum number of lines in a manual journal entry.
+		#[pallet::constant]
+		type MaxJournalLines: Get<u32>;
+		/// The maximum number of deferred postings attempted in one block.
+		#[pallet::constant]
+		type MaxDeferredPostingsPerBlock: Get<u32>;
+		/// The maximum number of accounts reconciled in one block.
+		#[pallet::constant]
+		type MaxReconciledAccountsPerBlock: Get<u32>;
+		/// Weight information for the extrinsics and the postings of this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::error]
@@ -233,30 +398,434 @@ mod pallet {
 		SystemFailure,
 		/// Overflow error, amount too big.
 		AmountOverflow,
+		/// The total of the debits does not equal the total of the credits.
+		UnbalancedPosting,
+		/// The debits do not equal the credits for one of the parties in the posting.
+		UnbalancedPartyPosting,
+		/// A journal entry must have at least one line.
+		EmptyJournalEntry,
+		/// The ledger is maintained by the network and cannot be posted to manually.
+		ProtocolLedger,
+		/// The amount of a journal line cannot be negative: its side gives the direction of the line.
+		InvalidJournalAmount,
+		/// There are no lines for this account under the posting index.
+		UnknownPosting,
+		/// The posting has already been reversed.
+		AlreadyReversed,
+		/// The period length and the number of periods in a year of a fiscal calendar cannot be zero.
+		InvalidFiscalCalendar,
+		/// The fiscal calendar cannot be changed once a period has been closed.
+		FiscalCalendarLocked,
+		/// The account has not declared a fiscal calendar.
+		NoFiscalCalendar,
+		/// The period has not ended yet.
+		PeriodNotEnded,
+		/// The period is already closed.
+		PeriodAlreadyClosed,
+		/// The period is not closed.
+		PeriodNotClosed,
+		/// The posting falls into a closed period.
+		PeriodClosed,
+		/// The year has already been closed.
+		YearAlreadyClosed,
+		/// The period is not the last period of a fiscal year.
+		NotYearEnd,
+		/// The account has posted to more ledgers than the count given for the weight of the call.
+		InvalidLedgerCount,
+		/// There is no failed posting under this deferred index.
+		UnknownFailedPosting,
+		/// An opening balance cannot be negative: a balance against the normal side of its ledger stands on
+		/// the other side.
+		InvalidOpeningBalance,
+		/// The books of the account have already been opened.
+		BooksAlreadyOpened,
+		/// A posting cannot have more than one line for the same ledger of a party.
+		DuplicateLedger,
 	}
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
-
-	// #[pallet::call]
-	// impl<T: Config> Pallet<T> {
-	//     #[pallet::weight(0/*TODO*/)]
-	//     pub fn opening_balance(_origin: OriginFor<T>) -> DispatchResultWithPostInfo {
-	//         todo!()
-	//     }
-
-	//     #[pallet::weight(0/*TODO*/)]
-	//     pub fn adjustment(_origin: OriginFor<T>) -> DispatchResultWithPostInfo {
-	//         todo!()
-	//     }
-	// }
-
-	// Temporarily removed until events are re-added
-	// #[pallet::event]
-	// #[pallet::generate_deposit(pub(super) fn deposit_event)]
-	// pub enum Event<T: Config> {
-	// 	LegderUpdate(<T as frame_system::Config>::AccountId, Ledger, LedgerBalance, PostingIndex),
-	// }
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Posts the deferred recipes, then continues the pending reconciliation, as far as the remaining
+		/// weight allows.
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut consumed = T::DbWeight::get().reads(1);
+			if !consumed.all_lte(remaining_weight) {
+				return Weight::zero()
+			}
+
+			let indexes = DeferredPostings::<T>::iter_keys()
+				.take(T::MaxDeferredPostingsPerBlock::get() as usize)
+				.collect::<Vec<_>>();
+			for index in indexes {
+				let next = consumed.saturating_add(Self::deferred_posting_weight());
+				if !next.all_lte(remaining_weight) {
+					break
+				}
+				consumed = next;
+
+				if let Some(posting) = DeferredPostings::<T>::take(index) {
+					match Self::post_deferred(posting.clone()) {
+						Ok(()) => {
+							Self::track_deferred_changes(&posting, false);
+							Self::deposit_event(Event::DeferredPostingPosted { index });
+						},
+						Err(error) => {
+							FailedPostings::<T>::insert(index, posting);
+							FailedPostingCount::<T>::mutate(|count| {
+								*count = count.saturating_add(1)
+							});
+							Self::deposit_event(Event::DeferredPostingFailed { index, error });
+						},
+					}
+				}
+			}
+
+			consumed.saturating_add(Self::reconcile_accounts(
+				T::MaxReconciledAccountsPerBlock::get(),
+				remaining_weight.saturating_sub(consumed),
+			))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), &'static str> {
+			Self::do_try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Posts a manual journal entry to the books of the caller, for example an invoice received, an
+		/// accrual or depreciation.
+		///
+		/// The lines must balance, and cannot be posted to the ledgers that are only maintained by the
+		/// network (the internal balances and the network reserves). The amount of a line cannot be negative:
+		/// a line increases its ledger on the normal side of the ledger, and decreases it on the other side.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::post_journal_entry(lines.len() as u32))]
+		pub fn post_journal_entry(
+			origin: OriginFor<T>,
+			lines: BoundedVec<JournalLine<T::AccountId, T::BlockNumber>, T::MaxJournalLines>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!lines.is_empty(), Error::<T>::EmptyJournalEntry);
+			ensure!(
+				lines.iter().all(|line| !Self::is_protocol_ledger(&line.ledger)),
+				Error::<T>::ProtocolLedger
+			);
+			ensure!(
+				lines.iter().all(|line| !line.amount.is_negative()),
+				Error::<T>::InvalidJournalAmount
+			);
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let reference_hash = Self::hash_reference(PostingSource::JournalEntry);
+			let keys = lines
+				.into_iter()
+				.map(|line| Record {
+					primary_party: who.clone(),
+					counterparty: line.counterparty,
+					ledger: line.ledger,
+					amount: Self::signed_amount(&line.ledger, line.amount, line.debit_credit),
+					debit_credit: line.debit_credit,
+					reference_hash,
+					changed_on_blocknumber: current_block,
+					applicable_period_blocknumber: line.applicable_period_blocknumber,
+				})
+				.collect::<Vec<_>>();
+
+			Self::post_multiple_amounts(&keys, PostingSource::JournalEntry)?;
+
+			Ok(())
+		}
+
+		/// Reverses the lines posted to the books of the caller under a posting index, by posting their
+		/// mirror image under a new posting index. A posting can only be reversed once.
+		///
+		/// Postings to the ledgers that are only maintained by the network cannot be reversed this way.
+		///
+		/// `ledger_count` is the number of ledgers the caller has posted to, and bounds the weight of
+		/// the call.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::reverse_posting(*ledger_count))]
+		pub fn reverse_posting(
+			origin: OriginFor<T>,
+			posting_index: PostingIndex,
+			ledger_count: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_reverse_posting(&who, posting_index, ledger_count, false)?;
+
+			Ok(())
+		}
+
+		/// Declares the fiscal calendar of the caller: the length of its accounting periods in blocks,
+		/// and a block on which its fiscal year ends.
+		///
+		/// The calendar cannot be changed once a period has been closed.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::set_fiscal_calendar())]
+		pub fn set_fiscal_calendar(
+			origin: OriginFor<T>,
+			calendar: FiscalCalendar<T::BlockNumber>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				!calendar.period_length.is_zero() && !calendar.periods_per_year.is_zero(),
+				Error::<T>::InvalidFiscalCalendar
+			);
+			ensure!(!ClosedUntil::<T>::contains_key(&who), Error::<T>::FiscalCalendarLocked);
+
+			FiscalCalendars::<T>::insert(&who, calendar);
+			Self::deposit_event(Event::FiscalCalendarSet { who, calendar });
+
+			Ok(())
+		}
+
+		/// Closes the period of the caller containing `block`, and every period before it. The
+		/// period must have ended. No posting can be made to the books of the caller in a closed
+		/// period, unless the period is reopened.
+		///
+		/// Closing a reopened period closes it again.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::close_period())]
+		pub fn close_period(origin: OriginFor<T>, block: T::BlockNumber) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let calendar = Self::fiscal_calendar(&who).ok_or(Error::<T>::NoFiscalCalendar)?;
+			let period_end = calendar.period_end(block);
+			ensure!(
+				period_end < frame_system::Pallet::<T>::block_number(),
+				Error::<T>::PeriodNotEnded
+			);
+
+			Self::do_close_period(who, period_end)
+		}
+
+		/// Reopens a closed period of the caller containing `block`, so that postings can be made to it
+		/// until it is closed again.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::reopen_period())]
+		pub fn reopen_period(origin: OriginFor<T>, block: T::BlockNumber) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let calendar = Self::fiscal_calendar(&who).ok_or(Error::<T>::NoFiscalCalendar)?;
+			let period_end = calendar.period_end(block);
+			ensure!(Self::is_period_closed(&who, block, &calendar), Error::<T>::PeriodNotClosed);
+
+			ReopenedPeriods::<T>::insert(&who, period_end, ());
+			Self::deposit_event(Event::PeriodReopened { who, period_end });
+
+			Ok(())
+		}
+
+		/// Closes the fiscal year of the caller ending with the period containing `block`. The period
+		/// must be the last of a year of the fiscal calendar of the caller.
+		///
+		/// Every profit and loss ledger with a balance is brought to zero by a closing entry in the
+		/// last period of the year, and the net profit or loss is booked to retained earnings. The
+		/// balances before the close are kept for reporting, and the period is then closed.
+		///
+		/// The balances are taken as they stand when the close is made, so the year should be closed
+		/// before anything is posted to the profit and loss ledgers of the following year.
+		///
+		/// `ledger_count` is the number of ledgers the caller has posted to, and bounds the weight of
+		/// the call.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::close_year(*ledger_count))]
+		pub fn close_year(
+			origin: OriginFor<T>,
+			block: T::BlockNumber,
+			ledger_count: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let calendar = Self::fiscal_calendar(&who).ok_or(Error::<T>::NoFiscalCalendar)?;
+			let period_end = calendar.period_end(block);
+			ensure!(calendar.is_year_end(period_end), Error::<T>::NotYearEnd);
+			ensure!(
+				period_end < frame_system::Pallet::<T>::block_number(),
+				Error::<T>::PeriodNotEnded
+			);
+			ensure!(
+				!Self::is_period_closed(&who, period_end, &calendar),
+				Error::<T>::PeriodAlreadyClosed
+			);
+			ensure!(
+				!YearEndResults::<T>::contains_key(&who, period_end),
+				Error::<T>::YearAlreadyClosed
+			);
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let reference_hash = Self::hash_reference(PostingSource::YearEndClose);
+			let closing_record =
+				|ledger: Ledger, amount: LedgerBalance, debit_credit: Indicator| Record {
+					primary_party: who.clone(),
+					counterparty: who.clone(),
+					ledger,
+					amount,
+					debit_credit,
+					reference_hash,
+					changed_on_blocknumber: current_block,
+					applicable_period_blocknumber: period_end,
+				};
+
+			let mut net: LedgerBalance = Zero::zero();
+			let mut keys = Vec::new();
+			for (index, (ledger, balance)) in BalanceByLedger::<T>::iter_prefix(&who).enumerate() {
+				ensure!(index < ledger_count as usize, Error::<T>::InvalidLedgerCount);
+				if !matches!(ledger, Ledger::ProfitLoss(_)) || balance.is_zero() {
+					continue
+				}
+				YearEndBalances::<T>::insert((who.clone(), period_end), ledger, balance);
+
+				let normal_balance = ledger.normal_balance();
+				// Income increases the result, expenses decrease it.
+				net = match normal_balance {
+					Indicator::Credit => net.checked_add(balance),
+					Indicator::Debit => net.checked_sub(balance),
+				}
+				.ok_or(Error::<T>::AmountOverflow)?;
+				let debit_credit = match balance > 0 {
+					true => normal_balance.reverse(),
+					false => normal_balance,
+				};
+				let amount = balance.checked_neg().ok_or(Error::<T>::AmountOverflow)?;
+				keys.push(closing_record(ledger, amount, debit_credit));
+			}
+
+			if !net.is_zero() {
+				let debit_credit = match net > 0 {
+					true => Indicator::Credit,
+					false => Indicator::Debit,
+				};
+				let retained_earnings = Ledger::BalanceSheet(B::Equity(E::RetainedEarnings(
+					RetainedEarnings::RetainedEarnings,
+				)));
+				keys.push(closing_record(retained_earnings, net, debit_credit));
+			}
+			if !keys.is_empty() {
+				Self::post_multiple_amounts(&keys, PostingSource::YearEndClose)?;
+			}
+
+			YearEndResults::<T>::insert(&who, period_end, net);
+			Self::deposit_event(Event::YearClosed { who: who.clone(), period_end, net });
+
+			Self::do_close_period(who, period_end)
+		}
+
+		/// Posts a deferred recipe that could not be posted in `on_idle`, once whatever made it fail has been
+		/// resolved.
+		///
+		/// The dispatch origin for this call is `root`.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::retry_failed_posting())]
+		pub fn retry_failed_posting(origin: OriginFor<T>, index: u64) -> DispatchResult {
+			ensure_root(origin)?;
+			let posting = Self::failed_posting(index).ok_or(Error::<T>::UnknownFailedPosting)?;
+
+			Self::post_deferred(posting.clone())?;
+
+			Self::track_deferred_changes(&posting, false);
+			FailedPostings::<T>::remove(index);
+			FailedPostingCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+			Self::deposit_event(Event::DeferredPostingPosted { index });
+
+			Ok(())
+		}
+
+		/// Opens the books of an account that brings its existing accounts onto the chain. Each line is the
+		/// balance of a ledger and the side it stands on, as in a trial balance, and the lines must balance.
+		///
+		/// The books of an account can only be opened once. The ledgers that are only maintained by the
+		/// network cannot be opened this way, as they already follow the balances.
+		///
+		/// The dispatch origin for this call is `root`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::open_books(lines.len() as u32))]
+		pub fn open_books(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			lines: BoundedVec<(Ledger, LedgerBalance, Indicator), T::MaxJournalLines>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(!lines.is_empty(), Error::<T>::EmptyJournalEntry);
+			ensure!(
+				lines.iter().all(|(ledger, _, _)| !Self::is_protocol_ledger(ledger)),
+				Error::<T>::ProtocolLedger
+			);
+			ensure!(!OpenedBooks::<T>::contains_key(&who), Error::<T>::BooksAlreadyOpened);
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let reference_hash = Self::hash_reference(PostingSource::OpeningBalances);
+			let keys = lines
+				.into_iter()
+				.map(|(ledger, amount, debit_credit)| {
+					Self::opening_record(
+						who.clone(),
+						ledger,
+						amount,
+						debit_credit,
+						reference_hash,
+						current_block,
+					)
+				})
+				.collect::<Result<Vec<_>, _>>()?;
+
+			let posting_index = Self::post_multiple_amounts(&keys, PostingSource::OpeningBalances)?;
+
+			OpenedBooks::<T>::insert(&who, posting_index);
+			Self::deposit_event(Event::BooksOpened { who, posting_index });
+
+			Ok(())
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A single line was posted to a ledger.
+		/// The amount is signed as it was applied to the balance of the ledger.
+		LedgerUpdate {
+			account: T::AccountId,
+			ledger: Ledger,
+			amount: LedgerBalance,
+			debit_credit: Indicator,
+			posting_index: PostingIndex,
+		},
+		/// A group of related lines was posted under one posting index.
+		/// `net` is the total of the debits less the total of the credits, and is zero when the group balances.
+		PostingRecorded {
+			posting_index: PostingIndex,
+			reference_hash: T::Hash,
+			lines: u32,
+			net: LedgerBalance,
+		},
+		/// The lines of an account under a posting index were reversed by a new posting.
+		PostingReversed {
+			who: T::AccountId,
+			posting_index: PostingIndex,
+			reversal_index: PostingIndex,
+		},
+		/// An account declared its fiscal calendar.
+		FiscalCalendarSet { who: T::AccountId, calendar: FiscalCalendar<T::BlockNumber> },
+		/// An account closed every period up to and including the one ending on `period_end`.
+		PeriodClosed { who: T::AccountId, period_end: T::BlockNumber },
+		/// An account reopened the closed period ending on `period_end`.
+		PeriodReopened { who: T::AccountId, period_end: T::BlockNumber },
+		/// An account closed its profit and loss ledgers into retained earnings for the year ending on
+		/// `period_end`. `net` is the profit, or the loss when negative.
+		YearClosed { who: T::AccountId, period_end: T::BlockNumber, net: LedgerBalance },
+		/// A recipe could not be posted when its balance moved, and was queued under a deferred index.
+		PostingDeferred { index: u64 },
+		/// A deferred recipe was posted.
+		DeferredPostingPosted { index: u64 },
+		/// A deferred recipe could not be posted, and is left for `retry_failed_posting`.
+		DeferredPostingFailed { index: u64, error: DispatchError },
+		/// The adjustment of the internal balance ledgers of an account could not be posted.
+		ReconciliationFailed { who: T::AccountId, error: DispatchError },
+		/// Every account was reconciled with the balances. `adjusted` accounts had their ledgers adjusted
+		/// against the balance adjustment suspense ledger.
+		BalancesReconciled { accounts: u32, adjusted: u32, failed: u32 },
+		/// The books of an account were opened with its opening balances.
+		BooksOpened { who: T::AccountId, posting_index: PostingIndex },
+	}
 
 	impl<T: Config> Pallet<T> {
 		/// Basic posting function (warning! can cause imbalance if not called with corresponding debit or credit entries)
@@ -287,43 +856,696 @@ mod pallet {
 
 			// !! Warning !!
 			// Values could feasibly overflow, with no visibility on other accounts. In this event this function returns an error.
-			// Reversals must occur in the parent function (i.e. that calls this function).
+			// This function is always called inside the storage transaction opened by `handle_multiposting_amounts`, which
+			// discards any change already made by the other records of the same posting.
 			// As all values passed to this function are already signed +/- we only need to sum to the previous balance and check for overflow
 			// Updates are only made to storage once tests below are passed for debits or credits.
-			match <BalanceByLedger<T>>::get(&key.primary_party, &key.ledger) {
-				None => BalanceByLedger::<T>::insert(
-					&key.primary_party,
-					&key.ledger,
-					key.amount.clone(),
-				),
-				Some(b) => {
-					let new_balance =
-						b.checked_add(key.amount).ok_or(Error::<T>::BalanceValueOverflow)?;
-					BalanceByLedger::<T>::insert(&key.primary_party, &key.ledger, new_balance);
+			let new_balance = match <BalanceByLedger<T>>::get(&key.primary_party, &key.ledger) {
+				None => key.amount,
+				Some(b) => b.checked_add(key.amount).ok_or(Error::<T>::BalanceValueOverflow)?,
+			};
+			let new_global_balance = <GlobalLedger<T>>::get(&key.ledger)
+				.checked_add(key.amount)
+				.ok_or(Error::<T>::BalanceValueOverflow)?;
+
+			BalanceByLedger::<T>::insert(&key.primary_party, &key.ledger, new_balance);
+			GlobalLedger::<T>::insert(&key.ledger, new_global_balance);
+			PostingNumber::<T>::put(posting_index);
+			PostingDetail::<T>::insert(&balance_key, &posting_index, detail);
+
+			Self::deposit_event(Event::LedgerUpdate {
+				account: key.primary_party,
+				ledger: key.ledger,
+				amount: key.amount,
+				debit_credit: key.debit_credit,
+				posting_index,
+			});
+
+			Ok(())
+		}
+
+		/// Posts every record under the next posting index inside a single storage transaction, and returns
+		/// that posting index. If any one of the records fails, the changes already made by the preceding
+		/// records are discarded, so the set is either posted in full or not at all.
+		fn post_multiple_amounts(
+			keys: &[Record<T::AccountId, T::Hash, T::BlockNumber>],
+			source: PostingSource,
+		) -> Result<PostingIndex, DispatchError> {
+			with_transaction(|| match Self::try_post_multiple_amounts(keys, source) {
+				Ok(posting_index) => TransactionOutcome::Commit(Ok(posting_index)),
+				Err(e) => TransactionOutcome::Rollback(Err(e)),
+			})
+		}
+
+		/// Posts every record under the next posting index, and records the reference of the posting.
+		/// This does not undo anything on failure, and must only be called inside a storage transaction.
+		fn try_post_multiple_amounts(
+			keys: &[Record<T::AccountId, T::Hash, T::BlockNumber>],
+			source: PostingSource,
+		) -> Result<PostingIndex, DispatchError> {
+			// Taken before any event of the posting is deposited.
+			let reference = Self::current_reference(source);
+			// Set initial value for posting index
+			let mut posting_index: PostingIndex = 1;
+			// Only need to increment, if it exists, else this the the very first record (with value 1).
+			if <PostingNumber<T>>::exists() {
+				posting_index = match Self::posting_number().checked_add(1) {
+					Some(i) => i,
+					None => {
+						fail!(Error::<T>::PostingIndexOverflow)
+					},
+				}
+			};
+
+			let net = Self::ensure_balanced(keys)?;
+			Self::ensure_periods_open(keys)?;
+
+			for key in keys.iter().cloned() {
+				PostingsByReference::<T>::insert(key.reference_hash, posting_index, ());
+				Self::post_amounts(key, posting_index)?;
+			}
+			PostingReferences::<T>::insert(posting_index, reference);
+			if let Some(extrinsic_index) = reference.extrinsic_index {
+				PostingsByExtrinsic::<T>::insert(
+					(reference.block_number, extrinsic_index),
+					posting_index,
+					(),
+				);
+			}
+
+			if let Some(first) = keys.first() {
+				Self::deposit_event(Event::PostingRecorded {
+					posting_index,
+					reference_hash: first.reference_hash,
+					lines: keys.len() as u32,
+					net,
+				});
+			}
+
+			Ok(posting_index)
+		}
+
+		/// Checks that the total of the debits equals the total of the credits, and when `EnforcePartyBalance`
+		/// is set, that it does so for the records of each primary party too. Each ledger of a party can only
+		/// have one record, as the detail of a posting is kept by ledger.
+		/// Returns the net of the debits and credits, which is always zero when the check passes.
+		fn ensure_balanced(
+			keys: &[Record<T::AccountId, T::Hash, T::BlockNumber>],
+		) -> Result<LedgerBalance, DispatchError> {
+			Self::ensure_distinct_ledgers(keys)?;
+			let net = keys
+				.iter()
+				.try_fold(Zero::zero(), |net, key| Self::add_debit_credit(net, key))?;
+			ensure!(net.is_zero(), Error::<T>::UnbalancedPosting);
+
+			if T::EnforcePartyBalance::get() {
+				Self::ensure_parties_balanced(keys)?;
+			}
+
+			Ok(net)
+		}
+
+		/// Checks that no two records are for the same ledger of the same primary party.
+		fn ensure_distinct_ledgers(
+			keys: &[Record<T::AccountId, T::Hash, T::BlockNumber>],
+		) -> DispatchResult {
+			for (index, key) in keys.iter().enumerate() {
+				ensure!(
+					!keys[..index].iter().any(|previous| {
+						previous.primary_party == key.primary_party && previous.ledger == key.ledger
+					}),
+					Error::<T>::DuplicateLedger
+				);
+			}
+
+			Ok(())
+		}
+
+		/// Checks that the debits equal the credits for the records of each primary party.
+		fn ensure_parties_balanced(
+			keys: &[Record<T::AccountId, T::Hash, T::BlockNumber>],
+		) -> DispatchResult {
+			let mut parties = BTreeMap::<T::AccountId, LedgerBalance>::new();
+			for key in keys {
+				let party_net = parties.entry(key.primary_party.clone()).or_default();
+				*party_net = Self::add_debit_credit(*party_net, key)?;
+			}
+			ensure!(parties.values().all(Zero::is_zero), Error::<T>::UnbalancedPartyPosting);
+
+			Ok(())
+		}
+
+		/// The change that `amount` on the `debit_credit` side makes to the balance of `ledger`. It increases
+		/// the ledger on its normal side and decreases it on the other.
+		fn signed_amount(
+			ledger: &Ledger,
+			amount: LedgerBalance,
+			debit_credit: Indicator,
+		) -> LedgerBalance {
+			match debit_credit == ledger.normal_balance() {
+				true => amount,
+				false => -amount,
+			}
+		}
+
+		/// The record opening a ledger of `who` with a balance standing on the `debit_credit` side. A balance
+		/// on the normal side of the ledger increases it, and a balance on the other side decreases it.
+		fn opening_record(
+			who: T::AccountId,
+			ledger: Ledger,
+			amount: LedgerBalance,
+			debit_credit: Indicator,
+			reference_hash: T::Hash,
+			block_number: T::BlockNumber,
+		) -> Result<Record<T::AccountId, T::Hash, T::BlockNumber>, Error<T>> {
+			ensure!(!amount.is_negative(), Error::<T>::InvalidOpeningBalance);
+
+			Ok(Record {
+				primary_party: who.clone(),
+				counterparty: who,
+				ledger,
+				amount: Self::signed_amount(&ledger, amount, debit_credit),
+				debit_credit,
+				reference_hash,
+				changed_on_blocknumber: block_number,
+				applicable_period_blocknumber: block_number,
+			})
+		}
+
+		/// Closes every period of `who` up to and including the one ending on `period_end`, or closes
+		/// again a reopened period.
+		fn do_close_period(who: T::AccountId, period_end: T::BlockNumber) -> DispatchResult {
+			match Self::closed_until(&who) {
+				Some(closed_until) if period_end <= closed_until => {
+					ensure!(
+						ReopenedPeriods::<T>::contains_key(&who, period_end),
+						Error::<T>::PeriodAlreadyClosed
+					);
+					ReopenedPeriods::<T>::remove(&who, period_end);
+				},
+				_ => {
+					// Any reopened period is before this one and is closed again.
+					let _ = ReopenedPeriods::<T>::clear_prefix(&who, u32::MAX, None);
+					ClosedUntil::<T>::insert(&who, period_end);
+				},
+			}
+			Self::deposit_event(Event::PeriodClosed { who, period_end });
+
+			Ok(())
+		}
+
+		/// Checks that no record falls into a closed period of its primary party.
+		fn ensure_periods_open(
+			keys: &[Record<T::AccountId, T::Hash, T::BlockNumber>],
+		) -> DispatchResult {
+			for key in keys {
+				if let Some(calendar) = Self::fiscal_calendar(&key.primary_party) {
+					ensure!(
+						!Self::is_period_closed(
+							&key.primary_party,
+							key.applicable_period_blocknumber,
+							&calendar
+						),
+						Error::<T>::PeriodClosed
+					);
+				}
+			}
+
+			Ok(())
+		}
+
+		/// Whether the period of `who` containing `block` is closed and has not been reopened.
+		fn is_period_closed(
+			who: &T::AccountId,
+			block: T::BlockNumber,
+			calendar: &FiscalCalendar<T::BlockNumber>,
+		) -> bool {
+			match Self::closed_until(who) {
+				Some(closed_until) if block <= closed_until =>
+					!ReopenedPeriods::<T>::contains_key(who, calendar.period_end(block)),
+				_ => false,
+			}
+		}
+
+		/// Adds a debit to, or subtracts a credit from, a running net of debits and credits.
+		/// The amounts in the records are signed according to whether they increase or decrease the
+		/// ledger, so the absolute value is used here.
+		fn add_debit_credit(
+			net: LedgerBalance,
+			key: &Record<T::AccountId, T::Hash, T::BlockNumber>,
+		) -> Result<LedgerBalance, Error<T>> {
+			let amount = key.amount.checked_abs().ok_or(Error::<T>::AmountOverflow)?;
+			match key.debit_credit {
+				Indicator::Debit => net.checked_add(amount),
+				Indicator::Credit => net.checked_sub(amount),
+			}
+			.ok_or(Error::<T>::AmountOverflow)
+		}
+
+		/// Posts the mirror image of the lines of `who` under `posting_index`: each line has its indicator
+		/// flipped and the change it made to the balance of the ledger undone.
+		/// The original and the reversal are linked so that the original cannot be reversed again.
+		fn do_reverse_posting(
+			who: &T::AccountId,
+			posting_index: PostingIndex,
+			ledger_count: u32,
+			allow_protocol_ledgers: bool,
+		) -> Result<PostingIndex, DispatchError> {
+			ensure!(
+				!ReversedBy::<T>::contains_key(who, posting_index),
+				Error::<T>::AlreadyReversed
+			);
+
+			// The ledgers used by the account are the only places where its lines can be found.
+			let mut lines = Vec::new();
+			for (index, ledger) in BalanceByLedger::<T>::iter_key_prefix(who).enumerate() {
+				ensure!(index < ledger_count as usize, Error::<T>::InvalidLedgerCount);
+				if let Some(detail) = PostingDetail::<T>::get((who.clone(), ledger), posting_index)
+				{
+					lines.push((ledger, detail));
+				}
+			}
+			ensure!(!lines.is_empty(), Error::<T>::UnknownPosting);
+			ensure!(
+				allow_protocol_ledgers ||
+					lines.iter().all(|(ledger, _)| !Self::is_protocol_ledger(ledger)),
+				Error::<T>::ProtocolLedger
+			);
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let keys = lines
+				.into_iter()
+				.map(|(ledger, detail)| {
+					// The detail only keeps the absolute amount. It was an increase of the ledger
+					// when posted on the side on which the ledger has its normal balance.
+					let reversal_amount = match detail.debit_credit == ledger.normal_balance() {
+						true => -detail.amount,
+						false => detail.amount,
+					};
+					Record {
+						primary_party: who.clone(),
+						counterparty: detail.counterparty,
+						ledger,
+						amount: reversal_amount,
+						debit_credit: detail.debit_credit.reverse(),
+						reference_hash: detail.reference_hash,
+						changed_on_blocknumber: current_block,
+						applicable_period_blocknumber: current_block,
+					}
+				})
+				.collect::<Vec<_>>();
+
+			let reversal_index = Self::post_multiple_amounts(&keys, PostingSource::Reversal)?;
+			ReversedBy::<T>::insert(who, posting_index, reversal_index);
+			ReversalOf::<T>::insert(who, reversal_index, posting_index);
+
+			Self::deposit_event(Event::PostingReversed {
+				who: who.clone(),
+				posting_index,
+				reversal_index,
+			});
+
+			Ok(reversal_index)
+		}
+
+		/// Posts a deferred recipe.
+		fn post_deferred(posting: DeferredPostingOf<T>) -> DispatchResult {
+			match posting {
+				DeferredPosting::Unreserve { beneficiary, amount } =>
+					<Self as Posting<_, _, _, _>>::unreserve_amount(beneficiary, amount),
+				DeferredPosting::SlashReserve { beneficiary, amount } =>
+					<Self as Posting<_, _, _, _>>::slash_reserve(beneficiary, amount),
+				DeferredPosting::Slash { beneficiary, amount } =>
+					<Self as Posting<_, _, _, _>>::account_for_slash(beneficiary, amount),
+			}
+		}
+
+		/// The weight of posting one deferred recipe, including taking it from the queue and recording a
+		/// failure.
+		fn deferred_posting_weight() -> Weight {
+			T::WeightInfo::handle_multiposting_amounts(2)
+				.saturating_add(T::DbWeight::get().reads_writes(3, 4))
+		}
+
+		/// The beneficiary of a deferred recipe, with the changes the recipe makes to its internal balance and
+		/// to its internal reserved balance.
+		fn deferred_changes_of(
+			posting: &DeferredPostingOf<T>,
+		) -> (&T::AccountId, LedgerBalance, LedgerBalance) {
+			let to_ledger_balance =
+				|amount| T::AccountingConverter::try_convert(amount).unwrap_or(LedgerBalance::MAX);
+			match posting {
+				DeferredPosting::Unreserve { beneficiary, amount } => {
+					let amount = to_ledger_balance(*amount);
+					(beneficiary, amount, amount.saturating_neg())
+				},
+				DeferredPosting::SlashReserve { beneficiary, amount } =>
+					(beneficiary, 0, to_ledger_balance(*amount).saturating_neg()),
+				DeferredPosting::Slash { beneficiary, amount } =>
+					(beneficiary, to_ledger_balance(*amount).saturating_neg(), 0),
+			}
+		}
+
+		/// Adds the changes of a deferred recipe to the changes of its beneficiary when it is `queued`, and
+		/// takes them away once it is posted.
+		fn track_deferred_changes(posting: &DeferredPostingOf<T>, queued: bool) {
+			let (beneficiary, mut free_change, mut reserved_change) =
+				Self::deferred_changes_of(posting);
+			if !queued {
+				free_change = free_change.saturating_neg();
+				reserved_change = reserved_change.saturating_neg();
+			}
+			DeferredChanges::<T>::mutate_exists(beneficiary, |changes| {
+				let (free, reserved) = changes.unwrap_or_default();
+				let updated =
+					(free.saturating_add(free_change), reserved.saturating_add(reserved_change));
+				*changes = (updated != (0, 0)).then_some(updated);
+			});
+		}
+
+		/// Reconciles the next accounts of the pending reconciliation, no more than `limit` and no more than
+		/// `remaining_weight` allows. Returns the weight used.
+		///
+		/// The accounts of the balances are reconciled first. The ledgers are walked next, for the accounts that
+		/// hold ledgers but no longer have an account in the balances, such as reaped accounts.
+		pub(crate) fn reconcile_accounts(limit: u32, remaining_weight: Weight) -> Weight {
+			let mut consumed = T::DbWeight::get().reads_writes(1, 1);
+			if !consumed.all_lte(remaining_weight) {
+				return Weight::zero()
+			}
+			let mut progress = match Self::pending_reconciliation() {
+				Some(progress) => progress,
+				None => return T::DbWeight::get().reads(1),
+			};
+
+			let mut reconciled = 0;
+			let mut finished = false;
+			if !matches!(progress.cursor, Some(ReconciliationCursor::Ledger(_))) {
+				let mut accounts = match &progress.cursor {
+					Some(ReconciliationCursor::Account(last_account)) =>
+						frame_system::Account::<T>::iter_keys_from(
+							frame_system::Account::<T>::hashed_key_for(last_account),
+						),
+					_ => frame_system::Account::<T>::iter_keys(),
+				};
+				while reconciled < limit {
+					let next = consumed.saturating_add(Self::reconciliation_weight());
+					if !next.all_lte(remaining_weight) {
+						break
+					}
+					consumed = next;
+
+					match accounts.next() {
+						Some(who) => {
+							Self::reconcile_into(&who, &mut progress);
+							progress.cursor = Some(ReconciliationCursor::Account(who));
+							reconciled += 1;
+						},
+						None => {
+							progress.cursor = Some(ReconciliationCursor::Ledger(None));
+							break
+						},
+					}
+				}
+			}
+
+			if let Some(ReconciliationCursor::Ledger(last_key)) = progress.cursor.clone() {
+				let mut keys = match &last_key {
+					Some((last_account, last_ledger)) => BalanceByLedger::<T>::iter_keys_from(
+						BalanceByLedger::<T>::hashed_key_for(last_account, last_ledger),
+					),
+					None => BalanceByLedger::<T>::iter_keys(),
+				};
+				let mut last_account = last_key.map(|(who, _)| who);
+				while reconciled < limit {
+					// Reading the next ledger and whether its account is in the balances.
+					let next = consumed.saturating_add(T::DbWeight::get().reads(2));
+					if !next.all_lte(remaining_weight) {
+						break
+					}
+					let (who, ledger) = match keys.next() {
+						Some(key) => key,
+						None => {
+							consumed = next;
+							finished = true;
+							break
+						},
+					};
+					// The ledgers of an account follow each other, and the accounts of the balances have
+					// already been reconciled.
+					let reconcile = last_account.as_ref() != Some(&who) &&
+						!frame_system::Account::<T>::contains_key(&who);
+					let next = match reconcile {
+						true => next.saturating_add(Self::reconciliation_weight()),
+						false => next,
+					};
+					if !next.all_lte(remaining_weight) {
+						break
+					}
+					consumed = next;
+
+					if reconcile {
+						Self::reconcile_into(&who, &mut progress);
+						reconciled += 1;
+					}
+					progress.cursor =
+						Some(ReconciliationCursor::Ledger(Some((who.clone(), ledger))));
+					last_account = Some(who);
+				}
+			}
+
+			if finished {
+				PendingReconciliation::<T>::kill();
+				Self::deposit_event(Event::BalancesReconciled {
+					accounts: progress.accounts,
+					adjusted: progress.adjusted,
+					failed: progress.failed,
+				});
+			} else {
+				PendingReconciliation::<T>::put(progress);
+			}
+
+			consumed
+		}
+
+		/// Reconciles the account `who` and counts it in the `progress` of the reconciliation.
+		fn reconcile_into(who: &T::AccountId, progress: &mut Reconciliation<T::AccountId>) {
+			match Self::reconcile_account(who) {
+				Ok(true) => progress.adjusted = progress.adjusted.saturating_add(1),
+				Ok(false) => (),
+				Err(error) => {
+					progress.failed = progress.failed.saturating_add(1);
+					Self::deposit_event(Event::ReconciliationFailed { who: who.clone(), error });
 				},
-			};
+			}
+			progress.accounts = progress.accounts.saturating_add(1);
+		}
 
-			if let Some(new_global_balance) =
-				<GlobalLedger<T>>::get(&key.ledger).checked_add(key.amount)
-			{
-				GlobalLedger::<T>::insert(&key.ledger, new_global_balance);
-			} else {
-				GlobalLedger::<T>::insert(&key.ledger, key.amount.clone());
+		/// Posts the adjustments bringing the internal balance ledgers of `who` in line with its free and
+		/// reserved balances, against the balance adjustment suspense ledger.
+		/// Returns whether there was anything to adjust.
+		fn reconcile_account(who: &T::AccountId) -> Result<bool, DispatchError> {
+			let to_ledger_balance = |amount| {
+				T::AccountingConverter::try_convert(amount).ok_or(Error::<T>::AmountOverflow)
 			};
+			let internal_balance =
+				Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::InternalBalance)));
+			let internal_reserved_balance = Ledger::BalanceSheet(B::Assets(A::CurrentAssets(
+				CurrentAssets::InternalReservedBalance,
+			)));
+			let suspense = Ledger::BalanceSheet(B::Equity(E::BalanceAdjustmentSuspense));
+
+			let free = T::Currency::free_balance(who);
+			let reserved = T::Currency::total_balance(who).saturating_sub(free);
+			// The queued deferred postings already account for part of the difference.
+			let free_adjustment = to_ledger_balance(free)?
+				.checked_sub(Self::settled_balance(who, internal_balance))
+				.ok_or(Error::<T>::AmountOverflow)?;
+			let reserved_adjustment = to_ledger_balance(reserved)?
+				.checked_sub(Self::settled_balance(who, internal_reserved_balance))
+				.ok_or(Error::<T>::AmountOverflow)?;
+			let suspense_adjustment = free_adjustment
+				.checked_add(reserved_adjustment)
+				.ok_or(Error::<T>::AmountOverflow)?;
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let reference_hash = Self::hash_reference(PostingSource::Reconciliation);
+			// A ledger moves on its normal side when the adjustment increases it, and on the other side when
+			// the adjustment decreases it.
+			let keys = [
+				(internal_balance, free_adjustment),
+				(internal_reserved_balance, reserved_adjustment),
+				(suspense, suspense_adjustment),
+			]
+			.into_iter()
+			.filter(|(_, amount)| !amount.is_zero())
+			.map(|(ledger, amount)| Record {
+				primary_party: who.clone(),
+				counterparty: who.clone(),
+				ledger,
+				amount,
+				debit_credit: match amount.is_negative() {
+					true => ledger.normal_balance().reverse(),
+					false => ledger.normal_balance(),
+				},
+				reference_hash,
+				changed_on_blocknumber: current_block,
+				applicable_period_blocknumber: current_block,
+			})
+			.collect::<Vec<_>>();
+			if keys.is_empty() {
+				return Ok(false)
+			}
 
-			PostingNumber::<T>::put(posting_index);
-			PostingDetail::<T>::insert(&balance_key, &posting_index, detail);
+			Self::post_multiple_amounts(&keys, PostingSource::Reconciliation)?;
+
+			Ok(true)
+		}
+
+		/// The weight of reconciling one account, including posting its adjustments.
+		fn reconciliation_weight() -> Weight {
+			T::WeightInfo::handle_multiposting_amounts(3)
+				.saturating_add(T::DbWeight::get().reads(5))
+		}
+
+		/// The reference of a posting made now by `source`.
+		fn current_reference(source: PostingSource) -> PostingReference<T::BlockNumber> {
+			PostingReference {
+				block_number: frame_system::Pallet::<T>::block_number(),
+				extrinsic_index: frame_system::Pallet::<T>::extrinsic_index(),
+				event_index: frame_system::Pallet::<T>::event_count(),
+				source,
+			}
+		}
+
+		/// The reference hash of a posting made now by `source`.
+		fn hash_reference(source: PostingSource) -> T::Hash {
+			T::Hashing::hash_of(&Self::current_reference(source))
+		}
+
+		/// The postings made by an extrinsic, in posting order.
+		pub fn postings_by_extrinsic(
+			block_number: T::BlockNumber,
+			extrinsic_index: u32,
+		) -> Vec<PostingIndex> {
+			let mut postings =
+				PostingsByExtrinsic::<T>::iter_key_prefix((block_number, extrinsic_index))
+					.collect::<Vec<_>>();
+			postings.sort();
+
+			postings
+		}
+
+		/// The postings whose lines carry a reference hash, in posting order.
+		pub fn postings_by_reference(reference_hash: T::Hash) -> Vec<PostingIndex> {
+			let mut postings =
+				PostingsByReference::<T>::iter_key_prefix(reference_hash).collect::<Vec<_>>();
+			postings.sort();
+
+			postings
+		}
+
+		/// Checks that the global ledger is the sum of the balances of every account, that the failed
+		/// postings are counted, and that the deferred changes follow the queued and failed postings.
+		#[cfg(any(feature = "try-runtime", test))]
+		pub fn do_try_state() -> Result<(), &'static str> {
+			let mut totals = BTreeMap::<totem_primitives::Account, LedgerBalance>::new();
+			for (_, ledger, balance) in BalanceByLedger::<T>::iter() {
+				let total = totals.entry(ledger.account_number()).or_default();
+				*total = total.checked_add(balance).ok_or("ledger total overflowed")?;
+			}
+			for (ledger, balance) in GlobalLedger::<T>::iter() {
+				let total = totals.remove(&ledger.account_number()).unwrap_or_default();
+				ensure!(total == balance, "global ledger differs from the sum of the accounts");
+			}
+			ensure!(
+				totals.values().all(|total| total.is_zero()),
+				"accounts hold balances missing from the global ledger"
+			);
+
+			ensure!(
+				FailedPostings::<T>::iter_keys().count() as u32 == Self::failed_posting_count(),
+				"failed postings differ from their count"
+			);
 
-			// Self::deposit_event(Event::LegderUpdate(
-			//     key.primary_party,
-			//     key.ledger,
-			//     key.amount,
-			//     posting_index,
-			// ));
+			let mut changes = BTreeMap::<T::AccountId, (LedgerBalance, LedgerBalance)>::new();
+			for posting in
+				DeferredPostings::<T>::iter_values().chain(FailedPostings::<T>::iter_values())
+			{
+				let (beneficiary, free_change, reserved_change) =
+					Self::deferred_changes_of(&posting);
+				let (free, reserved) = changes.entry(beneficiary.clone()).or_default();
+				*free = free.saturating_add(free_change);
+				*reserved = reserved.saturating_add(reserved_change);
+			}
+			changes.retain(|_, changes| *changes != (0, 0));
+			ensure!(
+				DeferredChanges::<T>::iter().collect::<BTreeMap<_, _>>() == changes,
+				"deferred changes differ from the queued and failed deferred postings"
+			);
 
 			Ok(())
 		}
 
+		/// All the ledgers used by an account, with their balances.
+		pub fn balances_of(account: &T::AccountId) -> Vec<(Ledger, LedgerBalance)> {
+			BalanceByLedger::<T>::iter_prefix(account).collect()
+		}
+
+		/// The balances of every ledger across all accounts.
+		pub fn global_ledger_balances() -> Vec<(Ledger, LedgerBalance)> {
+			GlobalLedger::<T>::iter().collect()
+		}
+
+		/// The trial balance of an account. A balance that has moved against the normal side of its
+		/// ledger is shown on the opposite side.
+		pub fn trial_balance(account: &T::AccountId) -> TrialBalance {
+			let mut trial_balance = TrialBalance::default();
+			for (ledger, balance) in Self::balances_of(account) {
+				let (debit, credit) = match ledger.balance_side(balance) {
+					(Indicator::Debit, amount) => (amount, 0),
+					(Indicator::Credit, amount) => (0, amount),
+				};
+				trial_balance.total_debit = trial_balance.total_debit.saturating_add(debit);
+				trial_balance.total_credit = trial_balance.total_credit.saturating_add(credit);
+				trial_balance.lines.push(TrialBalanceLine { ledger, debit, credit });
+			}
+
+			trial_balance
+		}
+
+		/// The detail of at most `limit` postings of an account to a ledger, following the posting `start_after`.
+		/// The postings come in storage order rather than in posting order: the next page starts after the last
+		/// posting of the previous one.
+		pub fn postings(
+			account: T::AccountId,
+			ledger: Ledger,
+			start_after: Option<PostingIndex>,
+			limit: u32,
+		) -> Vec<(PostingIndex, Detail<T::AccountId, T::Hash, T::BlockNumber>)> {
+			let postings = match start_after {
+				Some(index) => {
+					let start_key = PostingDetail::<T>::hashed_key_for((&account, ledger), index);
+					PostingDetail::<T>::iter_prefix_from((account, ledger), start_key)
+				},
+				None => PostingDetail::<T>::iter_prefix((account, ledger)),
+			};
+
+			postings.take(limit as usize).collect()
+		}
+
+		/// The ledgers that only the network posts to, as they must always agree with the balances pallet.
+		fn is_protocol_ledger(ledger: &Ledger) -> bool {
+			matches!(
+				ledger,
+				Ledger::BalanceSheet(B::Assets(A::CurrentAssets(
+					CurrentAssets::InternalBalance | CurrentAssets::InternalReservedBalance
+				))) | Ledger::BalanceSheet(B::Equity(
+					E::NetworkReserves | E::BalanceAdjustmentSuspense
+				))
+			)
+		}
+
 		/// Return a pair of:
 		/// - The amount given as a parameter, but signed.
 		/// - The opposite of that amount.
@@ -339,58 +1561,18 @@ mod pallet {
 		}
 	}
 
-	impl<T: Config> Posting<T::AccountId, T::Hash, T::BlockNumber, CurrencyBalanceOf<T>> for Pallet<T>
-	where
-		T: pallet_timestamp::Config,
-	{
+	impl<T: Config> Posting<T::AccountId, T::Hash, T::BlockNumber, CurrencyBalanceOf<T>> for Pallet<T> {
 		type PostingIndex = PostingIndex;
 
 		/// The Totem Accounting Recipes are constructed using this function which handles posting to multiple accounts.
 		/// It is exposed to other modules as a trait
-		/// If for whatever reason an error occurs during the storage processing which is sequential
-		/// this function also handles reversing out the prior accounting entries
-		/// Therefore the recipes that are passed as arguments need to be be accompanied with a reversal
-		/// Obviously the last posting does not need a reversal for if it errors, then it was not posted in the first place.
+		/// All the records are posted inside a single storage transaction, so the set is either posted in full
+		/// or not at all.
 		fn handle_multiposting_amounts(
 			keys: &[Record<T::AccountId, T::Hash, T::BlockNumber>],
+			source: PostingSource,
 		) -> DispatchResult {
-			// Set initial value for posting index
-			let mut posting_index: PostingIndex = 1;
-			// Only need to increment, if it exists, else this the the very first record (with value 1).
-			if <PostingNumber<T>>::exists() {
-				posting_index = match Self::posting_number().checked_add(1) {
-					Some(i) => i,
-					None => {
-						fail!(Error::<T>::PostingIndexOverflow)
-					},
-				}
-			};
-
-			// Iterate over forward keys. If error, then reverse out prior postings.
-			for (idx, key) in keys.iter().cloned().enumerate() {
-				if let Err(e) = Self::post_amounts(key, posting_index) {
-					// (Un)likely error before the value was updated.
-					// Need to reverse-out the earlier postings to storage just in case
-					// it has changed in storage already
-					if idx > 1 {
-						for key in keys.iter().cloned().take(idx - 1) {
-							let reversed = Record {
-								// Reversal should never cause an overflow - check nevertheless
-								amount: key
-									.amount
-									.checked_neg()
-									.ok_or(Error::<T>::AmountOverflow)?,
-								debit_credit: key.debit_credit.reverse(),
-								..key
-							};
-							// If this fails it would be serious. This is not expected to fail.
-							Self::post_amounts(reversed, posting_index)
-								.or(Err(Error::<T>::SystemFailure))?;
-						}
-					}
-					fail!(e)
-				}
-			}
+			Self::post_multiple_amounts(keys, source)?;
 
 			Ok(())
 		}
@@ -416,7 +1598,7 @@ mod pallet {
 			to: T::AccountId,
 			amount: CurrencyBalanceOf<T>,
 		) -> DispatchResult {
-			let reference_hash = Self::get_pseudo_random_hash(from.clone(), to.clone());
+			let reference_hash = Self::reference_hash(PostingSource::Transfer);
 			let current_block = frame_system::Pallet::<T>::block_number(); // For audit on change
 			let current_block_dupe = current_block; // Applicable period for accounting
 			let (increase_amount, decrease_amount) = Self::increase_decrease_amounts(amount)?;
@@ -467,7 +1649,7 @@ mod pallet {
 				},
 			];
 
-			Self::handle_multiposting_amounts(&keys)?;
+			Self::handle_multiposting_amounts(&keys, PostingSource::Transfer)?;
 
 			Ok(())
 		}
@@ -484,8 +1666,7 @@ mod pallet {
 			let current_block = frame_system::Pallet::<T>::block_number(); // For audit on change
 			let current_block_dupe = current_block; // Applicable period for accounting
 
-			// Generate dummy Hash reference (it has no real bearing but allows posting to happen)
-			let fee_hash: T::Hash = Self::get_pseudo_random_hash(payer.clone(), payer.clone());
+			let fee_hash: T::Hash = Self::reference_hash(PostingSource::Fees);
 
 			// Get the dummy address for fees. Note this does not identify the receipients of fees (validators)
 			// It is used just for generic self-referential accounting
@@ -518,7 +1699,7 @@ mod pallet {
 				},
 			];
 
-			Self::handle_multiposting_amounts(&keys)?;
+			Self::handle_multiposting_amounts(&keys, PostingSource::Fees)?;
 
 			Ok(())
 		}
@@ -537,9 +1718,7 @@ mod pallet {
 			let current_block = frame_system::Pallet::<T>::block_number(); // For audit on change
 			let current_block_dupe = current_block; // Applicable period for accounting
 
-			// Generate dummy Hash reference (it has no real bearing but allows posting to happen)
-			let ref_hash: T::Hash =
-				Self::get_pseudo_random_hash(beneficiary.clone(), beneficiary.clone());
+			let ref_hash: T::Hash = Self::reference_hash(PostingSource::Reserve);
 
 			let keys = [
 				Record {
@@ -568,7 +1747,7 @@ mod pallet {
 				},
 			];
 
-			Self::handle_multiposting_amounts(&keys)?;
+			Self::handle_multiposting_amounts(&keys, PostingSource::Reserve)?;
 
 			Ok(())
 		}
@@ -587,9 +1766,7 @@ mod pallet {
 			let current_block = frame_system::Pallet::<T>::block_number(); // For audit on change
 			let current_block_dupe = current_block; // Applicable period for accounting
 
-			// Generate dummy Hash reference (it has no real bearing in this use case, but allows posting to happen)
-			let ref_hash: T::Hash =
-				Self::get_pseudo_random_hash(beneficiary.clone(), beneficiary.clone());
+			let ref_hash: T::Hash = Self::reference_hash(PostingSource::Unreserve);
 
 			let keys = [
 				Record {
@@ -618,7 +1795,7 @@ mod pallet {
 				},
 			];
 
-			Self::handle_multiposting_amounts(&keys)?;
+			Self::handle_multiposting_amounts(&keys, PostingSource::Unreserve)?;
 
 			Ok(())
 		}
@@ -638,9 +1815,7 @@ mod pallet {
 			let current_block = frame_system::Pallet::<T>::block_number(); // For audit on change
 			let current_block_dupe = current_block; // Applicable period for accounting
 
-			// Generate dummy Hash reference (it has no real bearing in this use case, but allows posting to happen)
-			let ref_hash: T::Hash =
-				Self::get_pseudo_random_hash(beneficiary.clone(), beneficiary.clone());
+			let ref_hash: T::Hash = Self::reference_hash(PostingSource::SlashReserved);
 
 			let keys = [
 				Record {
@@ -669,7 +1844,7 @@ mod pallet {
 				},
 			];
 
-			Self::handle_multiposting_amounts(&keys)?;
+			Self::handle_multiposting_amounts(&keys, PostingSource::SlashReserved)?;
 
 			Ok(())
 		}
@@ -690,9 +1865,7 @@ mod pallet {
 			let current_block = frame_system::Pallet::<T>::block_number(); // For audit on change
 			let current_block_dupe = current_block; // Applicable period for accounting
 
-			// Generate dummy Hash reference (it has no real bearing in this use case, but allows posting to happen)
-			let ref_hash: T::Hash =
-				Self::get_pseudo_random_hash(slashed.clone(), beneficiary.clone());
+			let ref_hash: T::Hash = Self::reference_hash(PostingSource::RepatriateReserved);
 
 			// Select the account ledger to update
 			let beneficiary_ledger = match is_free_balance {
@@ -756,7 +1929,257 @@ mod pallet {
 				},
 			];
 
-			Self::handle_multiposting_amounts(&keys)?;
+			Self::handle_multiposting_amounts(&keys, PostingSource::RepatriateReserved)?;
+
+			Ok(())
+		}
+
+		/// This function reclassifies the refunded part of a transaction fee. The fee was booked in full when it was
+		/// withdrawn, and the refund was booked as a mint, so this moves the refund from the network reserves back
+		/// out of the fee expense.
+		fn account_for_fee_refund(
+			refund: CurrencyBalanceOf<T>,
+			payer: T::AccountId,
+		) -> DispatchResult {
+			// Take the amount and convert for use with accounting. Amount is of type T::Balance which is u128.
+			// As amount will always be positive, convert for use in accounting
+			let (_, decrease_amount) = Self::increase_decrease_amounts(refund)?;
+			// This sets the change block and the applicable posting period. For this context they will always be
+			// the same.
+			let current_block = frame_system::Pallet::<T>::block_number(); // For audit on change
+			let current_block_dupe = current_block; // Applicable period for accounting
+			let netfee_address: T::AccountId = Self::get_netfees_account();
+
+			let ref_hash: T::Hash = Self::reference_hash(PostingSource::FeeRefund);
+
+			let keys = [
+				Record {
+					primary_party: payer.clone(),
+					counterparty: netfee_address.clone(),
+					ledger: Ledger::BalanceSheet(B::Equity(E::NetworkReserves)),
+					amount: decrease_amount,
+					debit_credit: Indicator::Debit,
+					reference_hash: ref_hash,
+					changed_on_blocknumber: current_block,
+					applicable_period_blocknumber: current_block_dupe,
+				},
+				Record {
+					primary_party: payer.clone(),
+					counterparty: netfee_address.clone(),
+					ledger: Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(
+						AdminCosts::Blockchain(InternalAccounting::NetworkTransactionFees),
+					)))),
+					amount: decrease_amount,
+					debit_credit: Indicator::Credit,
+					reference_hash: ref_hash,
+					changed_on_blocknumber: current_block,
+					applicable_period_blocknumber: current_block_dupe,
+				},
+			];
+
+			Self::handle_multiposting_amounts(&keys, PostingSource::FeeRefund)?;
+
+			Ok(())
+		}
+
+		/// This function accounts for coins created into the free balance of an account. The coin supply is increased
+		/// by the balances pallet, and the accounting books it against the network reserves.
+		fn account_for_mint(
+			beneficiary: T::AccountId,
+			amount: CurrencyBalanceOf<T>,
+		) -> DispatchResult {
+			// Take the amount and convert for use with accounting. Amount is of type T::Balance which is u128.
+			// As amount will always be positive, convert for use in accounting
+			let (increase_amount, decrease_amount) = Self::increase_decrease_amounts(amount)?;
+			// This sets the change block and the applicable posting period. For this context they will always be
+			// the same.
+			let current_block = frame_system::Pallet::<T>::block_number(); // For audit on change
+			let current_block_dupe = current_block; // Applicable period for accounting
+
+			let ref_hash: T::Hash = Self::reference_hash(PostingSource::Mint);
+
+			let keys = [
+				Record {
+					primary_party: beneficiary.clone(),
+					counterparty: beneficiary.clone(),
+					ledger: Ledger::BalanceSheet(B::Assets(A::CurrentAssets(
+						CurrentAssets::InternalBalance,
+					))),
+					amount: increase_amount,
+					debit_credit: Indicator::Debit,
+					reference_hash: ref_hash,
+					changed_on_blocknumber: current_block,
+					applicable_period_blocknumber: current_block_dupe,
+				},
+				Record {
+					primary_party: beneficiary.clone(),
+					counterparty: beneficiary.clone(),
+					ledger: Ledger::BalanceSheet(B::Equity(E::NetworkReserves)),
+					amount: increase_amount,
+					debit_credit: Indicator::Credit,
+					reference_hash: ref_hash,
+					changed_on_blocknumber: current_block,
+					applicable_period_blocknumber: current_block_dupe,
+				},
+			];
+
+			Self::handle_multiposting_amounts(&keys, PostingSource::Mint)?;
+
+			Ok(())
+		}
+
+		/// This function accounts for coins destroyed from the free balance of an account. The coin supply is reduced
+		/// by the balances pallet, and the accounting books it direct to expenses.
+		fn account_for_burn(loser: T::AccountId, amount: CurrencyBalanceOf<T>) -> DispatchResult {
+			// Take the amount and convert for use with accounting. Amount is of type T::Balance which is u128.
+			// As amount will always be positive, convert for use in accounting
+			let (increase_amount, decrease_amount) = Self::increase_decrease_amounts(amount)?;
+			// This sets the change block and the applicable posting period. For this context they will always be
+			// the same.
+			let current_block = frame_system::Pallet::<T>::block_number(); // For audit on change
+			let current_block_dupe = current_block; // Applicable period for accounting
+
+			let ref_hash: T::Hash = Self::reference_hash(PostingSource::Burn);
+
+			let keys = [
+				Record {
+					primary_party: loser.clone(),
+					counterparty: loser.clone(),
+					ledger: Ledger::BalanceSheet(B::Assets(A::CurrentAssets(
+						CurrentAssets::InternalBalance,
+					))),
+					amount: decrease_amount,
+					debit_credit: Indicator::Credit,
+					reference_hash: ref_hash,
+					changed_on_blocknumber: current_block,
+					applicable_period_blocknumber: current_block_dupe,
+				},
+				Record {
+					primary_party: loser.clone(),
+					counterparty: loser.clone(),
+					ledger: Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(
+						OPEX::CostOfGoodsSold(COGS::CryptoBurnWriteDown),
+					))),
+					amount: increase_amount,
+					debit_credit: Indicator::Debit,
+					reference_hash: ref_hash,
+					changed_on_blocknumber: current_block,
+					applicable_period_blocknumber: current_block_dupe,
+				},
+			];
+
+			Self::handle_multiposting_amounts(&keys, PostingSource::Burn)?;
+
+			Ok(())
+		}
+
+		/// This function accounts for coins slashed from the free balance of an account. The coin supply is reduced by
+		/// the balances pallet, and the accounting books it direct to expenses.
+		fn account_for_slash(loser: T::AccountId, amount: CurrencyBalanceOf<T>) -> DispatchResult {
+			// Take the amount and convert for use with accounting. Amount is of type T::Balance which is u128.
+			// As amount will always be positive, convert for use in accounting
+			let (increase_amount, decrease_amount) = Self::increase_decrease_amounts(amount)?;
+			// This sets the change block and the applicable posting period. For this context they will always be
+			// the same.
+			let current_block = frame_system::Pallet::<T>::block_number(); // For audit on change
+			let current_block_dupe = current_block; // Applicable period for accounting
+
+			let ref_hash: T::Hash = Self::reference_hash(PostingSource::Slash);
+
+			let keys = [
+				Record {
+					primary_party: loser.clone(),
+					counterparty: loser.clone(),
+					ledger: Ledger::BalanceSheet(B::Assets(A::CurrentAssets(
+						CurrentAssets::InternalBalance,
+					))),
+					amount: decrease_amount,
+					debit_credit: Indicator::Credit,
+					reference_hash: ref_hash,
+					changed_on_blocknumber: current_block,
+					applicable_period_blocknumber: current_block_dupe,
+				},
+				Record {
+					primary_party: loser.clone(),
+					counterparty: loser.clone(),
+					ledger: Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(
+						OPEX::TaxFinesPenalties(TFP::SlashedCoins),
+					))),
+					amount: increase_amount,
+					debit_credit: Indicator::Debit,
+					reference_hash: ref_hash,
+					changed_on_blocknumber: current_block,
+					applicable_period_blocknumber: current_block_dupe,
+				},
+			];
+
+			Self::handle_multiposting_amounts(&keys, PostingSource::Slash)?;
+
+			Ok(())
+		}
+
+		/// This function accounts for the balance lost by an account that is reaped below the existential deposit.
+		/// Both the free and the reserved balance are written off to expenses.
+		fn account_for_dust(
+			loser: T::AccountId,
+			free_amount: CurrencyBalanceOf<T>,
+			reserved_amount: CurrencyBalanceOf<T>,
+		) -> DispatchResult {
+			let (_, free_decrease) = Self::increase_decrease_amounts(free_amount)?;
+			let (_, reserved_decrease) = Self::increase_decrease_amounts(reserved_amount)?;
+			let total_increase = free_decrease
+				.checked_add(reserved_decrease)
+				.ok_or(Error::<T>::AmountOverflow)?
+				.checked_neg()
+				.ok_or(Error::<T>::AmountOverflow)?;
+			let current_block = frame_system::Pallet::<T>::block_number(); // For audit on change
+			let current_block_dupe = current_block; // Applicable period for accounting
+
+			let ref_hash: T::Hash = Self::reference_hash(PostingSource::Dust);
+
+			let mut keys = Vec::with_capacity(3);
+			for (ledger, amount) in [
+				(
+					Ledger::BalanceSheet(B::Assets(A::CurrentAssets(
+						CurrentAssets::InternalBalance,
+					))),
+					free_decrease,
+				),
+				(
+					Ledger::BalanceSheet(B::Assets(A::CurrentAssets(
+						CurrentAssets::InternalReservedBalance,
+					))),
+					reserved_decrease,
+				),
+			] {
+				if amount.is_zero() {
+					continue
+				}
+				keys.push(Record {
+					primary_party: loser.clone(),
+					counterparty: loser.clone(),
+					ledger,
+					amount,
+					debit_credit: Indicator::Credit,
+					reference_hash: ref_hash,
+					changed_on_blocknumber: current_block,
+					applicable_period_blocknumber: current_block_dupe,
+				});
+			}
+			keys.push(Record {
+				primary_party: loser.clone(),
+				counterparty: loser.clone(),
+				ledger: Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(
+					OPEX::CostOfGoodsSold(COGS::CryptoBurnWriteDown),
+				))),
+				amount: total_increase,
+				debit_credit: Indicator::Debit,
+				reference_hash: ref_hash,
+				changed_on_blocknumber: current_block,
+				applicable_period_blocknumber: current_block_dupe,
+			});
+
+			Self::handle_multiposting_amounts(&keys, PostingSource::Dust)?;
 
 			Ok(())
 		}
@@ -772,7 +2195,7 @@ mod pallet {
 		//     let current_block = frame_system::Pallet::<T>::block_number(); // For audit on change
 		//     let current_block_dupe = current_block; // Applicable period for accounting
 
-		//     let fee_hash: T::Hash = Self::get_pseudo_random_hash(loser.clone(), loser.clone());
+		//     let fee_hash: T::Hash = Self::reference_hash(PostingSource::Pallet { pallet_index: 0, call_index: 0 });
 
 		//     let netfee_address: T::AccountId = Self::get_netfees_account();
 
@@ -816,7 +2239,7 @@ mod pallet {
 		//     let current_block = frame_system::Pallet::<T>::block_number(); // For audit on change
 		//     let current_block_dupe = current_block; // Applicable period for accounting
 
-		//     let fee_hash: T::Hash = Self::get_pseudo_random_hash(author.clone(), author.clone());
+		//     let fee_hash: T::Hash = Self::reference_hash(PostingSource::Pallet { pallet_index: 0, call_index: 0 });
 
 		//     let netfee_address: T::AccountId = Self::get_netfees_account();
 
@@ -869,19 +2292,59 @@ mod pallet {
 		//     Ok(())
 		// }
 
-		fn get_pseudo_random_hash(sender: T::AccountId, recipient: T::AccountId) -> T::Hash {
-			let tuple = (sender.clone(), recipient);
-			let sender_encoded = sender.encode();
-			let (random_value, _) = T::RandomThing::random(&sender_encoded);
-			let input = (
-				tuple,
-				pallet_timestamp::Pallet::<T>::get(),
-				random_value,
-				frame_system::Pallet::<T>::extrinsic_index(),
-				frame_system::Pallet::<T>::block_number(),
-			);
+		/// Reverses the lines of an account under a posting index. Unlike the extrinsic, this also
+		/// reverses postings to the ledgers maintained by the network, so the caller must make the
+		/// corresponding change to the balances.
+		fn reverse_posting(
+			who: T::AccountId,
+			posting_index: PostingIndex,
+			ledger_count: u32,
+		) -> Result<PostingIndex, DispatchError> {
+			Self::do_reverse_posting(&who, posting_index, ledger_count, true)
+		}
+
+		fn reference_hash(source: PostingSource) -> T::Hash {
+			Self::hash_reference(source)
+		}
+
+		fn settled_balance(who: &T::AccountId, ledger: Ledger) -> LedgerBalance {
+			let internal_balance =
+				Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::InternalBalance)));
+			let internal_reserved_balance = Ledger::BalanceSheet(B::Assets(A::CurrentAssets(
+				CurrentAssets::InternalReservedBalance,
+			)));
+
+			let balance = Self::balance_by_ledger(who, ledger).unwrap_or_default();
+			let (free_change, reserved_change) = Self::deferred_changes(who);
+			if ledger == internal_balance {
+				balance.saturating_add(free_change)
+			} else if ledger == internal_reserved_balance {
+				balance.saturating_add(reserved_change)
+			} else {
+				balance
+			}
+		}
+
+		fn first_open_block(who: &T::AccountId, block: T::BlockNumber) -> T::BlockNumber {
+			match (Self::fiscal_calendar(who), Self::closed_until(who)) {
+				(Some(calendar), Some(closed_until))
+					if Self::is_period_closed(who, block, &calendar) =>
+					closed_until.saturating_add(One::one()),
+				_ => block,
+			}
+		}
+
+		fn posting_weight(records: u32) -> Weight {
+			T::WeightInfo::handle_multiposting_amounts(records)
+		}
 
-			T::Hashing::hash(input.encode().as_slice()) // default hash BlakeTwo256
+		/// Queues the recipe to be posted in `on_idle`.
+		fn defer_posting(posting: DeferredPostingOf<T>) {
+			let index = NextDeferredIndex::<T>::get();
+			Self::track_deferred_changes(&posting, true);
+			DeferredPostings::<T>::insert(index, posting);
+			NextDeferredIndex::<T>::put(index.wrapping_add(1));
+			Self::deposit_event(Event::PostingDeferred { index });
 		}
 	}
 }
@@ -0,0 +1,108 @@
+//                              Næ§@@@ÑÉ©
+//                        æ@@@@@@@@@@@@@@@@@@
+//                    Ñ@@@@?.?@@@@@@@@@@@@@@@@@@@N
+//                 ¶@@@@@?^%@@.=@@@@@@@@@@@@@@@@@@@@
+//               N@@@@@@@?^@@@»^@@@@@@@@@@@@@@@@@@@@@@
+//               @@@@@@@@?^@@@».............?@@@@@@@@@É
+//              Ñ@@@@@@@@?^@@@@@@@@@@@@@@@@@@'?@@@@@@@@Ñ
+//              @@@@@@@@@?^@@@»..............»@@@@@@@@@@
+//              @@@@@@@@@?^@@@»^@@@@@@@@@@@@@@@@@@@@@@@@
+//              @@@@@@@@@?^ë@@&.@@@@@@@@@@@@@@@@@@@@@@@@
+//               @@@@@@@@?^´@@@o.%@@@@@@@@@@@@@@@@@@@@©
+//                @@@@@@@?.´@@@@@ë.........*.±@@@@@@@æ
+//                 @@@@@@@@?´.I@@@@@@@@@@@@@@.&@@@@@N
+//                  N@@@@@@@@@@ë.*=????????=?@@@@@Ñ
+//                    @@@@@@@@@@@@@@@@@@@@@@@@@@@¶
+//                        É@@@@@@@@@@@@@@@@Ñ¶
+//                             Næ§@@@ÑÉ©
+
+// Copyright 2020 Chris D'Costa
+// This file is part of Totem Live Accounting.
+// Authors:
+// - Félix Daudré-Vignier   email: felix@totemaccounting.com
+// - Chris D'Costa          email: chris.dcosta@totemaccounting.com
+
+// Totem is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Totem is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Totem.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Migrations of the accounting pallet.
+
+use super::*;
+use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade, weights::Weight};
+#[cfg(feature = "try-runtime")]
+use sp_std::prelude::*;
+
+pub mod v2 {
+	use super::*;
+
+	/// Starts the reconciliation of the internal balance ledgers with the balances, and sets the storage
+	/// version to `2`. Returns the weight used.
+	fn start_v1_to_v2<T: Config>() -> Weight {
+		PendingReconciliation::<T>::put(Reconciliation::default());
+		StorageVersion::new(2).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(1, 2)
+	}
+
+	/// Reconciles the internal balance ledgers of every account with its free and reserved balances, in the
+	/// upgrade block. Only fit for chains with few accounts; otherwise use `ReconcileBalancesMultiBlock`.
+	pub struct ReconcileBalances<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for ReconcileBalances<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 1 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			start_v1_to_v2::<T>()
+				.saturating_add(Pallet::<T>::reconcile_accounts(u32::MAX, Weight::MAX))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 2,
+				"storage version was not set to 2"
+			);
+			ensure!(
+				!PendingReconciliation::<T>::exists(),
+				"the reconciliation did not reach every account"
+			);
+
+			Ok(())
+		}
+	}
+
+	/// Starts the reconciliation of the internal balance ledgers with the balances in the upgrade block,
+	/// and leaves it to `on_idle`, which reconciles up to `MaxReconciledAccountsPerBlock` accounts a block.
+	pub struct ReconcileBalancesMultiBlock<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for ReconcileBalancesMultiBlock<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 1 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			start_v1_to_v2::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 2,
+				"storage version was not set to 2"
+			);
+			ensure!(PendingReconciliation::<T>::exists(), "the reconciliation was not started");
+
+			Ok(())
+		}
+	}
+}
@@ -35,133 +35,158 @@
 // You should have received a copy of the GNU General Public License
 // along with Totem.  If not, see <http://www.gnu.org/licenses/>.
 
-#![cfg(any(test, feature = "mock"))]
+#![cfg(test)]
 
 use crate as pallet_accounting;
-use frame_support::parameter_types;
+use frame_support::{
+	parameter_types,
+	traits::{ConstBool, ConstU32, ConstU64, GenesisBuild},
+};
 use frame_system as system;
 use sp_core::H256;
 use sp_runtime::{
-    testing::Header,
-    traits::{BlakeTwo256, IdentityLookup},
+	testing::Header,
+	traits::{BlakeTwo256, Convert, IdentityLookup},
 };
-use totem_common::traits::accounting::Posting;
-use totem_common::types::accounting::Record;
+use totem_common::TryConvert;
+use totem_primitives::{accounting::*, LedgerBalance};
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
-    pub enum Test where
-        Block = Block,
-        NodeBlock = Block,
-        UncheckedExtrinsic = UncheckedExtrinsic,
-    {
-        System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-        Accounting: pallet_accounting::{Pallet, Storage, Event<T>},
-        Balances: pallet_balances_totem::{Pallet, Call, Storage, Event<T>},
-    }
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances_totem::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Accounting: pallet_accounting::{Pallet, Call, Storage, Event<T>, Config<T>},
+	}
 );
 
-impl Posting for Test {
-    // type Account = totem_common::types::Account;
-    type PostingIndex = totem_common::types::PostingIndex;
-    type LedgerBalance = totem_common::types::LedgerBalance;
-
-    fn handle_multiposting_amounts(
-        // keys: Vec<Record<AccountId, Hash, BlockNumber, Self::Account, Self::LedgerBalance>>,
-        keys: Vec<Record<AccountId, Ledger, Self::LedgerBalance, Indicator, Hash, BlockNumber>>,
-    ) -> DispatchResult {
-        unimplemented!()
-    }
-
-    fn account_for_fees(f: CoinAmount, p: AccountId) -> DispatchResult {
-        unimplemented!()
-    }
-
-    // fn account_for_burnt_fees(f: CoinAmount, p: AccountId) -> DispatchResult {
-    //     unimplemented!()
-    // }
-    
-    // fn distribute_fees_rewards(f: CoinAmount, p: AccountId) -> DispatchResult {
-    //     unimplemented!()
-    // }
-
-    fn get_escrow_account() -> AccountId {
-        unimplemented!()
-    }
-
-    fn get_netfees_account() -> AccountId {
-        unimplemented!()
-    }
-
-    fn get_pseudo_random_hash(s: AccountId, r: AccountId) -> Hash {
-        unimplemented!()
-    }
-}
-
 parameter_types! {
-    pub const BlockHashCount: u64 = 250;
-    pub const SS58Prefix: u16 = 2007;
+	pub const SS58Prefix: u16 = 2007;
 }
+
 impl system::Config for Test {
-    type BaseCallFilter = ();
-    type BlockWeights = ();
-    type BlockLength = ();
-    type DbWeight = ();
-    type RuntimeOrigin = RuntimeOrigin;
-    type RuntimeCall = RuntimeCall;
-    type Index = u64;
-    type BlockNumber = u64;
-    type Hash = H256;
-    type Hashing = BlakeTwo256;
-    type AccountId = u64;
-    type Lookup = IdentityLookup<Self::AccountId>;
-    type Header = Header;
-    type RuntimeEvent = RuntimeEvent;
-    type BlockHashCount = BlockHashCount;
-    type Version = ();
-    type PalletInfo = PalletInfo;
-    type AccountData = pallet_balances_totem::AccountData<u64>;
-    type OnNewAccount = ();
-    type OnKilledAccount = ();
-    type SystemWeightInfo = ();
-    type SS58Prefix = SS58Prefix;
-    type OnSetCode = ();
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances_totem::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
 }
 
-parameter_types! {
-    pub BlockWeights: frame_system::limits::BlockWeights =
-        frame_system::limits::BlockWeights::simple_max(1024);
-    pub static ExistentialDeposit: u64 = 0;
+impl pallet_balances_totem::Config for Test {
+	type Balance = u64;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = pallet_balances_totem::weights::SubstrateWeight<Test>;
+	type MaxLocks = ();
+	type MaxReserves = ConstU32<2>;
+	type ReserveIdentifier = [u8; 8];
+	type Accounting = Accounting;
 }
 
-parameter_types! {
-    pub const MaxReserves: u32 = 2;
+/// Converts the mock balance and account types for use in the accounting.
+pub struct MockConverter;
+
+impl TryConvert<u64, LedgerBalance> for MockConverter {
+	fn try_convert(x: u64) -> Option<LedgerBalance> {
+		Some(x.into())
+	}
 }
-impl pallet_balances_totem::Config for Test {
-    type Balance = u64;
-    type DustRemoval = ();
-    type RuntimeEvent = RuntimeEvent;
-    type ExistentialDeposit = ExistentialDeposit;
-    type AccountStore = system::Pallet<Test>;
-    type MaxLocks = ();
-    type MaxReserves = MaxReserves;
-    type ReserveIdentifier = [u8; 8];
-    type WeightInfo = ();
-    type Accounting = Test;
+
+impl Convert<[u8; 32], u64> for MockConverter {
+	fn convert(a: [u8; 32]) -> u64 {
+		let mut account = [0; 8];
+		account.copy_from_slice(&a[..8]);
+		u64::from_le_bytes(account)
+	}
 }
 
 impl pallet_accounting::Config for Test {
-    type RuntimeEvent = RuntimeEvent;
-    type AccountingConverter = totem_common::converter::Converter;
+	type RuntimeEvent = RuntimeEvent;
+	type AccountingConverter = MockConverter;
+	type Currency = Balances;
+	type EnforcePartyBalance = ConstBool<true>;
+	type MaxJournalLines = ConstU32<10>;
+	type MaxDeferredPostingsPerBlock = ConstU32<2>;
+	type MaxReconciledAccountsPerBlock = ConstU32<2>;
+	type WeightInfo = ();
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    system::GenesisConfig::default()
-        .build_storage::<Test>()
-        .unwrap()
-        .into()
+	let storage = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let mut ext = sp_io::TestExternalities::new(storage);
+	// Events are not deposited on the genesis block.
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+// Build genesis storage with the given free balances, opened in the accounting as well.
+pub fn new_test_ext_with_balances(balances: &[(u64, u64)]) -> sp_io::TestExternalities {
+	let opening_balances = balances
+		.iter()
+		.flat_map(|(who, amount)| {
+			[
+				(
+					*who,
+					Ledger::BalanceSheet(B::Assets(A::CurrentAssets(
+						CurrentAssets::InternalBalance,
+					))),
+					LedgerBalance::from(*amount),
+					Indicator::Debit,
+				),
+				(
+					*who,
+					Ledger::BalanceSheet(B::Equity(E::NetworkReserves)),
+					LedgerBalance::from(*amount),
+					Indicator::Credit,
+				),
+			]
+		})
+		.collect();
+	new_test_ext_with_opening_balances(balances, opening_balances)
+}
+
+// Build genesis storage with the given free balances and opening balances.
+pub fn new_test_ext_with_opening_balances(
+	balances: &[(u64, u64)],
+	opening_balances: Vec<(u64, Ledger, LedgerBalance, Indicator)>,
+) -> sp_io::TestExternalities {
+	let mut storage = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances_totem::GenesisConfig::<Test> { balances: balances.to_vec() }
+		.assimilate_storage(&mut storage)
+		.unwrap();
+	pallet_accounting::GenesisConfig::<Test> { opening_balances }
+		.assimilate_storage(&mut storage)
+		.unwrap();
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
 }
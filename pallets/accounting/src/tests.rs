@@ -1,25 +1,1629 @@
 #![cfg(test)]
 
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	migration::v2::{ReconcileBalances, ReconcileBalancesMultiBlock},
+	mock::*,
+	BalanceByLedger, DeferredChanges, DeferredPostings, Error, Event, FailedPostingCount,
+	FailedPostings, GlobalLedger, OpenedBooks, PendingReconciliation, PostingDetail, PostingNumber,
+	PostingReferences,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::DispatchResult,
+	storage::{unhashed, well_known_keys},
+	traits::{
+		BalanceStatus, Currency, ExistenceRequirement, GetStorageVersion, Hooks, OnRuntimeUpgrade,
+		ReservableCurrency, StorageVersion, WithdrawReasons,
+	},
+	weights::Weight,
+	BoundedVec,
+};
+use sp_core::H256;
+use totem_primitives::{accounting::*, LedgerBalance, PostingIndex};
+
+type AccountingRecord = Record<u64, H256, u64>;
+
+const LINES: usize = 6;
+
+fn internal_balance() -> Ledger {
+	Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::InternalBalance)))
+}
+
+fn internal_reserved_balance() -> Ledger {
+	Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::InternalReservedBalance)))
+}
+
+fn network_reserves() -> Ledger {
+	Ledger::BalanceSheet(B::Equity(E::NetworkReserves))
+}
+
+fn bank_charges() -> Ledger {
+	Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::BankCharges))))
+}
+
+fn balance_adjustment_suspense() -> Ledger {
+	Ledger::BalanceSheet(B::Equity(E::BalanceAdjustmentSuspense))
+}
+
+fn accrued_expenses() -> Ledger {
+	Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(
+		CurrentLiabilities::OtherAccruedExpensesPayable,
+	)))
+}
+
+fn journal_line(
+	ledger: Ledger,
+	amount: LedgerBalance,
+	debit_credit: Indicator,
+) -> JournalLine<u64, u64> {
+	JournalLine { ledger, counterparty: 2, amount, debit_credit, applicable_period_blocknumber: 1 }
+}
+
+fn record(
+	party: u64,
+	ledger: Ledger,
+	amount: LedgerBalance,
+	debit_credit: Indicator,
+) -> AccountingRecord {
+	Record {
+		primary_party: party,
+		counterparty: party,
+		ledger,
+		amount,
+		debit_credit,
+		reference_hash: H256::repeat_byte(1),
+		changed_on_blocknumber: 1,
+		applicable_period_blocknumber: 1,
+	}
+}
+
+/// Debit/credit pairs, each pair on its own account.
+fn balanced_records(lines: usize) -> Vec<AccountingRecord> {
+	(0..lines)
+		.map(|i| {
+			let party = (i / 2) as u64 + 1;
+			match i % 2 {
+				0 => record(party, internal_balance(), 10, Indicator::Debit),
+				_ => record(party, network_reserves(), 10, Indicator::Credit),
+			}
+		})
+		.collect()
+}
+
+fn post(keys: &[AccountingRecord]) -> DispatchResult {
+	<Accounting as Posting<u64, H256, u64, u64>>::handle_multiposting_amounts(
+		keys,
+		PostingSource::Pallet { pallet_index: 0, call_index: 0 },
+	)
+}
+
+#[test]
+fn multiposting_posts_every_record() {
+	new_test_ext().execute_with(|| {
+		let keys = balanced_records(LINES);
+
+		assert_ok!(post(&keys));
+
+		let posting_index: PostingIndex = 1;
+		assert_eq!(PostingNumber::<Test>::get(), posting_index);
+		for key in &keys {
+			assert_eq!(BalanceByLedger::<Test>::get(key.primary_party, key.ledger), Some(10));
+			assert!(PostingDetail::<Test>::contains_key(
+				(key.primary_party, key.ledger),
+				posting_index
+			));
+		}
+		assert_eq!(GlobalLedger::<Test>::get(internal_balance()), 30);
+		assert_eq!(GlobalLedger::<Test>::get(network_reserves()), 30);
+	});
+}
+
+#[test]
+fn multiposting_uses_the_next_posting_index() {
+	new_test_ext().execute_with(|| {
+		let keys = balanced_records(2);
+
+		assert_ok!(post(&keys));
+		assert_ok!(post(&keys));
+
+		assert_eq!(PostingNumber::<Test>::get(), 2);
+		assert_eq!(BalanceByLedger::<Test>::get(1, internal_balance()), Some(20));
+		assert!(PostingDetail::<Test>::contains_key((1, internal_balance()), 1));
+		assert!(PostingDetail::<Test>::contains_key((1, internal_balance()), 2));
+	});
+}
+
+#[test]
+fn multiposting_rolls_back_on_ledger_overflow_at_every_line() {
+	for failing_line in 0..LINES {
+		new_test_ext().execute_with(|| {
+			let keys = balanced_records(LINES);
+			let key = &keys[failing_line];
+			BalanceByLedger::<Test>::insert(key.primary_party, key.ledger, LedgerBalance::MAX);
+
+			assert_noop!(post(&keys), Error::<Test>::BalanceValueOverflow);
+		});
+	}
+}
+
+#[test]
+fn multiposting_rolls_back_on_global_ledger_overflow_at_every_line() {
+	for failing_line in 0..LINES {
+		new_test_ext().execute_with(|| {
+			let mut keys = balanced_records(LINES);
+			GlobalLedger::<Test>::insert(internal_reserved_balance(), LedgerBalance::MAX);
+			keys[failing_line].ledger = internal_reserved_balance();
+
+			assert_noop!(post(&keys), Error::<Test>::BalanceValueOverflow);
+		});
+	}
+}
+
+#[test]
+fn multiposting_fails_on_posting_index_overflow() {
+	new_test_ext().execute_with(|| {
+		PostingNumber::<Test>::put(PostingIndex::MAX);
+
+		assert_noop!(post(&balanced_records(LINES)), Error::<Test>::PostingIndexOverflow);
+	});
+}
+
+#[test]
+fn multiposting_rejects_unbalanced_records() {
+	new_test_ext().execute_with(|| {
+		let mut keys = balanced_records(LINES);
+		keys[LINES - 1].amount = 11;
+
+		assert_noop!(post(&keys), Error::<Test>::UnbalancedPosting);
+	});
+}
+
+#[test]
+fn multiposting_rejects_records_with_the_same_indicator() {
+	new_test_ext().execute_with(|| {
+		let keys = [
+			record(1, internal_balance(), 10, Indicator::Debit),
+			record(1, network_reserves(), 10, Indicator::Debit),
+		];
+
+		assert_noop!(post(&keys), Error::<Test>::UnbalancedPosting);
+	});
+}
+
+#[test]
+fn multiposting_rejects_records_unbalanced_for_a_party() {
+	new_test_ext().execute_with(|| {
+		let keys = [
+			record(1, internal_balance(), 10, Indicator::Debit),
+			record(2, network_reserves(), 10, Indicator::Credit),
+		];
+
+		assert_noop!(post(&keys), Error::<Test>::UnbalancedPartyPosting);
+	});
+}
+
+#[test]
+fn multiposting_rejects_two_records_on_the_same_ledger_of_a_party() {
+	new_test_ext().execute_with(|| {
+		let keys = [
+			record(1, internal_balance(), 10, Indicator::Debit),
+			record(1, internal_balance(), -4, Indicator::Credit),
+			record(1, network_reserves(), 6, Indicator::Credit),
+		];
+
+		assert_noop!(post(&keys), Error::<Test>::DuplicateLedger);
+	});
+}
+
+#[test]
+fn multiposting_accepts_the_same_ledger_for_different_parties() {
+	new_test_ext().execute_with(|| {
+		let keys = [
+			record(1, internal_balance(), 10, Indicator::Debit),
+			record(1, network_reserves(), 10, Indicator::Credit),
+			record(2, internal_balance(), 10, Indicator::Debit),
+			record(2, network_reserves(), 10, Indicator::Credit),
+		];
+
+		assert_ok!(post(&keys));
+	});
+}
+
+#[test]
+fn multiposting_accepts_decreasing_amounts_that_balance() {
+	new_test_ext().execute_with(|| {
+		let keys = [
+			record(1, internal_balance(), -10, Indicator::Credit),
+			record(1, network_reserves(), -10, Indicator::Debit),
+		];
+
+		assert_ok!(post(&keys));
+		assert_eq!(BalanceByLedger::<Test>::get(1, internal_balance()), Some(-10));
+	});
+}
+
+#[test]
+fn post_journal_entry_posts_to_the_books_of_the_caller() {
+	new_test_ext().execute_with(|| {
+		let lines = BoundedVec::truncate_from(vec![
+			journal_line(bank_charges(), 25, Indicator::Debit),
+			journal_line(accrued_expenses(), 25, Indicator::Credit),
+		]);
+
+		assert_ok!(Accounting::post_journal_entry(RuntimeOrigin::signed(1), lines));
+
+		assert_eq!(BalanceByLedger::<Test>::get(1, bank_charges()), Some(25));
+		assert_eq!(BalanceByLedger::<Test>::get(1, accrued_expenses()), Some(25));
+		assert_eq!(BalanceByLedger::<Test>::get(2, bank_charges()), None);
+		let detail = PostingDetail::<Test>::get((1, bank_charges()), 1).unwrap();
+		assert_eq!(detail.counterparty, 2);
+	});
+}
+
+#[test]
+fn post_journal_entry_rejects_an_empty_entry() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Accounting::post_journal_entry(RuntimeOrigin::signed(1), BoundedVec::default()),
+			Error::<Test>::EmptyJournalEntry
+		);
+	});
+}
+
+#[test]
+fn post_journal_entry_rejects_protocol_ledgers() {
+	new_test_ext().execute_with(|| {
+		for ledger in [internal_balance(), internal_reserved_balance(), network_reserves()] {
+			let lines = BoundedVec::truncate_from(vec![
+				journal_line(ledger, 25, Indicator::Debit),
+				journal_line(accrued_expenses(), 25, Indicator::Credit),
+			]);
+
+			assert_noop!(
+				Accounting::post_journal_entry(RuntimeOrigin::signed(1), lines),
+				Error::<Test>::ProtocolLedger
+			);
+		}
+	});
+}
+
+#[test]
+fn post_journal_entry_rejects_unbalanced_lines() {
+	new_test_ext().execute_with(|| {
+		let lines = BoundedVec::truncate_from(vec![
+			journal_line(bank_charges(), 25, Indicator::Debit),
+			journal_line(accrued_expenses(), 20, Indicator::Credit),
+		]);
+
+		assert_noop!(
+			Accounting::post_journal_entry(RuntimeOrigin::signed(1), lines),
+			Error::<Test>::UnbalancedPosting
+		);
+	});
+}
+
+#[test]
+fn post_journal_entry_rejects_two_lines_on_the_same_ledger() {
+	new_test_ext().execute_with(|| {
+		let lines = BoundedVec::truncate_from(vec![
+			journal_line(bank_charges(), 10, Indicator::Debit),
+			journal_line(bank_charges(), 15, Indicator::Debit),
+			journal_line(accrued_expenses(), 25, Indicator::Credit),
+		]);
+
+		assert_noop!(
+			Accounting::post_journal_entry(RuntimeOrigin::signed(1), lines),
+			Error::<Test>::DuplicateLedger
+		);
+	});
+}
 
 #[test]
-fn it_works_for_default_value() {
-    new_test_ext().execute_with(|| {
-        // Dispatch a signed extrinsic.
-        assert_ok!(Accounting::do_something(Origin::signed(1), 42));
-        // Read pallet storage and assert an expected result.
-        assert_eq!(Accounting::something(), Some(42));
-    });
+fn post_journal_entry_rejects_negative_amounts() {
+	new_test_ext().execute_with(|| {
+		// A debit to the bank against a negative credit to sales would raise the assets and lower the
+		// income.
+		let lines = BoundedVec::truncate_from(vec![
+			journal_line(bank_current_account(), 100, Indicator::Debit),
+			journal_line(sales_of_services(), -100, Indicator::Credit),
+		]);
+
+		assert_noop!(
+			Accounting::post_journal_entry(RuntimeOrigin::signed(1), lines),
+			Error::<Test>::InvalidJournalAmount
+		);
+	});
 }
 
 #[test]
-fn correct_error_for_none_value() {
-    new_test_ext().execute_with(|| {
-        // Ensure the expected error is thrown when no value is present.
-        assert_noop!(
-            Accounting::cause_error(Origin::signed(1)),
-            Error::<Test>::NoneValue
-        );
-    });
+fn post_journal_entry_takes_the_direction_of_a_line_from_its_side() {
+	new_test_ext().execute_with(|| {
+		let lines = BoundedVec::truncate_from(vec![
+			journal_line(bank_charges(), 25, Indicator::Debit),
+			journal_line(bank_current_account(), 25, Indicator::Credit),
+		]);
+
+		assert_ok!(Accounting::post_journal_entry(RuntimeOrigin::signed(1), lines));
+
+		assert_eq!(BalanceByLedger::<Test>::get(1, bank_charges()), Some(25));
+		assert_eq!(BalanceByLedger::<Test>::get(1, bank_current_account()), Some(-25));
+		assert_eq!(PostingDetail::<Test>::get((1, bank_current_account()), 1).unwrap().amount, -25);
+
+		assert_ok!(Accounting::reverse_posting(RuntimeOrigin::signed(1), 1, 2));
+
+		assert_eq!(BalanceByLedger::<Test>::get(1, bank_charges()), Some(0));
+		assert_eq!(BalanceByLedger::<Test>::get(1, bank_current_account()), Some(0));
+		let reversal = PostingDetail::<Test>::get((1, bank_current_account()), 2).unwrap();
+		assert_eq!((reversal.amount, reversal.debit_credit), (25, Indicator::Debit));
+	});
+}
+
+#[test]
+fn reverse_posting_undoes_a_journal_entry() {
+	new_test_ext().execute_with(|| {
+		let lines = BoundedVec::truncate_from(vec![
+			journal_line(bank_charges(), 25, Indicator::Debit),
+			journal_line(accrued_expenses(), 25, Indicator::Credit),
+		]);
+		assert_ok!(Accounting::post_journal_entry(RuntimeOrigin::signed(1), lines));
+
+		assert_ok!(Accounting::reverse_posting(RuntimeOrigin::signed(1), 1, 2));
+
+		assert_eq!(BalanceByLedger::<Test>::get(1, bank_charges()), Some(0));
+		assert_eq!(BalanceByLedger::<Test>::get(1, accrued_expenses()), Some(0));
+		let reversal = PostingDetail::<Test>::get((1, bank_charges()), 2).unwrap();
+		assert_eq!(reversal.debit_credit, Indicator::Credit);
+		assert_eq!(Accounting::reversed_by(1, 1), Some(2));
+		assert_eq!(Accounting::reversal_of(1, 2), Some(1));
+	});
+}
+
+#[test]
+fn reverse_posting_only_reverses_once() {
+	new_test_ext().execute_with(|| {
+		let lines = BoundedVec::truncate_from(vec![
+			journal_line(bank_charges(), 25, Indicator::Debit),
+			journal_line(accrued_expenses(), 25, Indicator::Credit),
+		]);
+		assert_ok!(Accounting::post_journal_entry(RuntimeOrigin::signed(1), lines));
+		assert_ok!(Accounting::reverse_posting(RuntimeOrigin::signed(1), 1, 2));
+
+		assert_noop!(
+			Accounting::reverse_posting(RuntimeOrigin::signed(1), 1, 2),
+			Error::<Test>::AlreadyReversed
+		);
+	});
+}
+
+#[test]
+fn reverse_posting_fails_for_postings_of_other_accounts() {
+	new_test_ext().execute_with(|| {
+		let lines = BoundedVec::truncate_from(vec![
+			journal_line(bank_charges(), 25, Indicator::Debit),
+			journal_line(accrued_expenses(), 25, Indicator::Credit),
+		]);
+		assert_ok!(Accounting::post_journal_entry(RuntimeOrigin::signed(1), lines));
+
+		assert_noop!(
+			Accounting::reverse_posting(RuntimeOrigin::signed(2), 1, 2),
+			Error::<Test>::UnknownPosting
+		);
+	});
+}
+
+#[test]
+fn reverse_posting_requires_the_ledger_count_of_the_caller() {
+	new_test_ext().execute_with(|| {
+		let lines = BoundedVec::truncate_from(vec![
+			journal_line(bank_charges(), 25, Indicator::Debit),
+			journal_line(accrued_expenses(), 25, Indicator::Credit),
+		]);
+		assert_ok!(Accounting::post_journal_entry(RuntimeOrigin::signed(1), lines));
+
+		assert_noop!(
+			Accounting::reverse_posting(RuntimeOrigin::signed(1), 1, 1),
+			Error::<Test>::InvalidLedgerCount
+		);
+	});
+}
+
+#[test]
+fn reverse_posting_call_refuses_protocol_ledgers() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(post(&balanced_records(2)));
+
+		assert_noop!(
+			Accounting::reverse_posting(RuntimeOrigin::signed(1), 1, 2),
+			Error::<Test>::ProtocolLedger
+		);
+	});
+}
+
+#[test]
+fn reverse_posting_trait_method_undoes_decreases() {
+	new_test_ext().execute_with(|| {
+		let keys = [
+			record(1, internal_balance(), 10, Indicator::Debit),
+			record(1, network_reserves(), 10, Indicator::Credit),
+		];
+		assert_ok!(post(&keys));
+		let keys = [
+			record(1, internal_balance(), -4, Indicator::Credit),
+			record(1, network_reserves(), -4, Indicator::Debit),
+		];
+		assert_ok!(post(&keys));
+
+		assert_eq!(<Accounting as Posting<u64, H256, u64, u64>>::reverse_posting(1, 2, 2), Ok(3));
+
+		assert_eq!(BalanceByLedger::<Test>::get(1, internal_balance()), Some(10));
+		assert_eq!(BalanceByLedger::<Test>::get(1, network_reserves()), Some(10));
+		assert_eq!(GlobalLedger::<Test>::get(internal_balance()), 10);
+	});
+}
+
+fn fiscal_calendar() -> FiscalCalendar<u64> {
+	FiscalCalendar { period_length: 10, year_end: 19, periods_per_year: 2 }
+}
+
+fn journal_entry_for_period(
+	period: u64,
+) -> BoundedVec<JournalLine<u64, u64>, <Test as crate::Config>::MaxJournalLines> {
+	let mut lines = vec![
+		journal_line(bank_charges(), 25, Indicator::Debit),
+		journal_line(accrued_expenses(), 25, Indicator::Credit),
+	];
+	for line in lines.iter_mut() {
+		line.applicable_period_blocknumber = period;
+	}
+	BoundedVec::truncate_from(lines)
+}
+
+#[test]
+fn fiscal_calendar_finds_the_end_of_a_period() {
+	let calendar = fiscal_calendar();
+
+	assert_eq!(calendar.period_end(0), 9);
+	assert_eq!(calendar.period_end(9), 9);
+	assert_eq!(calendar.period_end(10), 19);
+	assert_eq!(calendar.period_end(19), 19);
+	assert_eq!(calendar.period_end(20), 29);
+	assert_eq!(calendar.period_end(35), 39);
+}
+
+#[test]
+fn fiscal_calendar_finds_the_end_of_a_year() {
+	let calendar = fiscal_calendar();
+
+	assert!(calendar.is_year_end(19));
+	assert!(calendar.is_year_end(39));
+	assert!(!calendar.is_year_end(9));
+	assert!(!calendar.is_year_end(29));
+	assert!(!calendar.is_year_end(20));
+}
+
+#[test]
+fn set_fiscal_calendar_rejects_an_empty_period_or_year() {
+	new_test_ext().execute_with(|| {
+		for calendar in [
+			FiscalCalendar { period_length: 0, year_end: 19, periods_per_year: 2 },
+			FiscalCalendar { period_length: 10, year_end: 19, periods_per_year: 0 },
+		] {
+			assert_noop!(
+				Accounting::set_fiscal_calendar(RuntimeOrigin::signed(1), calendar),
+				Error::<Test>::InvalidFiscalCalendar
+			);
+		}
+	});
+}
+
+#[test]
+fn close_period_requires_a_fiscal_calendar() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(25);
+
+		assert_noop!(
+			Accounting::close_period(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::NoFiscalCalendar
+		);
+	});
+}
+
+#[test]
+fn close_period_requires_the_period_to_have_ended() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(25);
+		assert_ok!(Accounting::set_fiscal_calendar(RuntimeOrigin::signed(1), fiscal_calendar()));
+
+		assert_noop!(
+			Accounting::close_period(RuntimeOrigin::signed(1), 25),
+			Error::<Test>::PeriodNotEnded
+		);
+	});
+}
+
+#[test]
+fn closed_periods_refuse_postings() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(25);
+		assert_ok!(Accounting::set_fiscal_calendar(RuntimeOrigin::signed(1), fiscal_calendar()));
+
+		assert_ok!(Accounting::close_period(RuntimeOrigin::signed(1), 5));
+
+		assert_eq!(Accounting::closed_until(1), Some(9));
+		assert_noop!(
+			Accounting::post_journal_entry(RuntimeOrigin::signed(1), journal_entry_for_period(5)),
+			Error::<Test>::PeriodClosed
+		);
+		assert_ok!(Accounting::post_journal_entry(
+			RuntimeOrigin::signed(1),
+			journal_entry_for_period(15)
+		));
+		assert_ok!(Accounting::post_journal_entry(
+			RuntimeOrigin::signed(2),
+			journal_entry_for_period(5)
+		));
+		assert_noop!(
+			Accounting::close_period(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::PeriodAlreadyClosed
+		);
+		assert_noop!(
+			Accounting::set_fiscal_calendar(RuntimeOrigin::signed(1), fiscal_calendar()),
+			Error::<Test>::FiscalCalendarLocked
+		);
+	});
+}
+
+#[test]
+fn reopened_periods_accept_postings_until_closed_again() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(25);
+		assert_ok!(Accounting::set_fiscal_calendar(RuntimeOrigin::signed(1), fiscal_calendar()));
+		assert_ok!(Accounting::close_period(RuntimeOrigin::signed(1), 15));
+		assert_noop!(
+			Accounting::reopen_period(RuntimeOrigin::signed(1), 25),
+			Error::<Test>::PeriodNotClosed
+		);
+
+		assert_ok!(Accounting::reopen_period(RuntimeOrigin::signed(1), 5));
+
+		assert_ok!(Accounting::post_journal_entry(
+			RuntimeOrigin::signed(1),
+			journal_entry_for_period(5)
+		));
+		assert_noop!(
+			Accounting::post_journal_entry(RuntimeOrigin::signed(1), journal_entry_for_period(15)),
+			Error::<Test>::PeriodClosed
+		);
+
+		assert_ok!(Accounting::close_period(RuntimeOrigin::signed(1), 5));
+
+		assert_noop!(
+			Accounting::post_journal_entry(RuntimeOrigin::signed(1), journal_entry_for_period(5)),
+			Error::<Test>::PeriodClosed
+		);
+	});
+}
+
+#[test]
+fn the_first_open_block_follows_the_closed_periods() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(25);
+		let first_open_block = |who, block| {
+			<Accounting as Posting<u64, H256, u64, u64>>::first_open_block(&who, block)
+		};
+		assert_eq!(first_open_block(1, 5), 5);
+
+		assert_ok!(Accounting::set_fiscal_calendar(RuntimeOrigin::signed(1), fiscal_calendar()));
+		assert_ok!(Accounting::close_period(RuntimeOrigin::signed(1), 15));
+
+		assert_eq!(first_open_block(1, 5), 20);
+		assert_eq!(first_open_block(1, 19), 20);
+		assert_eq!(first_open_block(1, 22), 22);
+		assert_eq!(first_open_block(2, 5), 5);
+
+		assert_ok!(Accounting::reopen_period(RuntimeOrigin::signed(1), 5));
+
+		assert_eq!(first_open_block(1, 5), 5);
+		assert_eq!(first_open_block(1, 15), 20);
+	});
+}
+
+fn sales_of_services() -> Ledger {
+	Ledger::ProfitLoss(P::Income(I::Sales(Sales::SalesOfServices)))
+}
+
+fn bank_current_account() -> Ledger {
+	Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::BankCurrentAccount)))
+}
+
+fn retained_earnings() -> Ledger {
+	Ledger::BalanceSheet(B::Equity(E::RetainedEarnings(RetainedEarnings::RetainedEarnings)))
+}
+
+#[test]
+fn close_year_books_the_profit_to_retained_earnings() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(25);
+		assert_ok!(Accounting::set_fiscal_calendar(RuntimeOrigin::signed(1), fiscal_calendar()));
+		assert_ok!(Accounting::post_journal_entry(
+			RuntimeOrigin::signed(1),
+			journal_entry_for_period(15)
+		));
+		let mut sale = vec![
+			journal_line(bank_current_account(), 100, Indicator::Debit),
+			journal_line(sales_of_services(), 100, Indicator::Credit),
+		];
+		sale.iter_mut().for_each(|line| line.applicable_period_blocknumber = 15);
+		assert_ok!(Accounting::post_journal_entry(
+			RuntimeOrigin::signed(1),
+			BoundedVec::truncate_from(sale)
+		));
+
+		assert_ok!(Accounting::close_year(RuntimeOrigin::signed(1), 19, 4));
+
+		assert_eq!(BalanceByLedger::<Test>::get(1, bank_charges()), Some(0));
+		assert_eq!(BalanceByLedger::<Test>::get(1, sales_of_services()), Some(0));
+		assert_eq!(BalanceByLedger::<Test>::get(1, retained_earnings()), Some(75));
+		assert_eq!(BalanceByLedger::<Test>::get(1, bank_current_account()), Some(100));
+		assert_eq!(Accounting::year_end_balance((1, 19), bank_charges()), Some(25));
+		assert_eq!(Accounting::year_end_balance((1, 19), sales_of_services()), Some(100));
+		assert_eq!(Accounting::year_end_balance((1, 19), bank_current_account()), None);
+		assert_eq!(Accounting::year_end_result(1, 19), Some(75));
+		assert_eq!(Accounting::closed_until(1), Some(19));
+		let closing = PostingDetail::<Test>::get((1, retained_earnings()), 3).unwrap();
+		assert_eq!(closing.debit_credit, Indicator::Credit);
+		assert_eq!(closing.applicable_period_blocknumber, 19);
+	});
+}
+
+#[test]
+fn close_year_books_a_loss_to_retained_earnings() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(25);
+		assert_ok!(Accounting::set_fiscal_calendar(RuntimeOrigin::signed(1), fiscal_calendar()));
+		assert_ok!(Accounting::post_journal_entry(
+			RuntimeOrigin::signed(1),
+			journal_entry_for_period(15)
+		));
+
+		assert_ok!(Accounting::close_year(RuntimeOrigin::signed(1), 19, 2));
+
+		assert_eq!(BalanceByLedger::<Test>::get(1, bank_charges()), Some(0));
+		assert_eq!(BalanceByLedger::<Test>::get(1, retained_earnings()), Some(-25));
+		assert_eq!(Accounting::year_end_result(1, 19), Some(-25));
+	});
+}
+
+#[test]
+fn close_year_only_closes_once() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(25);
+		assert_ok!(Accounting::set_fiscal_calendar(RuntimeOrigin::signed(1), fiscal_calendar()));
+		assert_ok!(Accounting::post_journal_entry(
+			RuntimeOrigin::signed(1),
+			journal_entry_for_period(15)
+		));
+		assert_ok!(Accounting::close_year(RuntimeOrigin::signed(1), 19, 2));
+
+		assert_noop!(
+			Accounting::close_year(RuntimeOrigin::signed(1), 19, 2),
+			Error::<Test>::PeriodAlreadyClosed
+		);
+		assert_ok!(Accounting::reopen_period(RuntimeOrigin::signed(1), 19));
+		assert_noop!(
+			Accounting::close_year(RuntimeOrigin::signed(1), 19, 2),
+			Error::<Test>::YearAlreadyClosed
+		);
+	});
+}
+
+#[test]
+fn close_year_only_closes_the_end_of_a_year() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(25);
+		assert_ok!(Accounting::set_fiscal_calendar(RuntimeOrigin::signed(1), fiscal_calendar()));
+		assert_ok!(Accounting::post_journal_entry(
+			RuntimeOrigin::signed(1),
+			journal_entry_for_period(5)
+		));
+
+		assert_noop!(
+			Accounting::close_year(RuntimeOrigin::signed(1), 5, 2),
+			Error::<Test>::NotYearEnd
+		);
+	});
+}
+
+#[test]
+fn close_year_requires_the_ledger_count_of_the_caller() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(25);
+		assert_ok!(Accounting::set_fiscal_calendar(RuntimeOrigin::signed(1), fiscal_calendar()));
+		assert_ok!(Accounting::post_journal_entry(
+			RuntimeOrigin::signed(1),
+			journal_entry_for_period(15)
+		));
+
+		assert_noop!(
+			Accounting::close_year(RuntimeOrigin::signed(1), 19, 1),
+			Error::<Test>::InvalidLedgerCount
+		);
+		assert_ok!(Accounting::close_year(RuntimeOrigin::signed(1), 19, 2));
+	});
+}
+
+#[test]
+fn trial_balance_agrees_for_balanced_books() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(post(&[
+			record(1, bank_charges(), 40, Indicator::Debit),
+			record(1, accrued_expenses(), 40, Indicator::Credit),
+		]));
+		// A credit to an asset ledger is shown on the credit side of the trial balance.
+		assert_ok!(post(&[
+			record(1, internal_balance(), -15, Indicator::Credit),
+			record(1, accrued_expenses(), -15, Indicator::Debit),
+		]));
+
+		let trial_balance = Accounting::trial_balance(&1);
+
+		assert_eq!(trial_balance.lines.len(), 3);
+		assert!(trial_balance.lines.contains(&TrialBalanceLine {
+			ledger: internal_balance(),
+			debit: 0,
+			credit: 15
+		}));
+		assert!(trial_balance.lines.contains(&TrialBalanceLine {
+			ledger: accrued_expenses(),
+			debit: 0,
+			credit: 25
+		}));
+		assert_eq!(trial_balance.total_debit, 40);
+		assert_eq!(trial_balance.total_credit, 40);
+	});
+}
+
+#[test]
+fn postings_are_paged_from_the_last_posting_of_the_previous_page() {
+	new_test_ext().execute_with(|| {
+		let keys = balanced_records(2);
+		for _ in 0..5 {
+			assert_ok!(post(&keys));
+		}
+
+		let indexes = |start_after, limit| {
+			Accounting::postings(1, internal_balance(), start_after, limit)
+				.into_iter()
+				.map(|(index, _)| index)
+				.collect::<Vec<_>>()
+		};
+
+		let mut pages = Vec::new();
+		let mut start_after = None;
+		loop {
+			let page = indexes(start_after, 2);
+			assert!(page.len() <= 2);
+			match page.last() {
+				Some(last) => start_after = Some(*last),
+				None => break,
+			}
+			pages.push(page);
+		}
+		assert_eq!(pages.iter().map(Vec::len).collect::<Vec<_>>(), vec![2, 2, 1]);
+		let mut postings = pages.concat();
+		postings.sort();
+		assert_eq!(postings, vec![1, 2, 3, 4, 5]);
+		assert_eq!(indexes(None, 10).len(), 5);
+		assert!(Accounting::postings(1, bank_charges(), None, 10).is_empty());
+	});
+}
+
+#[test]
+fn global_ledger_balances_list_every_ledger() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(post(&balanced_records(LINES)));
+
+		let mut balances = Accounting::global_ledger_balances();
+		balances.sort_by_key(|(ledger, _)| *ledger == network_reserves());
+
+		assert_eq!(balances, vec![(internal_balance(), 30), (network_reserves(), 30)]);
+	});
+}
+
+fn set_extrinsic_index(extrinsic_index: u32) {
+	unhashed::put(well_known_keys::EXTRINSIC_INDEX, &extrinsic_index);
+}
+
+#[test]
+fn postings_record_where_they_were_made() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(3);
+		set_extrinsic_index(2);
+		let lines = BoundedVec::truncate_from(vec![
+			journal_line(bank_charges(), 25, Indicator::Debit),
+			journal_line(accrued_expenses(), 25, Indicator::Credit),
+		]);
+
+		assert_ok!(Accounting::post_journal_entry(RuntimeOrigin::signed(1), lines));
+
+		let reference = PostingReferences::<Test>::get(1).unwrap();
+		assert_eq!(reference.block_number, 3);
+		assert_eq!(reference.extrinsic_index, Some(2));
+		assert_eq!(reference.event_index, 0);
+		assert_eq!(reference.source, PostingSource::JournalEntry);
+		assert_eq!(Accounting::postings_by_extrinsic(3, 2), vec![1]);
+		assert!(Accounting::postings_by_extrinsic(3, 1).is_empty());
+	});
+}
+
+#[test]
+fn reference_hashes_are_deterministic_and_traceable() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(3);
+		set_extrinsic_index(2);
+		let expected = <Accounting as Posting<u64, H256, u64, u64>>::reference_hash(
+			PostingSource::JournalEntry,
+		);
+		let lines = BoundedVec::truncate_from(vec![
+			journal_line(bank_charges(), 25, Indicator::Debit),
+			journal_line(accrued_expenses(), 25, Indicator::Credit),
+		]);
+
+		assert_ok!(Accounting::post_journal_entry(RuntimeOrigin::signed(1), lines));
+
+		let detail = PostingDetail::<Test>::get((1, bank_charges()), 1).unwrap();
+		assert_eq!(detail.reference_hash, expected);
+		assert_eq!(Accounting::postings_by_reference(expected), vec![1]);
+		assert_ne!(
+			expected,
+			<Accounting as Posting<u64, H256, u64, u64>>::reference_hash(PostingSource::Transfer)
+		);
+	});
+}
+
+#[test]
+fn reversals_share_the_reference_of_the_original_posting() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(3);
+		let lines = BoundedVec::truncate_from(vec![
+			journal_line(bank_charges(), 25, Indicator::Debit),
+			journal_line(accrued_expenses(), 25, Indicator::Credit),
+		]);
+		assert_ok!(Accounting::post_journal_entry(RuntimeOrigin::signed(1), lines));
+
+		assert_ok!(Accounting::reverse_posting(RuntimeOrigin::signed(1), 1, 2));
+
+		let detail = PostingDetail::<Test>::get((1, bank_charges()), 1).unwrap();
+		assert_eq!(Accounting::postings_by_reference(detail.reference_hash), vec![1, 2]);
+		assert_eq!(PostingReferences::<Test>::get(2).unwrap().source, PostingSource::Reversal);
+	});
+}
+
+fn crypto_burn_write_down() -> Ledger {
+	Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::CostOfGoodsSold(
+		COGS::CryptoBurnWriteDown,
+	))))
+}
+
+fn slashed_coins() -> Ledger {
+	Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::TaxFinesPenalties(
+		TFP::SlashedCoins,
+	))))
+}
+
+fn network_transaction_fees() -> Ledger {
+	Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::Blockchain(
+		InternalAccounting::NetworkTransactionFees,
+	)))))
+}
+
+#[test]
+fn mint_and_burn_follow_the_free_balance() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(<Accounting as Posting<u64, H256, u64, u64>>::account_for_mint(1, 50));
+		assert_ok!(<Accounting as Posting<u64, H256, u64, u64>>::account_for_burn(1, 20));
+
+		assert_eq!(BalanceByLedger::<Test>::get(1, internal_balance()), Some(30));
+		assert_eq!(BalanceByLedger::<Test>::get(1, network_reserves()), Some(50));
+		assert_eq!(BalanceByLedger::<Test>::get(1, crypto_burn_write_down()), Some(20));
+	});
+}
+
+#[test]
+fn slash_books_the_free_balance_to_slashed_coins() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(<Accounting as Posting<u64, H256, u64, u64>>::account_for_mint(1, 50));
+
+		assert_ok!(<Accounting as Posting<u64, H256, u64, u64>>::account_for_slash(1, 15));
+
+		assert_eq!(BalanceByLedger::<Test>::get(1, internal_balance()), Some(35));
+		assert_eq!(BalanceByLedger::<Test>::get(1, slashed_coins()), Some(15));
+	});
+}
+
+#[test]
+fn dust_writes_off_the_free_and_the_reserved_balance() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(<Accounting as Posting<u64, H256, u64, u64>>::account_for_mint(1, 10));
+		assert_ok!(<Accounting as Posting<u64, H256, u64, u64>>::set_reserve_amount(1, 4));
+
+		assert_ok!(<Accounting as Posting<u64, H256, u64, u64>>::account_for_dust(1, 6, 4));
+
+		assert_eq!(BalanceByLedger::<Test>::get(1, internal_balance()), Some(0));
+		assert_eq!(BalanceByLedger::<Test>::get(1, internal_reserved_balance()), Some(0));
+		assert_eq!(BalanceByLedger::<Test>::get(1, crypto_burn_write_down()), Some(10));
+	});
+}
+
+#[test]
+fn fee_refund_moves_the_refund_out_of_the_fee() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(<Accounting as Posting<u64, H256, u64, u64>>::account_for_mint(1, 100));
+		assert_ok!(<Accounting as Posting<u64, H256, u64, u64>>::account_for_fees(10, 1));
+		assert_ok!(<Accounting as Posting<u64, H256, u64, u64>>::account_for_mint(1, 4));
+
+		assert_ok!(<Accounting as Posting<u64, H256, u64, u64>>::account_for_fee_refund(4, 1));
+
+		assert_eq!(BalanceByLedger::<Test>::get(1, internal_balance()), Some(94));
+		assert_eq!(BalanceByLedger::<Test>::get(1, network_reserves()), Some(100));
+		assert_eq!(BalanceByLedger::<Test>::get(1, network_transaction_fees()), Some(6));
+	});
+}
+
+fn slashed_funds_income() -> Ledger {
+	Ledger::ProfitLoss(P::Income(I::OtherOperatingIncome(OOPIN::BlockchainSlashedFundsIncome)))
+}
+
+/// Checks that the internal balance ledgers of an account follow its balances.
+fn assert_ledgers_follow_balances(who: u64) {
+	assert_eq!(
+		BalanceByLedger::<Test>::get(who, internal_balance()).unwrap_or_default(),
+		LedgerBalance::from(Balances::free_balance(who))
+	);
+	assert_eq!(
+		BalanceByLedger::<Test>::get(who, internal_reserved_balance()).unwrap_or_default(),
+		LedgerBalance::from(Balances::reserved_balance(who))
+	);
+}
+
+#[test]
+fn genesis_opens_the_balances_in_the_ledgers() {
+	new_test_ext_with_balances(&[(1, 100), (2, 50)]).execute_with(|| {
+		assert_eq!(BalanceByLedger::<Test>::get(1, internal_balance()), Some(100));
+		assert_eq!(BalanceByLedger::<Test>::get(1, network_reserves()), Some(100));
+		assert_eq!(BalanceByLedger::<Test>::get(2, internal_balance()), Some(50));
+		assert_eq!(GlobalLedger::<Test>::get(internal_balance()), 150);
+		assert_eq!(GlobalLedger::<Test>::get(network_reserves()), 150);
+		assert_eq!(PostingNumber::<Test>::get(), 0);
+		assert_ledgers_follow_balances(1);
+		assert_ledgers_follow_balances(2);
+		assert_ok!(Accounting::do_try_state());
+	});
+}
+
+fn opening_line(
+	who: u64,
+	ledger: Ledger,
+	amount: LedgerBalance,
+	debit_credit: Indicator,
+) -> (u64, Ledger, LedgerBalance, Indicator) {
+	(who, ledger, amount, debit_credit)
+}
+
+/// A bank account, an overpaid liability and the equity they amount to.
+fn company_books(who: u64) -> Vec<(u64, Ledger, LedgerBalance, Indicator)> {
+	vec![
+		opening_line(who, bank_current_account(), 70, Indicator::Debit),
+		opening_line(who, accrued_expenses(), 10, Indicator::Debit),
+		opening_line(who, retained_earnings(), 80, Indicator::Credit),
+	]
+}
+
+#[test]
+fn genesis_opens_any_ledger_on_either_side() {
+	let mut opening_balances = company_books(3);
+	opening_balances.push(opening_line(1, internal_balance(), 100, Indicator::Debit));
+	opening_balances.push(opening_line(1, network_reserves(), 100, Indicator::Credit));
+
+	new_test_ext_with_opening_balances(&[(1, 100)], opening_balances).execute_with(|| {
+		assert_eq!(BalanceByLedger::<Test>::get(3, bank_current_account()), Some(70));
+		assert_eq!(BalanceByLedger::<Test>::get(3, accrued_expenses()), Some(-10));
+		assert_eq!(BalanceByLedger::<Test>::get(3, retained_earnings()), Some(80));
+		assert_eq!(BalanceByLedger::<Test>::get(1, internal_balance()), Some(100));
+		assert_eq!(OpenedBooks::<Test>::get(1), Some(0));
+		assert_eq!(OpenedBooks::<Test>::get(3), Some(0));
+		assert!(PostingDetail::<Test>::contains_key((3, accrued_expenses()), 0));
+		assert_ok!(Accounting::do_try_state());
+	});
+}
+
+#[test]
+#[should_panic(expected = "the opening balances of each account must balance")]
+fn genesis_refuses_unbalanced_opening_balances() {
+	let mut opening_balances = company_books(3);
+	opening_balances.pop();
+
+	new_test_ext_with_opening_balances(&[], opening_balances);
+}
+
+#[test]
+#[should_panic(expected = "an account can only open each ledger once")]
+fn genesis_refuses_a_ledger_opened_twice() {
+	let mut opening_balances = company_books(3);
+	opening_balances.push(opening_line(3, bank_current_account(), 5, Indicator::Debit));
+	opening_balances.push(opening_line(3, retained_earnings(), 5, Indicator::Credit));
+
+	new_test_ext_with_opening_balances(&[], opening_balances);
+}
+
+#[test]
+#[should_panic(expected = "the opening internal balances of an account must be its balances")]
+fn genesis_refuses_internal_balances_that_differ_from_the_balances() {
+	new_test_ext_with_opening_balances(
+		&[(1, 100)],
+		vec![
+			opening_line(1, internal_balance(), 90, Indicator::Debit),
+			opening_line(1, network_reserves(), 90, Indicator::Credit),
+		],
+	);
+}
+
+#[test]
+#[should_panic(expected = "the opening internal balances of an account must be its balances")]
+fn genesis_refuses_balances_missing_from_the_opening_balances() {
+	new_test_ext_with_opening_balances(&[(1, 100)], company_books(3));
+}
+
+fn opening_lines(
+	lines: Vec<(u64, Ledger, LedgerBalance, Indicator)>,
+) -> BoundedVec<(Ledger, LedgerBalance, Indicator), <Test as crate::Config>::MaxJournalLines> {
+	lines
+		.into_iter()
+		.map(|(_, ledger, amount, debit_credit)| (ledger, amount, debit_credit))
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+#[test]
+fn root_opens_the_books_of_an_account_once() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Accounting::open_books(RuntimeOrigin::signed(3), 3, opening_lines(company_books(3))),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(Accounting::open_books(
+			RuntimeOrigin::root(),
+			3,
+			opening_lines(company_books(3))
+		));
+
+		assert_eq!(BalanceByLedger::<Test>::get(3, bank_current_account()), Some(70));
+		assert_eq!(BalanceByLedger::<Test>::get(3, accrued_expenses()), Some(-10));
+		assert_eq!(BalanceByLedger::<Test>::get(3, retained_earnings()), Some(80));
+		assert_eq!(OpenedBooks::<Test>::get(3), Some(1));
+		assert_eq!(
+			PostingReferences::<Test>::get(1).unwrap().source,
+			PostingSource::OpeningBalances
+		);
+		System::assert_last_event(Event::<Test>::BooksOpened { who: 3, posting_index: 1 }.into());
+
+		assert_noop!(
+			Accounting::open_books(RuntimeOrigin::root(), 3, opening_lines(company_books(3))),
+			Error::<Test>::BooksAlreadyOpened
+		);
+	});
+}
+
+#[test]
+fn open_books_validates_the_opening_balances() {
+	new_test_ext().execute_with(|| {
+		let mut unbalanced = company_books(3);
+		unbalanced.pop();
+		assert_noop!(
+			Accounting::open_books(RuntimeOrigin::root(), 3, opening_lines(unbalanced)),
+			Error::<Test>::UnbalancedPosting
+		);
+
+		let mut negative = company_books(3);
+		negative[1].2 = -10;
+		negative[1].3 = Indicator::Credit;
+		assert_noop!(
+			Accounting::open_books(RuntimeOrigin::root(), 3, opening_lines(negative)),
+			Error::<Test>::InvalidOpeningBalance
+		);
+
+		let protocol = vec![
+			opening_line(3, internal_balance(), 10, Indicator::Debit),
+			opening_line(3, retained_earnings(), 10, Indicator::Credit),
+		];
+		assert_noop!(
+			Accounting::open_books(RuntimeOrigin::root(), 3, opening_lines(protocol)),
+			Error::<Test>::ProtocolLedger
+		);
+
+		assert_noop!(
+			Accounting::open_books(RuntimeOrigin::root(), 3, opening_lines(vec![])),
+			Error::<Test>::EmptyJournalEntry
+		);
+	});
+}
+
+#[test]
+fn transfers_are_posted_for_both_parties() {
+	new_test_ext_with_balances(&[(1, 100), (2, 50)]).execute_with(|| {
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(1), 2, 30));
+
+		assert_eq!(BalanceByLedger::<Test>::get(1, internal_balance()), Some(70));
+		assert_eq!(BalanceByLedger::<Test>::get(1, network_reserves()), Some(70));
+		assert_eq!(BalanceByLedger::<Test>::get(2, internal_balance()), Some(80));
+		assert_eq!(BalanceByLedger::<Test>::get(2, network_reserves()), Some(80));
+		assert_ledgers_follow_balances(1);
+		assert_ledgers_follow_balances(2);
+		assert_ok!(Accounting::do_try_state());
+	});
+}
+
+#[test]
+fn transaction_payments_are_posted_as_fees() {
+	new_test_ext_with_balances(&[(1, 100)]).execute_with(|| {
+		assert_ok!(Balances::withdraw(
+			&1,
+			10,
+			WithdrawReasons::TRANSACTION_PAYMENT,
+			ExistenceRequirement::KeepAlive
+		));
+
+		assert_eq!(BalanceByLedger::<Test>::get(1, internal_balance()), Some(90));
+		assert_eq!(BalanceByLedger::<Test>::get(1, network_transaction_fees()), Some(10));
+		assert_ledgers_follow_balances(1);
+	});
+}
+
+#[test]
+fn other_withdrawals_are_posted_as_burns() {
+	new_test_ext_with_balances(&[(1, 100)]).execute_with(|| {
+		assert_ok!(Balances::withdraw(
+			&1,
+			10,
+			WithdrawReasons::TRANSFER,
+			ExistenceRequirement::KeepAlive
+		));
+
+		assert_eq!(BalanceByLedger::<Test>::get(1, crypto_burn_write_down()), Some(10));
+		assert_eq!(BalanceByLedger::<Test>::get(1, network_transaction_fees()), None);
+		assert_ledgers_follow_balances(1);
+	});
+}
+
+#[test]
+fn reserves_and_unreserves_move_between_the_internal_ledgers() {
+	new_test_ext_with_balances(&[(1, 100)]).execute_with(|| {
+		assert_ok!(Balances::reserve(&1, 40));
+
+		assert_eq!(BalanceByLedger::<Test>::get(1, internal_balance()), Some(60));
+		assert_eq!(BalanceByLedger::<Test>::get(1, internal_reserved_balance()), Some(40));
+
+		assert_eq!(Balances::unreserve(&1, 15), 0);
+
+		assert_eq!(BalanceByLedger::<Test>::get(1, internal_balance()), Some(75));
+		assert_eq!(BalanceByLedger::<Test>::get(1, internal_reserved_balance()), Some(25));
+		assert_ledgers_follow_balances(1);
+		assert_ok!(Accounting::do_try_state());
+	});
+}
+
+#[test]
+fn slashes_are_posted_to_slashed_coins() {
+	new_test_ext_with_balances(&[(1, 100)]).execute_with(|| {
+		assert_ok!(Balances::reserve(&1, 30));
+
+		let (_, remaining) = Balances::slash(&1, 40);
+		assert_eq!(remaining, 0);
+
+		assert_eq!(BalanceByLedger::<Test>::get(1, internal_balance()), Some(30));
+		assert_eq!(BalanceByLedger::<Test>::get(1, slashed_coins()), Some(40));
+
+		let (_, remaining) = Balances::slash_reserved(&1, 10);
+		assert_eq!(remaining, 0);
+
+		assert_eq!(BalanceByLedger::<Test>::get(1, internal_reserved_balance()), Some(20));
+		assert_eq!(BalanceByLedger::<Test>::get(1, slashed_coins()), Some(50));
+		assert_ledgers_follow_balances(1);
+	});
+}
+
+#[test]
+fn slashes_whose_posting_fails_are_deferred() {
+	new_test_ext_with_balances(&[(1, 100)]).execute_with(|| {
+		assert_ok!(Balances::reserve(&1, 30));
+		BalanceByLedger::<Test>::insert(1, slashed_coins(), LedgerBalance::MAX);
+
+		let (_, remaining) = Balances::slash(&1, 80);
+
+		assert_eq!(remaining, 0);
+		assert_eq!((Balances::free_balance(1), Balances::reserved_balance(1)), (0, 20));
+		assert_eq!(DeferredPostings::<Test>::iter().count(), 2);
+		assert_eq!(Accounting::deferred_changes(1), (-70, -10));
+
+		BalanceByLedger::<Test>::remove(1, slashed_coins());
+		Accounting::on_idle(1, Weight::MAX);
+
+		assert_eq!(BalanceByLedger::<Test>::get(1, slashed_coins()), Some(80));
+		assert_ledgers_follow_balances(1);
+		assert_ok!(Accounting::do_try_state());
+	});
+}
+
+#[test]
+fn repatriated_reserves_are_reassigned_to_the_beneficiary() {
+	new_test_ext_with_balances(&[(1, 100), (2, 50)]).execute_with(|| {
+		assert_ok!(Balances::reserve(&1, 40));
+
+		assert_ok!(Balances::repatriate_reserved(&1, &2, 30, BalanceStatus::Free));
+		assert_ok!(Balances::repatriate_reserved(&1, &2, 10, BalanceStatus::Reserved));
+
+		assert_eq!(BalanceByLedger::<Test>::get(1, internal_reserved_balance()), Some(0));
+		assert_eq!(BalanceByLedger::<Test>::get(1, slashed_coins()), Some(40));
+		assert_eq!(BalanceByLedger::<Test>::get(2, internal_balance()), Some(80));
+		assert_eq!(BalanceByLedger::<Test>::get(2, internal_reserved_balance()), Some(10));
+		assert_eq!(BalanceByLedger::<Test>::get(2, slashed_funds_income()), Some(40));
+		assert_ledgers_follow_balances(1);
+		assert_ledgers_follow_balances(2);
+		assert_ok!(Accounting::do_try_state());
+	});
+}
+
+#[test]
+fn failed_postings_roll_back_the_balance_change() {
+	new_test_ext_with_balances(&[(1, 100), (2, 50)]).execute_with(|| {
+		BalanceByLedger::<Test>::insert(2, internal_balance(), LedgerBalance::MAX);
+
+		assert_noop!(
+			Balances::transfer(RuntimeOrigin::signed(1), 2, 30),
+			Error::<Test>::BalanceValueOverflow
+		);
+		BalanceByLedger::<Test>::insert(1, internal_reserved_balance(), LedgerBalance::MAX);
+		assert_noop!(Balances::reserve(&1, 10), Error::<Test>::BalanceValueOverflow);
+	});
+}
+
+fn defer_unreserve(beneficiary: u64, amount: u64) {
+	<Accounting as Posting<u64, H256, u64, u64>>::defer_posting(DeferredPosting::Unreserve {
+		beneficiary,
+		amount,
+	});
+}
+
+#[test]
+fn deferred_postings_are_posted_on_idle() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(<Accounting as Posting<u64, H256, u64, u64>>::set_reserve_amount(1, 10));
+		defer_unreserve(1, 10);
+		assert!(DeferredPostings::<Test>::contains_key(0));
+
+		Accounting::on_idle(1, Weight::MAX);
+
+		assert!(!DeferredPostings::<Test>::contains_key(0));
+		assert_eq!(BalanceByLedger::<Test>::get(1, internal_balance()), Some(0));
+		assert_eq!(BalanceByLedger::<Test>::get(1, internal_reserved_balance()), Some(0));
+		assert_eq!(FailedPostingCount::<Test>::get(), 0);
+	});
+}
+
+#[test]
+fn on_idle_posts_no_more_than_the_limit_or_the_weight_allows() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..3 {
+			defer_unreserve(1, 1);
+		}
+
+		Accounting::on_idle(1, Weight::MAX);
+		assert_eq!(DeferredPostings::<Test>::iter().count(), 1);
+
+		assert_eq!(Accounting::on_idle(1, Weight::zero()), Weight::zero());
+		assert_eq!(DeferredPostings::<Test>::iter().count(), 1);
+	});
+}
+
+#[test]
+fn failed_deferred_postings_can_be_retried_by_root() {
+	new_test_ext().execute_with(|| {
+		BalanceByLedger::<Test>::insert(1, internal_balance(), LedgerBalance::MAX);
+		defer_unreserve(1, 10);
+
+		Accounting::on_idle(1, Weight::MAX);
+
+		assert!(FailedPostings::<Test>::contains_key(0));
+		assert_eq!(FailedPostingCount::<Test>::get(), 1);
+		assert_noop!(
+			Accounting::retry_failed_posting(RuntimeOrigin::signed(1), 0),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Accounting::retry_failed_posting(RuntimeOrigin::root(), 0),
+			Error::<Test>::BalanceValueOverflow
+		);
+
+		BalanceByLedger::<Test>::insert(1, internal_balance(), 0);
+		assert_ok!(Accounting::retry_failed_posting(RuntimeOrigin::root(), 0));
+
+		assert!(!FailedPostings::<Test>::contains_key(0));
+		assert_eq!(FailedPostingCount::<Test>::get(), 0);
+		assert_eq!(BalanceByLedger::<Test>::get(1, internal_balance()), Some(10));
+		assert_noop!(
+			Accounting::retry_failed_posting(RuntimeOrigin::root(), 0),
+			Error::<Test>::UnknownFailedPosting
+		);
+	});
+}
+
+#[test]
+fn settled_balance_includes_queued_deferred_postings() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(post(&balanced_records(LINES)));
+		assert_ok!(<Accounting as Posting<u64, H256, u64, u64>>::set_reserve_amount(1, 10));
+		defer_unreserve(1, 4);
+
+		let settled =
+			|ledger| <Accounting as Posting<u64, H256, u64, u64>>::settled_balance(&1, ledger);
+		assert_eq!(settled(internal_balance()), 4);
+		assert_eq!(settled(internal_reserved_balance()), 6);
+
+		Accounting::on_idle(1, Weight::MAX);
+
+		assert_eq!(settled(internal_balance()), 4);
+		assert_eq!(settled(internal_reserved_balance()), 6);
+	});
+}
+
+#[test]
+fn deferred_changes_follow_the_queue() {
+	new_test_ext().execute_with(|| {
+		defer_unreserve(1, 4);
+		defer_unreserve(1, 6);
+
+		assert_eq!(Accounting::deferred_changes(1), (10, -10));
+		assert_ok!(Accounting::do_try_state());
+
+		Accounting::on_idle(1, Weight::MAX);
+
+		assert!(!DeferredChanges::<Test>::contains_key(1));
+		assert_ok!(Accounting::do_try_state());
+	});
+}
+
+#[test]
+fn try_state_detects_drifted_deferred_changes() {
+	new_test_ext().execute_with(|| {
+		defer_unreserve(1, 4);
+		DeferredChanges::<Test>::insert(1, (5, -5));
+
+		assert!(Accounting::do_try_state().is_err());
+	});
+}
+
+#[test]
+fn try_state_holds_after_postings() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(post(&balanced_records(LINES)));
+
+		assert_ok!(Accounting::do_try_state());
+	});
+}
+
+#[test]
+fn try_state_detects_global_ledger_drift() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(post(&balanced_records(LINES)));
+		GlobalLedger::<Test>::insert(internal_balance(), 31);
+
+		assert!(Accounting::do_try_state().is_err());
+	});
+}
+
+#[test]
+fn try_state_detects_a_wrong_failed_posting_count() {
+	new_test_ext().execute_with(|| {
+		FailedPostingCount::<Test>::put(1);
+
+		assert!(Accounting::do_try_state().is_err());
+	});
+}
+
+/// Sets the free balance of an account without posting it, as on a chain where some flows were never posted.
+fn set_unposted_balance(who: u64, amount: u64) {
+	frame_system::Account::<Test>::mutate(who, |account| {
+		account.providers = 1;
+		account.data.free = amount;
+	});
+}
+
+fn drift_balances(balances: &[(u64, u64)]) {
+	for (who, amount) in balances {
+		set_unposted_balance(*who, *amount);
+	}
+	StorageVersion::new(1).put::<Accounting>();
+}
+
+#[test]
+fn reconciliation_adjusts_the_ledgers_to_the_balances() {
+	new_test_ext().execute_with(|| {
+		// Account 2 has 10 in its internal balance ledger, but only 5 coins.
+		assert_ok!(post(
+			&balanced_records(2)
+				.into_iter()
+				.map(|mut key| {
+					key.primary_party = 2;
+					key.counterparty = 2;
+					key
+				})
+				.collect::<Vec<_>>()
+		));
+		drift_balances(&[(1, 100), (2, 5)]);
+		assert_ok!(Balances::reserve(&1, 30));
+
+		ReconcileBalances::<Test>::on_runtime_upgrade();
+
+		assert_eq!(BalanceByLedger::<Test>::get(1, internal_balance()), Some(70));
+		assert_eq!(BalanceByLedger::<Test>::get(1, internal_reserved_balance()), Some(30));
+		assert_eq!(BalanceByLedger::<Test>::get(1, balance_adjustment_suspense()), Some(100));
+		assert_eq!(BalanceByLedger::<Test>::get(2, internal_balance()), Some(5));
+		assert_eq!(BalanceByLedger::<Test>::get(2, balance_adjustment_suspense()), Some(-5));
+		assert_eq!(GlobalLedger::<Test>::get(balance_adjustment_suspense()), 95);
+		assert_eq!(Accounting::on_chain_storage_version(), 2);
+		assert!(!PendingReconciliation::<Test>::exists());
+		System::assert_last_event(
+			Event::<Test>::BalancesReconciled { accounts: 2, adjusted: 2, failed: 0 }.into(),
+		);
+		assert_ok!(Accounting::do_try_state());
+	});
+}
+
+#[test]
+fn reconciliation_leaves_reconciled_accounts_alone() {
+	new_test_ext().execute_with(|| {
+		drift_balances(&[(1, 10)]);
+		assert_ok!(<Accounting as Posting<u64, H256, u64, u64>>::account_for_mint(1, 10));
+
+		ReconcileBalances::<Test>::on_runtime_upgrade();
+
+		assert_eq!(BalanceByLedger::<Test>::get(1, balance_adjustment_suspense()), None);
+		System::assert_last_event(
+			Event::<Test>::BalancesReconciled { accounts: 1, adjusted: 0, failed: 0 }.into(),
+		);
+	});
+}
+
+#[test]
+fn reconciliation_runs_once() {
+	new_test_ext().execute_with(|| {
+		drift_balances(&[(1, 10)]);
+		ReconcileBalances::<Test>::on_runtime_upgrade();
+		set_unposted_balance(1, 20);
+
+		ReconcileBalances::<Test>::on_runtime_upgrade();
+
+		assert_eq!(BalanceByLedger::<Test>::get(1, internal_balance()), Some(10));
+	});
+}
+
+#[test]
+fn multi_block_reconciliation_continues_on_idle() {
+	new_test_ext().execute_with(|| {
+		drift_balances(&[(1, 10), (2, 20), (3, 30)]);
+
+		ReconcileBalancesMultiBlock::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Accounting::on_chain_storage_version(), 2);
+		assert_eq!(Accounting::pending_reconciliation().map(|progress| progress.accounts), Some(0));
+
+		Accounting::on_idle(1, Weight::MAX);
+
+		assert_eq!(Accounting::pending_reconciliation().map(|progress| progress.accounts), Some(2));
+
+		Accounting::on_idle(2, Weight::MAX);
+
+		assert!(!PendingReconciliation::<Test>::exists());
+		for (who, amount) in [(1, 10), (2, 20), (3, 30)] {
+			assert_eq!(BalanceByLedger::<Test>::get(who, internal_balance()), Some(amount));
+		}
+		System::assert_last_event(
+			Event::<Test>::BalancesReconciled { accounts: 3, adjusted: 3, failed: 0 }.into(),
+		);
+	});
+}
+
+#[test]
+fn reconciliation_clears_the_ledgers_of_reaped_accounts() {
+	new_test_ext().execute_with(|| {
+		// Account 4 holds 10 in its internal balance ledger but has no account in the balances.
+		assert_ok!(<Accounting as Posting<u64, H256, u64, u64>>::account_for_mint(4, 10));
+		drift_balances(&[(1, 10)]);
+		assert_ok!(<Accounting as Posting<u64, H256, u64, u64>>::account_for_mint(1, 10));
+		assert!(!frame_system::Account::<Test>::contains_key(4));
+
+		ReconcileBalancesMultiBlock::<Test>::on_runtime_upgrade();
+		Accounting::on_idle(1, Weight::MAX);
+		Accounting::on_idle(2, Weight::MAX);
+
+		assert!(!PendingReconciliation::<Test>::exists());
+		assert_eq!(BalanceByLedger::<Test>::get(4, internal_balance()), Some(0));
+		assert_eq!(BalanceByLedger::<Test>::get(4, balance_adjustment_suspense()), Some(-10));
+		assert_eq!(BalanceByLedger::<Test>::get(1, balance_adjustment_suspense()), None);
+		System::assert_last_event(
+			Event::<Test>::BalancesReconciled { accounts: 2, adjusted: 1, failed: 0 }.into(),
+		);
+		assert_ok!(Accounting::do_try_state());
+	});
+}
+
+#[test]
+fn reconciliation_counts_queued_deferred_postings() {
+	new_test_ext().execute_with(|| {
+		drift_balances(&[(1, 10)]);
+		assert_ok!(<Accounting as Posting<u64, H256, u64, u64>>::account_for_mint(1, 10));
+		assert_ok!(<Accounting as Posting<u64, H256, u64, u64>>::set_reserve_amount(1, 4));
+		defer_unreserve(1, 4);
+
+		ReconcileBalances::<Test>::on_runtime_upgrade();
+
+		assert_eq!(BalanceByLedger::<Test>::get(1, balance_adjustment_suspense()), None);
+	});
+}
+
+#[test]
+fn reconciliation_counts_failed_deferred_postings_until_they_are_retried() {
+	new_test_ext().execute_with(|| {
+		drift_balances(&[(1, 10)]);
+		assert_ok!(<Accounting as Posting<u64, H256, u64, u64>>::account_for_mint(1, 10));
+		assert_ok!(<Accounting as Posting<u64, H256, u64, u64>>::set_reserve_amount(1, 4));
+		defer_unreserve(1, 4);
+		BalanceByLedger::<Test>::insert(1, internal_balance(), LedgerBalance::MAX);
+		Accounting::on_idle(1, Weight::MAX);
+		BalanceByLedger::<Test>::insert(1, internal_balance(), 6);
+
+		assert!(FailedPostings::<Test>::contains_key(0));
+		assert_eq!(Accounting::deferred_changes(1), (4, -4));
+		assert_ok!(Accounting::do_try_state());
+
+		ReconcileBalances::<Test>::on_runtime_upgrade();
+
+		assert_eq!(BalanceByLedger::<Test>::get(1, balance_adjustment_suspense()), None);
+
+		assert_ok!(Accounting::retry_failed_posting(RuntimeOrigin::root(), 0));
+
+		assert_eq!(BalanceByLedger::<Test>::get(1, internal_balance()), Some(10));
+		assert_eq!(BalanceByLedger::<Test>::get(1, internal_reserved_balance()), Some(0));
+		assert!(!DeferredChanges::<Test>::contains_key(1));
+		assert_ok!(Accounting::do_try_state());
+	});
+}
+
+#[test]
+fn failed_deferred_unreserves_keep_the_settled_ledgers_in_line_with_the_balances() {
+	new_test_ext_with_balances(&[(1, 100)]).execute_with(|| {
+		assert_ok!(Balances::reserve(&1, 40));
+		BalanceByLedger::<Test>::insert(1, internal_balance(), LedgerBalance::MAX);
+		assert_eq!(Balances::unreserve(&1, 15), 0);
+		Accounting::on_idle(1, Weight::MAX);
+		BalanceByLedger::<Test>::insert(1, internal_balance(), 60);
+
+		assert!(FailedPostings::<Test>::contains_key(0));
+		let settled =
+			|ledger| <Accounting as Posting<u64, H256, u64, u64>>::settled_balance(&1, ledger);
+		assert_eq!(settled(internal_balance()), LedgerBalance::from(Balances::free_balance(1)));
+		assert_eq!(
+			settled(internal_reserved_balance()),
+			LedgerBalance::from(Balances::reserved_balance(1))
+		);
+		assert_ok!(Accounting::do_try_state());
+
+		assert_ok!(Accounting::retry_failed_posting(RuntimeOrigin::root(), 0));
+
+		assert_ledgers_follow_balances(1);
+		assert_eq!(Accounting::deferred_changes(1), (0, 0));
+		assert_ok!(Accounting::do_try_state());
+	});
 }
@@ -0,0 +1,239 @@
+#![cfg(test)]
+
+//! Randomized sequences of balance operations, checked after every step against an in-memory
+//! double-entry model of the postings the operations should make.
+
+use crate::{mock::*, BalanceByLedger, GlobalLedger};
+use frame_support::traits::{BalanceStatus, Currency, ExistenceRequirement, ReservableCurrency};
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_pcg::Pcg64;
+use sp_std::collections::btree_map::BTreeMap;
+use totem_primitives::{accounting::*, Account, LedgerBalance};
+
+const ACCOUNTS: [u64; 4] = [1, 2, 3, 4];
+const OPENING_BALANCE: u64 = 100;
+const SEEDS: u64 = 32;
+const STEPS: usize = 100;
+
+fn internal_balance() -> Ledger {
+	Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::InternalBalance)))
+}
+
+fn internal_reserved_balance() -> Ledger {
+	Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::InternalReservedBalance)))
+}
+
+fn network_reserves() -> Ledger {
+	Ledger::BalanceSheet(B::Equity(E::NetworkReserves))
+}
+
+fn slashed_coins() -> Ledger {
+	Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::TaxFinesPenalties(
+		TFP::SlashedCoins,
+	))))
+}
+
+fn slashed_funds_income() -> Ledger {
+	Ledger::ProfitLoss(P::Income(I::OtherOperatingIncome(OOPIN::BlockchainSlashedFundsIncome)))
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Operation {
+	Transfer { from: u64, to: u64, amount: u64 },
+	Reserve { who: u64, amount: u64 },
+	Unreserve { who: u64, amount: u64 },
+	Slash { who: u64, amount: u64 },
+	Repatriate { from: u64, to: u64, amount: u64, status: BalanceStatus },
+}
+
+impl Operation {
+	fn random(rng: &mut Pcg64) -> Self {
+		let mut parties = ACCOUNTS;
+		parties.shuffle(rng);
+		let (from, to) = (parties[0], parties[1]);
+		// Amounts above the opening balance exercise the failing paths as well.
+		let amount = rng.gen_range(0..=OPENING_BALANCE + 20);
+		match rng.gen_range(0..5) {
+			0 => Self::Transfer { from, to, amount },
+			1 => Self::Reserve { who: from, amount },
+			2 => Self::Unreserve { who: from, amount },
+			3 => Self::Slash { who: from, amount },
+			_ => {
+				let status = match rng.gen_bool(0.5) {
+					true => BalanceStatus::Free,
+					false => BalanceStatus::Reserved,
+				};
+				Self::Repatriate { from, to, amount, status }
+			},
+		}
+	}
+
+	/// Applies the operation to the balances pallet. Failures are expected and must leave both the
+	/// balances and the ledgers untouched.
+	fn apply(self) {
+		match self {
+			Self::Transfer { from, to, amount } => {
+				let _ = <Balances as Currency<u64>>::transfer(
+					&from,
+					&to,
+					amount,
+					ExistenceRequirement::AllowDeath,
+				);
+			},
+			Self::Reserve { who, amount } => {
+				let _ = Balances::reserve(&who, amount);
+			},
+			Self::Unreserve { who, amount } => {
+				let _ = Balances::unreserve(&who, amount);
+			},
+			Self::Slash { who, amount } => {
+				let _ = Balances::slash(&who, amount);
+			},
+			Self::Repatriate { from, to, amount, status } => {
+				let _ = Balances::repatriate_reserved(&from, &to, amount, status);
+			},
+		}
+	}
+}
+
+/// The balances of the accounts, as seen by the balances pallet.
+#[derive(Debug, Default, Clone, Copy)]
+struct Balance {
+	free: u64,
+	reserved: u64,
+}
+
+fn balance_of(who: u64) -> Balance {
+	Balance { free: Balances::free_balance(who), reserved: Balances::reserved_balance(who) }
+}
+
+/// The ledgers the operations should have posted to, kept by hand as double entries.
+#[derive(Default)]
+struct Model {
+	ledgers: BTreeMap<(u64, Account), (Ledger, LedgerBalance)>,
+	global: BTreeMap<Account, (Ledger, LedgerBalance)>,
+}
+
+impl Model {
+	fn opened(accounts: &[u64], amount: u64) -> Self {
+		let mut model = Self::default();
+		for who in accounts {
+			model.post(*who, internal_balance(), amount);
+			model.post(*who, network_reserves(), amount);
+		}
+		model
+	}
+
+	fn post(&mut self, who: u64, ledger: Ledger, amount: impl Into<LedgerBalance>) {
+		let amount = amount.into();
+		self.ledgers.entry((who, ledger.account_number())).or_insert((ledger, 0)).1 += amount;
+		self.global.entry(ledger.account_number()).or_insert((ledger, 0)).1 += amount;
+	}
+
+	/// Books the postings of an operation, with the amounts that actually moved in the balances.
+	fn apply(&mut self, operation: Operation, before: &BTreeMap<u64, Balance>) {
+		let moved = |who: u64| {
+			let (old, new) = (before[&who], balance_of(who));
+			(
+				LedgerBalance::from(old.free) - LedgerBalance::from(new.free),
+				LedgerBalance::from(old.reserved) - LedgerBalance::from(new.reserved),
+			)
+		};
+		match operation {
+			Operation::Transfer { from, to, .. } => {
+				let (sent, _) = moved(from);
+				self.post(from, internal_balance(), -sent);
+				self.post(from, network_reserves(), -sent);
+				self.post(to, internal_balance(), sent);
+				self.post(to, network_reserves(), sent);
+			},
+			Operation::Reserve { who, .. } | Operation::Unreserve { who, .. } => {
+				let (free, reserved) = moved(who);
+				self.post(who, internal_balance(), -free);
+				self.post(who, internal_reserved_balance(), -reserved);
+			},
+			Operation::Slash { who, .. } => {
+				let (free, reserved) = moved(who);
+				self.post(who, internal_balance(), -free);
+				self.post(who, internal_reserved_balance(), -reserved);
+				self.post(who, slashed_coins(), free + reserved);
+			},
+			Operation::Repatriate { from, to, status, .. } => {
+				let (_, repatriated) = moved(from);
+				let to_ledger = match status {
+					BalanceStatus::Free => internal_balance(),
+					BalanceStatus::Reserved => internal_reserved_balance(),
+				};
+				self.post(from, internal_reserved_balance(), -repatriated);
+				self.post(from, slashed_coins(), repatriated);
+				self.post(to, to_ledger, repatriated);
+				self.post(to, slashed_funds_income(), repatriated);
+			},
+		}
+	}
+
+	fn check(&self, context: &str) {
+		for (who, ledger, balance) in BalanceByLedger::<Test>::iter() {
+			let expected = self.ledgers.get(&(who, ledger.account_number())).map_or(0, |l| l.1);
+			assert_eq!(balance, expected, "{context}: ledger {ledger:?} of account {who}");
+		}
+		for ((who, _), (ledger, expected)) in &self.ledgers {
+			let balance = BalanceByLedger::<Test>::get(who, ledger).unwrap_or_default();
+			assert_eq!(balance, *expected, "{context}: ledger {ledger:?} of account {who}");
+		}
+
+		for (ledger, balance) in GlobalLedger::<Test>::iter() {
+			let expected = self.global.get(&ledger.account_number()).map_or(0, |l| l.1);
+			assert_eq!(balance, expected, "{context}: global ledger {ledger:?}");
+		}
+		let (mut debits, mut credits) = (0, 0);
+		for (ledger, balance) in self.global.values() {
+			assert_eq!(GlobalLedger::<Test>::get(ledger), *balance, "{context}: {ledger:?}");
+			match ledger.normal_balance() {
+				Indicator::Debit => debits += balance,
+				Indicator::Credit => credits += balance,
+			}
+		}
+		assert_eq!(debits, credits, "{context}: the debits and the credits differ");
+
+		for who in ACCOUNTS {
+			let balance = balance_of(who);
+			let booked = |ledger: Ledger| {
+				self.ledgers.get(&(who, ledger.account_number())).map_or(0, |l| l.1)
+			};
+			assert_eq!(
+				booked(internal_balance()),
+				LedgerBalance::from(balance.free),
+				"{context}: free balance of account {who}"
+			);
+			assert_eq!(
+				booked(internal_reserved_balance()),
+				LedgerBalance::from(balance.reserved),
+				"{context}: reserved balance of account {who}"
+			);
+		}
+	}
+}
+
+#[test]
+fn random_balance_operations_keep_the_ledgers_in_step_with_the_model() {
+	let opening = ACCOUNTS.map(|who| (who, OPENING_BALANCE));
+	for seed in 0..SEEDS {
+		let mut rng = Pcg64::seed_from_u64(seed);
+		new_test_ext_with_balances(&opening).execute_with(|| {
+			let mut model = Model::opened(&ACCOUNTS, OPENING_BALANCE);
+			model.check(&format!("seed {seed}, genesis"));
+
+			for step in 0..STEPS {
+				let operation = Operation::random(&mut rng);
+				let before: BTreeMap<_, _> =
+					ACCOUNTS.into_iter().map(|who| (who, balance_of(who))).collect();
+
+				operation.apply();
+				model.apply(operation, &before);
+
+				model.check(&format!("seed {seed}, step {step}, {operation:?}"));
+			}
+		});
+	}
+}
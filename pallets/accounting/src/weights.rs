@@ -0,0 +1,199 @@
+//                              Næ§@@@ÑÉ©
+//                        æ@@@@@@@@@@@@@@@@@@
+//                    Ñ@@@@?.?@@@@@@@@@@@@@@@@@@@N
+//                 ¶@@@@@?^%@@.=@@@@@@@@@@@@@@@@@@@@
+//               N@@@@@@@?^@@@»^@@@@@@@@@@@@@@@@@@@@@@
+//               @@@@@@@@?^@@@».............?@@@@@@@@@É
+//              Ñ@@@@@@@@?^@@@@@@@@@@@@@@@@@@'?@@@@@@@@Ñ
+//              @@@@@@@@@?^@@@»..............»@@@@@@@@@@
+//              @@@@@@@@@?^@@@»^@@@@@@@@@@@@@@@@@@@@@@@@
+//              @@@@@@@@@?^ë@@&.@@@@@@@@@@@@@@@@@@@@@@@@
+//               @@@@@@@@?^´@@@o.%@@@@@@@@@@@@@@@@@@@@©
+//                @@@@@@@?.´@@@@@ë.........*.±@@@@@@@æ
+//                 @@@@@@@@?´.I@@@@@@@@@@@@@@.&@@@@@N
+//                  N@@@@@@@@@@ë.*=????????=?@@@@@Ñ
+//                    @@@@@@@@@@@@@@@@@@@@@@@@@@@¶
+//                        É@@@@@@@@@@@@@@@@Ñ¶
+//                             Næ§@@@ÑÉ©
+
+// Copyright 2020 Chris D'Costa
+// This file is part of Totem Live Accounting.
+// Authors:
+// - Félix Daudré-Vignier   email: felix@totemaccounting.com
+// - Chris D'Costa          email: chris.dcosta@totemaccounting.com
+
+// Totem is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Totem is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Totem.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Placeholder weights for pallet_accounting
+//!
+//! These weights have not been benchmarked. They are rough estimates from the storage reads and writes of
+//! each call, and are to be replaced by the output of the benchmarks run on reference hardware:
+//!
+//! ./target/release/totem-parachain-collator benchmark pallet --chain=lego --steps=50 --repeat=20
+//! --pallet=pallet_accounting --extrinsic=* --execution=wasm --wasm-execution=compiled
+//! --template=./weight-template/totem-parachain-weight.hbs --output=./pallets/accounting/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for pallet_accounting.
+pub trait WeightInfo {
+	fn handle_multiposting_amounts(r: u32, ) -> Weight;
+	fn post_journal_entry(l: u32, ) -> Weight;
+	fn reverse_posting(l: u32, ) -> Weight;
+	fn set_fiscal_calendar() -> Weight;
+	fn close_period() -> Weight;
+	fn reopen_period() -> Weight;
+	fn close_year(l: u32, ) -> Weight;
+	fn retry_failed_posting() -> Weight;
+	fn open_books(l: u32, ) -> Weight;
+}
+
+/// Placeholder weights for pallet_accounting, until the benchmarks are run.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// The range of component `r` is `[2, 100]`.
+	fn handle_multiposting_amounts(r: u32, ) -> Weight {
+		Weight::from_ref_time(40_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(30_000_000 as u64).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+			.saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(r as u64)))
+	}
+	/// The range of component `l` is `[2, 50]`.
+	fn post_journal_entry(l: u32, ) -> Weight {
+		Weight::from_ref_time(45_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(30_000_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(l as u64)))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+			.saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(l as u64)))
+	}
+	/// The range of component `l` is `[2, 50]`.
+	fn reverse_posting(l: u32, ) -> Weight {
+		Weight::from_ref_time(60_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(40_000_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().reads((7 as u64).saturating_mul(l as u64)))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+			.saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(l as u64)))
+	}
+	fn set_fiscal_calendar() -> Weight {
+		Weight::from_ref_time(25_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn close_period() -> Weight {
+		Weight::from_ref_time(35_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	fn reopen_period() -> Weight {
+		Weight::from_ref_time(30_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	/// The range of component `l` is `[2, 50]`.
+	fn close_year(l: u32, ) -> Weight {
+		Weight::from_ref_time(70_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(35_000_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(l as u64)))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+			.saturating_add(T::DbWeight::get().writes((5 as u64).saturating_mul(l as u64)))
+	}
+	fn retry_failed_posting() -> Weight {
+		Weight::from_ref_time(110_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(T::DbWeight::get().writes(14 as u64))
+	}
+	/// The range of component `l` is `[2, 50]`.
+	fn open_books(l: u32, ) -> Weight {
+		Weight::from_ref_time(50_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(30_000_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(l as u64)))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+			.saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(l as u64)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn handle_multiposting_amounts(r: u32, ) -> Weight {
+		Weight::from_ref_time(40_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(30_000_000 as u64).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().reads((4 as u64).saturating_mul(r as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes((4 as u64).saturating_mul(r as u64)))
+	}
+	fn post_journal_entry(l: u32, ) -> Weight {
+		Weight::from_ref_time(45_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(30_000_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().reads((4 as u64).saturating_mul(l as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes((4 as u64).saturating_mul(l as u64)))
+	}
+	fn reverse_posting(l: u32, ) -> Weight {
+		Weight::from_ref_time(60_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(40_000_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().reads((7 as u64).saturating_mul(l as u64)))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes((4 as u64).saturating_mul(l as u64)))
+	}
+	fn set_fiscal_calendar() -> Weight {
+		Weight::from_ref_time(25_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn close_period() -> Weight {
+		Weight::from_ref_time(35_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	fn reopen_period() -> Weight {
+		Weight::from_ref_time(30_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn close_year(l: u32, ) -> Weight {
+		Weight::from_ref_time(70_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(35_000_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().reads((4 as u64).saturating_mul(l as u64)))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes((5 as u64).saturating_mul(l as u64)))
+	}
+	fn retry_failed_posting() -> Weight {
+		Weight::from_ref_time(110_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(12 as u64))
+			.saturating_add(RocksDbWeight::get().writes(14 as u64))
+	}
+	fn open_books(l: u32, ) -> Weight {
+		Weight::from_ref_time(50_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(30_000_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().reads((4 as u64).saturating_mul(l as u64)))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes((4 as u64).saturating_mul(l as u64)))
+	}
+}
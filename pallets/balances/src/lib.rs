@@ -170,6 +170,7 @@ use frame_support::traits::GenesisBuild;
 use frame_support::{
 	ensure,
 	pallet_prelude::DispatchResult,
+	storage::with_storage_layer,
 	traits::{
 		tokens::{fungible, BalanceStatus as Status, DepositConsequence, WithdrawConsequence},
 		Currency, DefensiveSaturating, ExistenceRequirement,
@@ -177,6 +178,7 @@ use frame_support::{
 		Get, Imbalance, LockIdentifier, LockableCurrency, NamedReservableCurrency, OnUnbalanced,
 		ReservableCurrency, SignedImbalance, StoredMap, TryDrop, WithdrawReasons,
 	},
+	weights::Weight,
 	WeakBoundedVec,
 };
 use frame_system as system;
@@ -191,7 +193,7 @@ use sp_runtime::{
 use sp_std::{cmp, fmt::Debug, mem, ops::BitOr, prelude::*, result};
 pub use weights::WeightInfo;
 
-use totem_primitives::accounting::Posting;
+use totem_primitives::accounting::{DeferredPosting, Posting};
 
 pub use pallet::*;
 
@@ -204,7 +206,7 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 
 	#[pallet::config]
-	pub trait Config<I: 'static = ()>: frame_system::Config + pallet_accounting::Config {
+	pub trait Config<I: 'static = ()>: frame_system::Config {
 		/// The balance of an account.
 		type Balance: Parameter
 			+ Member
@@ -260,6 +262,14 @@ pub mod pallet {
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), &'static str> {
+			Self::do_try_state()
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// Transfer some liquid free balance to another account.
@@ -288,7 +298,9 @@ pub mod pallet {
 		/// - Origin account is already in memory, so no DB operations for them.
 		/// # </weight>
 		#[pallet::call_index(0)]
-		#[pallet::weight(T::WeightInfo::transfer())]
+		#[pallet::weight(
+			T::WeightInfo::transfer().saturating_add(Pallet::<T, I>::reaping_posting_weight(4))
+		)]
 		pub fn transfer(
 			origin: OriginFor<T>,
 			dest: AccountIdLookupOf<T>,
@@ -317,6 +329,7 @@ pub mod pallet {
 		#[pallet::weight(
 			T::WeightInfo::set_balance_creating() // Creates a new account.
 				.max(T::WeightInfo::set_balance_killing()) // Kills an existing account.
+				.saturating_add(Pallet::<T, I>::reaping_posting_weight(2))
 		)]
 		pub fn set_balance(
 			origin: OriginFor<T>,
@@ -333,15 +346,19 @@ pub mod pallet {
 			let new_reserved = if wipeout { Zero::zero() } else { new_reserved };
 
 			// First we try to modify the account's balance to the forced balance.
-			let (old_free, old_reserved) = Self::mutate_account(&who, |account| {
-				let old_free = account.free;
-				let old_reserved = account.reserved;
+			let (old_free, old_reserved) =
+				Self::try_mutate_account(&who, |account, _| -> Result<_, DispatchError> {
+					let old_free = account.free;
+					let old_reserved = account.reserved;
 
-				account.free = new_free;
-				account.reserved = new_reserved;
+					// Added for Totem Accounting
+					Self::post_free_balance_change(&who, old_free, new_free)?;
+
+					account.free = new_free;
+					account.reserved = new_reserved;
 
-				(old_free, old_reserved)
-			})?;
+					Ok((old_free, old_reserved))
+				})?;
 
 			// This will adjust the total issuance, which was not done by the `mutate_account`
 			// above.
@@ -368,7 +385,10 @@ pub mod pallet {
 		///   assumed to be in the overlay.
 		/// # </weight>
 		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::force_transfer())]
+		#[pallet::weight(
+			T::WeightInfo::force_transfer()
+				.saturating_add(Pallet::<T, I>::reaping_posting_weight(4))
+		)]
 		pub fn force_transfer(
 			origin: OriginFor<T>,
 			source: AccountIdLookupOf<T>,
@@ -394,7 +414,9 @@ pub mod pallet {
 		///
 		/// [`transfer`]: struct.Pallet.html#method.transfer
 		#[pallet::call_index(3)]
-		#[pallet::weight(T::WeightInfo::transfer_keep_alive())]
+		#[pallet::weight(
+			T::WeightInfo::transfer_keep_alive().saturating_add(T::Accounting::posting_weight(4))
+		)]
 		pub fn transfer_keep_alive(
 			origin: OriginFor<T>,
 			dest: AccountIdLookupOf<T>,
@@ -424,7 +446,9 @@ pub mod pallet {
 		/// - O(1). Just like transfer, but reading the user's transferable balance first.
 		///   #</weight>
 		#[pallet::call_index(4)]
-		#[pallet::weight(T::WeightInfo::transfer_all())]
+		#[pallet::weight(
+			T::WeightInfo::transfer_all().saturating_add(Pallet::<T, I>::reaping_posting_weight(4))
+		)]
 		pub fn transfer_all(
 			origin: OriginFor<T>,
 			dest: AccountIdLookupOf<T>,
@@ -443,7 +467,9 @@ pub mod pallet {
 		///
 		/// Can only be called by ROOT.
 		#[pallet::call_index(5)]
-		#[pallet::weight(T::WeightInfo::force_unreserve())]
+		#[pallet::weight(
+			T::WeightInfo::force_unreserve().saturating_add(T::Accounting::posting_weight(2))
+		)]
 		pub fn force_unreserve(
 			origin: OriginFor<T>,
 			who: AccountIdLookupOf<T>,
@@ -920,6 +946,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// should be dropped once it is known that all nested mutates that could affect storage items
 	/// what the dust handler touches have completed.
 	///
+	/// The mutation runs in its own storage layer, so the postings made by `f` and for the dust are
+	/// undone along with it if the account cannot be written.
+	///
 	/// NOTE: Doesn't do any preparatory work for creating a new account, so should only be used
 	/// when it is known that the account already exists.
 	///
@@ -929,14 +958,20 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		who: &T::AccountId,
 		f: impl FnOnce(&mut AccountData<T::Balance>, bool) -> Result<R, E>,
 	) -> Result<(R, DustCleaner<T, I>), E> {
-		let result = T::AccountStore::try_mutate_exists(who, |maybe_account| {
-			let is_new = maybe_account.is_none();
-			let mut account = maybe_account.take().unwrap_or_default();
-			f(&mut account, is_new).map(move |result| {
+		let result = with_storage_layer(|| {
+			T::AccountStore::try_mutate_exists(who, |maybe_account| {
+				let is_new = maybe_account.is_none();
+				let mut account = maybe_account.take().unwrap_or_default();
+				let result = f(&mut account, is_new)?;
 				let maybe_endowed = if is_new { Some(account.free) } else { None };
+				let (free, reserved) = (account.free, account.reserved);
 				let maybe_account_maybe_dust = Self::post_mutation(who, account);
+				if maybe_account_maybe_dust.1.is_some() {
+					// Added for Totem Accounting
+					T::Accounting::account_for_dust(who.clone(), free, reserved)?;
+				}
 				*maybe_account = maybe_account_maybe_dust.0;
-				(maybe_endowed, maybe_account_maybe_dust.1, result)
+				Ok((maybe_endowed, maybe_account_maybe_dust.1, result))
 			})
 		});
 		result.map(|(maybe_endowed, maybe_dust, result)| {
@@ -1000,6 +1035,86 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 	}
 
+	/// Checks that the internal balance ledgers of every account match its free and reserved
+	/// balances, once the deferred postings are posted.
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn do_try_state() -> Result<(), &'static str> {
+		use sp_runtime::traits::SaturatedConversion;
+		use totem_primitives::accounting::{CurrentAssets, Ledger, A, B};
+
+		let matches = |who: &T::AccountId, ledger, balance: T::Balance| {
+			let ledger_balance = T::Accounting::settled_balance(who, ledger);
+			u128::try_from(ledger_balance).ok() == Some(balance.saturated_into::<u128>())
+		};
+		for who in system::Account::<T>::iter_keys() {
+			let account = Self::account(&who);
+			ensure!(
+				matches(
+					&who,
+					Ledger::BalanceSheet(B::Assets(A::CurrentAssets(
+						CurrentAssets::InternalBalance
+					))),
+					account.free,
+				),
+				"internal balance ledger differs from the free balance"
+			);
+			ensure!(
+				matches(
+					&who,
+					Ledger::BalanceSheet(B::Assets(A::CurrentAssets(
+						CurrentAssets::InternalReservedBalance,
+					))),
+					account.reserved,
+				),
+				"internal reserved balance ledger differs from the reserved balance"
+			);
+		}
+
+		Ok(())
+	}
+
+	/// The weight of the postings of a call that may reap the account: its recipe of `records` records,
+	/// and the dust.
+	fn reaping_posting_weight(records: u32) -> Weight {
+		T::Accounting::posting_weight(records).saturating_add(T::Accounting::posting_weight(3))
+	}
+
+	/// Books a change of the free balance of `who` from `old` to `new` as a mint or a burn.
+	fn post_free_balance_change(
+		who: &T::AccountId,
+		old: T::Balance,
+		new: T::Balance,
+	) -> DispatchResult {
+		if new > old {
+			T::Accounting::account_for_mint(who.clone(), new - old)
+		} else if new < old {
+			T::Accounting::account_for_burn(who.clone(), old - new)
+		} else {
+			Ok(())
+		}
+	}
+
+	/// Books a slash of the free and the reserved balance of `who`.
+	/// The balance has already moved, so a posting that fails is deferred.
+	fn post_slash(who: &T::AccountId, free_slash: T::Balance, reserved_slash: T::Balance) {
+		if !free_slash.is_zero() &&
+			T::Accounting::account_for_slash(who.clone(), free_slash).is_err()
+		{
+			T::Accounting::defer_posting(DeferredPosting::Slash {
+				beneficiary: who.clone(),
+				amount: free_slash,
+			});
+		}
+		if !reserved_slash.is_zero() &&
+			T::Accounting::slash_reserve(who.clone(), reserved_slash).is_err()
+		{
+			T::Accounting::defer_posting(DeferredPosting::SlashReserve {
+				beneficiary: who.clone(),
+				amount: reserved_slash,
+			});
+		}
+	}
+
 	/// Move the reserved balance of one account into the balance of another, according to `status`.
 	///
 	/// Is a no-op if:
@@ -1041,14 +1156,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 									.checked_add(&actual)
 									.ok_or(ArithmeticError::Overflow)?;
 								// Added for Totem Accounting
-								// Errors should not happen at this point otherwise the accounting and the blockchain balance will be incorrect. Used .ok() shim.
 								T::Accounting::reassign_reserve(
 									slashed.clone(),
 									beneficiary.clone(),
 									actual,
-									false,
-								)
-								.ok();
+									true,
+								)?;
 							},
 							Status::Reserved => {
 								to_account.reserved = to_account
@@ -1056,14 +1169,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 									.checked_add(&actual)
 									.ok_or(ArithmeticError::Overflow)?;
 								// Added for Totem Accounting
-								// Errors should not happen at this point otherwise the accounting and the blockchain balance will be incorrect. Used .ok() shim.
 								T::Accounting::reassign_reserve(
 									slashed.clone(),
 									beneficiary.clone(),
 									actual,
-									true,
-								)
-								.ok();
+									false,
+								)?;
 							},
 						}
 						from_account.reserved -= actual;
@@ -1131,7 +1242,8 @@ impl<T: Config<I>, I: 'static> fungible::Mutate<T::AccountId> for Pallet<T, I> {
 		Self::try_mutate_account(who, |account, _is_new| -> DispatchResult {
 			Self::deposit_consequence(who, amount, account, true).into_result()?;
 			account.free += amount;
-			Ok(())
+			// Added for Totem Accounting
+			T::Accounting::account_for_mint(who.clone(), amount)
 		})?;
 		TotalIssuance::<T, I>::mutate(|t| *t += amount);
 		Self::deposit_event(Event::Deposit { who: who.clone(), amount });
@@ -1151,6 +1263,8 @@ impl<T: Config<I>, I: 'static> fungible::Mutate<T::AccountId> for Pallet<T, I> {
 				let extra = Self::withdraw_consequence(who, amount, account).into_result()?;
 				let actual = amount + extra;
 				account.free -= actual;
+				// Added for Totem Accounting
+				T::Accounting::account_for_burn(who.clone(), actual)?;
 				Ok(actual)
 			},
 		)?;
@@ -1182,11 +1296,13 @@ impl<T: Config<I>, I: 'static> fungible::Transfer<T::AccountId> for Pallet<T, I>
 
 impl<T: Config<I>, I: 'static> fungible::Unbalanced<T::AccountId> for Pallet<T, I> {
 	fn set_balance(who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
-		Self::mutate_account(who, |account| -> DispatchResult {
+		Self::try_mutate_account(who, |account, _is_new| -> DispatchResult {
 			// fungibles::Unbalanced::decrease_balance didn't check account.reserved
 			// free = new_balance - reserved
-			account.free =
-				amount.checked_sub(&account.reserved).ok_or(ArithmeticError::Underflow)?;
+			let free = amount.checked_sub(&account.reserved).ok_or(ArithmeticError::Underflow)?;
+			// Added for Totem Accounting
+			Self::post_free_balance_change(who, account.free, free)?;
+			account.free = free;
 			Self::deposit_event(Event::BalanceSet {
 				who: who.clone(),
 				free: account.free,
@@ -1194,7 +1310,7 @@ impl<T: Config<I>, I: 'static> fungible::Unbalanced<T::AccountId> for Pallet<T,
 			});
 
 			Ok(())
-		})?
+		})
 	}
 
 	fn set_total_issuance(amount: Self::Balance) {
@@ -1227,11 +1343,12 @@ impl<T: Config<I>, I: 'static> fungible::MutateHold<T::AccountId> for Pallet<T,
 			return Ok(())
 		}
 		ensure!(Self::can_reserve(who, amount), Error::<T, I>::InsufficientBalance);
-		Self::mutate_account(who, |a| {
+		Self::try_mutate_account(who, |a, _| -> DispatchResult {
 			a.free -= amount;
 			a.reserved += amount;
-		})?;
-		Ok(())
+			// Added for Totem Accounting
+			T::Accounting::set_reserve_amount(who.clone(), amount)
+		})
 	}
 	fn release(
 		who: &T::AccountId,
@@ -1249,6 +1366,10 @@ impl<T: Config<I>, I: 'static> fungible::MutateHold<T::AccountId> for Pallet<T,
 			// ^^^ Guaranteed to be <= amount and <= a.reserved
 			a.free = new_free;
 			a.reserved = a.reserved.saturating_sub(actual);
+			// Added for Totem Accounting
+			if !actual.is_zero() {
+				T::Accounting::unreserve_amount(who.clone(), actual)?;
+			}
 			Ok(actual)
 		})
 	}
@@ -1472,6 +1593,8 @@ where
 
 	// Burn funds from the total issuance, returning a positive imbalance for the amount burned.
 	// Is a no-op if amount to be burned is zero.
+	// Totem Accounting: no account changes here. The ledgers are posted when the imbalance is
+	// settled against an account, by `withdraw` or `slash`.
 	fn burn(mut amount: Self::Balance) -> Self::PositiveImbalance {
 		if amount.is_zero() {
 			return PositiveImbalance::zero()
@@ -1488,6 +1611,8 @@ where
 	// Create new funds into the total issuance, returning a negative imbalance
 	// for the amount issued.
 	// Is a no-op if amount to be issued it zero.
+	// Totem Accounting: no account changes here. The ledgers are posted when the imbalance is
+	// resolved into an account, by `deposit_into_existing` or `deposit_creating`.
 	fn issue(mut amount: Self::Balance) -> Self::NegativeImbalance {
 		if amount.is_zero() {
 			return NegativeImbalance::zero()
@@ -1618,7 +1743,10 @@ where
 				who,
 				|account,
 				 _is_new|
-				 -> Result<(Self::NegativeImbalance, Self::Balance), DispatchError> {
+				 -> Result<
+					(Self::NegativeImbalance, Self::Balance, Self::Balance),
+					DispatchError,
+				> {
 					// Best value is the most amount we can slash following liveness rules.
 					let best_value = match attempt {
 						// First attempt we try to slash the full amount, and see if liveness issues
@@ -1640,21 +1768,26 @@ where
 						// If we have remaining slash, take it from reserved balance.
 						let reserved_slash = cmp::min(account.reserved, remaining_slash);
 						account.reserved -= reserved_slash; // Safe because of above check
+
 						Ok((
 							NegativeImbalance::new(free_slash + reserved_slash),
 							value - free_slash - reserved_slash, /* Safe because value is gt or
 							                                      * eq total slashed */
+							free_slash,
 						))
 					} else {
 						// Else we are done!
 						Ok((
 							NegativeImbalance::new(free_slash),
 							value - free_slash, // Safe because value is gt or eq to total slashed
+							free_slash,
 						))
 					}
 				},
 			) {
-				Ok((imbalance, not_slashed)) => {
+				Ok((imbalance, not_slashed, free_slash)) => {
+					// Added for Totem Accounting
+					Self::post_slash(who, free_slash, imbalance.peek().saturating_sub(free_slash));
 					Self::deposit_event(Event::Slashed {
 						who: who.clone(),
 						amount: value.saturating_sub(not_slashed),
@@ -1685,6 +1818,8 @@ where
 			|account, is_new| -> Result<Self::PositiveImbalance, DispatchError> {
 				ensure!(!is_new, Error::<T, I>::DeadAccount);
 				account.free = account.free.checked_add(&value).ok_or(ArithmeticError::Overflow)?;
+				// Added for Totem Accounting
+				T::Accounting::account_for_mint(who.clone(), value)?;
 				Self::deposit_event(Event::Deposit { who: who.clone(), amount: value });
 				Ok(PositiveImbalance::new(value))
 			},
@@ -1718,6 +1853,10 @@ where
 					None => return Ok(Self::PositiveImbalance::zero()),
 				};
 
+				// Added for Totem Accounting
+				// A deposit that cannot be accounted for is a no-op, like an overflow.
+				T::Accounting::account_for_mint(who.clone(), value)?;
+
 				Self::deposit_event(Event::Deposit { who: who.clone(), amount: value });
 				Ok(PositiveImbalance::new(value))
 			},
@@ -1752,6 +1891,17 @@ where
 
 				Self::ensure_can_withdraw(who, value, reasons, new_free_account)?;
 
+				// Added for Totem Accounting
+				// Transaction fees and tips are booked as fees, and any other withdrawal as a burn.
+				let fee_reasons = WithdrawReasons::TRANSACTION_PAYMENT | WithdrawReasons::TIP;
+				if reasons.contains(WithdrawReasons::TRANSACTION_PAYMENT) &&
+					fee_reasons.contains(reasons)
+				{
+					T::Accounting::account_for_fees(value, who.clone())?;
+				} else {
+					T::Accounting::account_for_burn(who.clone(), value)?;
+				}
+
 				account.free = new_free_account;
 
 				Self::deposit_event(Event::Withdraw { who: who.clone(), amount: value });
@@ -1786,6 +1936,8 @@ where
 				} else {
 					SignedImbalance::Negative(NegativeImbalance::new(account.free - value))
 				};
+				// Added for Totem Accounting
+				Self::post_free_balance_change(who, account.free, value)?;
 				account.free = value;
 				Self::deposit_event(Event::BalanceSet {
 					who: who.clone(),
@@ -1865,8 +2017,13 @@ where
 		}) {
 			Ok(x) => {
 				// Added for Totem Accounting
-				// TODO Errors in the accounting are not handled correctly - the `ok()` shim has been used instead
-				T::Accounting::unreserve_amount(who.clone(), x.clone()).ok();
+				// The balance has already moved, so a posting that fails is deferred.
+				if !x.is_zero() && T::Accounting::unreserve_amount(who.clone(), x).is_err() {
+					T::Accounting::defer_posting(DeferredPosting::Unreserve {
+						beneficiary: who.clone(),
+						amount: x,
+					});
+				}
 				x
 			},
 			Err(_) => {
@@ -1921,8 +2078,15 @@ where
 					let accounting_amount = value.saturating_sub(not_slashed);
 
 					// Added for Totem Accounting
-					// TODO Errors in the accounting are not handled correctly - the `ok()` shim has been used instead
-					T::Accounting::slash_reserve(who.clone(), accounting_amount.clone()).ok();
+					// The balance has already moved, so a posting that fails is deferred.
+					if !accounting_amount.is_zero() &&
+						T::Accounting::slash_reserve(who.clone(), accounting_amount).is_err()
+					{
+						T::Accounting::defer_posting(DeferredPosting::SlashReserve {
+							beneficiary: who.clone(),
+							amount: accounting_amount,
+						});
+					}
 
 					Self::deposit_event(Event::Slashed {
 						who: who.clone(),
@@ -48,6 +48,38 @@ fn migrate_v0_to_v1<T: Config<I>, I: 'static>(accounts: &[T::AccountId]) -> Weig
 	}
 }
 
+/// Records the inactive issuance, and how much the migration is expected to deactivate.
+#[cfg(feature = "try-runtime")]
+fn pre_upgrade_v0_to_v1<T: Config<I>, I: 'static>(
+	accounts: &[T::AccountId],
+) -> Result<Vec<u8>, &'static str> {
+	let deactivated = if Pallet::<T, I>::on_chain_storage_version() == 0 {
+		accounts
+			.iter()
+			.map(|a| Pallet::<T, I>::total_balance(a))
+			.fold(T::Balance::zero(), |a, e| a.saturating_add(e))
+	} else {
+		Zero::zero()
+	};
+
+	Ok((InactiveIssuance::<T, I>::get(), deactivated).encode())
+}
+
+/// Checks that the storage version is `1` and that exactly the expected amount was deactivated.
+#[cfg(feature = "try-runtime")]
+fn post_upgrade_v0_to_v1<T: Config<I>, I: 'static>(state: Vec<u8>) -> Result<(), &'static str> {
+	let (inactive, deactivated): (T::Balance, T::Balance) =
+		Decode::decode(&mut &state[..]).map_err(|_| "pre-upgrade state cannot be decoded")?;
+
+	ensure!(Pallet::<T, I>::on_chain_storage_version() == 1, "storage version was not set to 1");
+	ensure!(
+		InactiveIssuance::<T, I>::get() == inactive.saturating_add(deactivated),
+		"inactive issuance differs from the deactivated balance"
+	);
+
+	Ok(())
+}
+
 // NOTE: This must be used alongside the account whose balance is expected to be inactive.
 // Generally this will be used for the XCM teleport checking account.
 pub struct MigrateToTrackInactive<T, A, I = ()>(PhantomData<(T, A, I)>);
@@ -57,6 +89,16 @@ impl<T: Config<I>, A: Get<T::AccountId>, I: 'static> OnRuntimeUpgrade
 	fn on_runtime_upgrade() -> Weight {
 		migrate_v0_to_v1::<T, I>(&[A::get()])
 	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		pre_upgrade_v0_to_v1::<T, I>(&[A::get()])
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		post_upgrade_v0_to_v1::<T, I>(state)
+	}
 }
 
 // NOTE: This must be used alongside the accounts whose balance is expected to be inactive.
@@ -68,4 +110,14 @@ impl<T: Config<I>, A: Get<Vec<T::AccountId>>, I: 'static> OnRuntimeUpgrade
 	fn on_runtime_upgrade() -> Weight {
 		migrate_v0_to_v1::<T, I>(&A::get())
 	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		pre_upgrade_v0_to_v1::<T, I>(&A::get())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		post_upgrade_v0_to_v1::<T, I>(state)
+	}
 }
@@ -0,0 +1,231 @@
+//                              Næ§@@@ÑÉ©
+//                        æ@@@@@@@@@@@@@@@@@@
+//                    Ñ@@@@?.?@@@@@@@@@@@@@@@@@@@N
+//                 ¶@@@@@?^%@@.=@@@@@@@@@@@@@@@@@@@@
+//               N@@@@@@@?^@@@»^@@@@@@@@@@@@@@@@@@@@@@
+//               @@@@@@@@?^@@@».............?@@@@@@@@@É
+//              Ñ@@@@@@@@?^@@@@@@@@@@@@@@@@@@'?@@@@@@@@Ñ
+//              @@@@@@@@@?^@@@»..............»@@@@@@@@@@
+//              @@@@@@@@@?^@@@»^@@@@@@@@@@@@@@@@@@@@@@@@
+//              @@@@@@@@@?^ë@@&.@@@@@@@@@@@@@@@@@@@@@@@@
+//               @@@@@@@@?^´@@@o.%@@@@@@@@@@@@@@@@@@@@©
+//                @@@@@@@?.´@@@@@ë.........*.±@@@@@@@æ
+//                 @@@@@@@@?´.I@@@@@@@@@@@@@@.&@@@@@N
+//                  N@@@@@@@@@@ë.*=????????=?@@@@@Ñ
+//                    @@@@@@@@@@@@@@@@@@@@@@@@@@@¶
+//                        É@@@@@@@@@@@@@@@@Ñ¶
+//                             Næ§@@@ÑÉ©
+
+// Copyright 2020 Chris D'Costa
+// This file is part of Totem Live Accounting.
+// Authors:
+// - Félix Daudré-Vignier   email: felix@totemaccounting.com
+// - Chris D'Costa          email: chris.dcosta@totemaccounting.com
+
+// Totem is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Totem is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Totem.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmarks for the teams pallet.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use crate::Pallet as Teams;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Hash;
+use totem_primitives::teams::{ProjectRole, ProjectStatus, Validating};
+
+const SEED: u32 = 0;
+
+fn project_hash<T: Config>(index: u32) -> T::Hash {
+	T::Hashing::hash_of(&index)
+}
+
+/// Registers a project owned by `owner`, after filling the list of projects of the owner up to one below
+/// its maximum, so that the list is as long as it can be when the project is handled.
+fn add_project<T: Config>(owner: &T::AccountId) -> T::Hash {
+	for index in 1..T::MaxProjectsPerOwner::get() {
+		Teams::<T>::add_new_project(
+			RawOrigin::Signed(owner.clone()).into(),
+			project_hash::<T>(index),
+		)
+		.expect("the owner has room for the project");
+	}
+	let project_hash = project_hash::<T>(0);
+	Teams::<T>::add_new_project(RawOrigin::Signed(owner.clone()).into(), project_hash)
+		.expect("the owner has room for the project");
+
+	project_hash
+}
+
+/// Fills the team of a project up to one below its maximum, with as many pending invitations.
+fn fill_team<T: Config>(owner: &T::AccountId, project_hash: T::Hash) {
+	for index in 1..T::MaxMembersPerProject::get() {
+		let member: T::AccountId = account("member", index, SEED);
+		Teams::<T>::invite_member(
+			RawOrigin::Signed(owner.clone()).into(),
+			project_hash,
+			member.clone(),
+			ProjectRole::Member,
+		)
+		.expect("the project has room for the invitation");
+		Teams::<T>::accept_invitation(RawOrigin::Signed(member).into(), project_hash)
+			.expect("the project has room for the member");
+	}
+	for index in 1..T::MaxMembersPerProject::get() {
+		Teams::<T>::invite_member(
+			RawOrigin::Signed(owner.clone()).into(),
+			project_hash,
+			account("invitee", index, SEED),
+			ProjectRole::Viewer,
+		)
+		.expect("the project has room for the invitation");
+	}
+}
+
+/// Registers a project of `owner` with a full team, and invites `who` to it.
+fn invite<T: Config>(owner: &T::AccountId, who: &T::AccountId) -> T::Hash {
+	let project_hash = add_project::<T>(owner);
+	fill_team::<T>(owner, project_hash);
+	Teams::<T>::invite_member(
+		RawOrigin::Signed(owner.clone()).into(),
+		project_hash,
+		who.clone(),
+		ProjectRole::Member,
+	)
+	.expect("the project has room for the invitation");
+
+	project_hash
+}
+
+benchmarks! {
+	add_new_project {
+		let caller: T::AccountId = whitelisted_caller();
+		for index in 1..T::MaxProjectsPerOwner::get() {
+			Teams::<T>::add_new_project(
+				RawOrigin::Signed(caller.clone()).into(),
+				project_hash::<T>(index),
+			)?;
+		}
+		let project_hash = project_hash::<T>(0);
+	}: _(RawOrigin::Signed(caller.clone()), project_hash)
+	verify {
+		assert_eq!(ProjectHashOwner::<T>::get(project_hash), Some(caller));
+	}
+
+	remove_project {
+		let caller: T::AccountId = whitelisted_caller();
+		let project_hash = add_project::<T>(&caller);
+	}: _(RawOrigin::Signed(caller.clone()), project_hash)
+	verify {
+		assert!(DeletedProjects::<T>::contains_key(project_hash));
+	}
+
+	reassign_project {
+		let caller: T::AccountId = whitelisted_caller();
+		let new_owner: T::AccountId = account("new_owner", 0, SEED);
+		let project_hash = add_project::<T>(&caller);
+	}: _(RawOrigin::Signed(caller), new_owner.clone(), project_hash)
+	verify {
+		assert_eq!(ProjectHashOwner::<T>::get(project_hash), Some(new_owner));
+	}
+
+	close_project {
+		let caller: T::AccountId = whitelisted_caller();
+		let project_hash = add_project::<T>(&caller);
+	}: _(RawOrigin::Signed(caller), project_hash)
+	verify {
+		assert_eq!(ProjectHashStatus::<T>::get(project_hash), Some(ProjectStatus::Closed));
+	}
+
+	reopen_project {
+		let caller: T::AccountId = whitelisted_caller();
+		let project_hash = add_project::<T>(&caller);
+		Teams::<T>::close_project(RawOrigin::Signed(caller.clone()).into(), project_hash)?;
+	}: _(RawOrigin::Signed(caller), project_hash)
+	verify {
+		assert_eq!(ProjectHashStatus::<T>::get(project_hash), Some(ProjectStatus::Reopened));
+	}
+
+	set_status_project {
+		let caller: T::AccountId = whitelisted_caller();
+		let project_hash = add_project::<T>(&caller);
+		let status = ProjectStatus::OnHold;
+	}: _(RawOrigin::Signed(caller), project_hash, status)
+	verify {
+		assert_eq!(ProjectHashStatus::<T>::get(project_hash), Some(status));
+	}
+
+	invite_member {
+		let caller: T::AccountId = whitelisted_caller();
+		let who: T::AccountId = account("who", 0, SEED);
+		let project_hash = add_project::<T>(&caller);
+		fill_team::<T>(&caller, project_hash);
+	}: _(RawOrigin::Signed(caller), project_hash, who.clone(), ProjectRole::Manager)
+	verify {
+		assert!(ProjectInvitations::<T>::get(project_hash).contains(&(who, ProjectRole::Manager)));
+	}
+
+	accept_invitation {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let caller: T::AccountId = whitelisted_caller();
+		let project_hash = invite::<T>(&owner, &caller);
+	}: _(RawOrigin::Signed(caller.clone()), project_hash)
+	verify {
+		assert_eq!(Teams::<T>::role_of(caller, project_hash), Some(ProjectRole::Member));
+	}
+
+	decline_invitation {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let caller: T::AccountId = whitelisted_caller();
+		let project_hash = invite::<T>(&owner, &caller);
+	}: _(RawOrigin::Signed(caller.clone()), project_hash)
+	verify {
+		assert!(!ProjectInvitations::<T>::get(project_hash).iter().any(|(a, _)| a == &caller));
+	}
+
+	remove_member {
+		let caller: T::AccountId = whitelisted_caller();
+		let who: T::AccountId = account("who", 0, SEED);
+		let project_hash = invite::<T>(&caller, &who);
+		Teams::<T>::accept_invitation(RawOrigin::Signed(who.clone()).into(), project_hash)?;
+	}: _(RawOrigin::Signed(caller), project_hash, who.clone())
+	verify {
+		assert!(!Teams::<T>::is_project_member(who, project_hash));
+	}
+
+	ban_member {
+		let caller: T::AccountId = whitelisted_caller();
+		let who: T::AccountId = account("who", 0, SEED);
+		let project_hash = invite::<T>(&caller, &who);
+		Teams::<T>::accept_invitation(RawOrigin::Signed(who.clone()).into(), project_hash)?;
+	}: _(RawOrigin::Signed(caller.clone()), project_hash, who.clone())
+	verify {
+		assert_eq!(BannedMembers::<T>::get(project_hash, who), Some(caller));
+	}
+
+	unban_member {
+		let caller: T::AccountId = whitelisted_caller();
+		let who: T::AccountId = account("who", 0, SEED);
+		let project_hash = add_project::<T>(&caller);
+		fill_team::<T>(&caller, project_hash);
+		Teams::<T>::ban_member(RawOrigin::Signed(caller.clone()).into(), project_hash, who.clone())?;
+	}: _(RawOrigin::Signed(caller), project_hash, who.clone())
+	verify {
+		assert!(!BannedMembers::<T>::contains_key(project_hash, who));
+	}
+}
+
+impl_benchmark_test_suite!(Teams, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,687 @@
+//                              Næ§@@@ÑÉ©
+//                        æ@@@@@@@@@@@@@@@@@@
+//                    Ñ@@@@?.?@@@@@@@@@@@@@@@@@@@N
+//                 ¶@@@@@?^%@@.=@@@@@@@@@@@@@@@@@@@@
+//               N@@@@@@@?^@@@»^@@@@@@@@@@@@@@@@@@@@@@
+//               @@@@@@@@?^@@@».............?@@@@@@@@@É
+//              Ñ@@@@@@@@?^@@@@@@@@@@@@@@@@@@'?@@@@@@@@Ñ
+//              @@@@@@@@@?^@@@»..............»@@@@@@@@@@
+//              @@@@@@@@@?^@@@»^@@@@@@@@@@@@@@@@@@@@@@@@
+//              @@@@@@@@@?^ë@@&.@@@@@@@@@@@@@@@@@@@@@@@@
+//               @@@@@@@@?^´@@@o.%@@@@@@@@@@@@@@@@@@@@©
+//                @@@@@@@?.´@@@@@ë.........*.±@@@@@@@æ
+//                 @@@@@@@@?´.I@@@@@@@@@@@@@@.&@@@@@N
+//                  N@@@@@@@@@@ë.*=????????=?@@@@@Ñ
+//                    @@@@@@@@@@@@@@@@@@@@@@@@@@@¶
+//                        É@@@@@@@@@@@@@@@@Ñ¶
+//                             Næ§@@@ÑÉ©
+
+// Copyright 2020 Chris D'Costa
+// This file is part of Totem Live Accounting.
+// Authors:
+// - Félix Daudré-Vignier   email: felix@totemaccounting.com
+// - Chris D'Costa          email: chris.dcosta@totemaccounting.com
+
+// Totem is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Totem is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Totem.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Teams
+//!
+//! The registry of the projects of the Totem network. A project is registered under a hash supplied by its owner,
+//! who can close, reopen, reassign or delete it. Other Totem pallets check the owner and the status of a project
+//! through the `Validating` trait, for example before accepting a time record against it.
+//!
+//! A deleted project is kept as a `DeletedProject`, and its hash cannot be registered again.
+//!
+//! The owner builds the team of a project by inviting accounts as managers, members or viewers. An invitation
+//! only makes the account a member once it accepts it. Managers invite, remove and ban the members below them,
+//! and a banned account cannot be invited again until it is unbanned. Other pallets read the team through
+//! `Validating::is_project_member`, `Validating::role_of` and `Validating::is_banned`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+mod pallet {
+
+	use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
+	use frame_system::pallet_prelude::*;
+
+	use totem_primitives::teams::{
+		is_transition_allowed, DeletedProject, ProjectRole, ProjectStatus, Validating,
+	};
+
+	use crate::WeightInfo;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	/// The status of each project. A deleted project has no status here, it is kept in `DeletedProjects`.
+	#[pallet::storage]
+	#[pallet::getter(fn project_hash_status)]
+	pub type ProjectHashStatus<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, ProjectStatus>;
+
+	/// The owner of each project.
+	#[pallet::storage]
+	#[pallet::getter(fn project_hash_owner)]
+	pub type ProjectHashOwner<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, T::AccountId>;
+
+	/// The projects owned by each account.
+	#[pallet::storage]
+	#[pallet::getter(fn owner_projects_list)]
+	pub type OwnerProjectsList<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<T::Hash, T::MaxProjectsPerOwner>,
+		ValueQuery,
+	>;
+
+	/// The projects that have been deleted, with who owned and who deleted them.
+	#[pallet::storage]
+	#[pallet::getter(fn deleted_project)]
+	pub type DeletedProjects<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Hash, DeletedProject<T::AccountId, ProjectStatus>>;
+
+	/// The members of each project with their role, besides the owner.
+	#[pallet::storage]
+	#[pallet::getter(fn project_members)]
+	pub type ProjectMembers<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::Hash,
+		BoundedVec<(T::AccountId, ProjectRole), T::MaxMembersPerProject>,
+		ValueQuery,
+	>;
+
+	/// The pending invitations to each project, with the role offered.
+	#[pallet::storage]
+	#[pallet::getter(fn project_invitations)]
+	pub type ProjectInvitations<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::Hash,
+		BoundedVec<(T::AccountId, ProjectRole), T::MaxMembersPerProject>,
+		ValueQuery,
+	>;
+
+	/// The accounts banned from each project, with who banned them.
+	#[pallet::storage]
+	#[pallet::getter(fn banned_member)]
+	pub type BannedMembers<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::Hash,
+		Blake2_128Concat,
+		T::AccountId,
+		T::AccountId,
+	>;
+
+	/// The number of accounts banned from each project.
+	#[pallet::storage]
+	#[pallet::getter(fn banned_member_count)]
+	pub type BannedMemberCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Hash, u32, ValueQuery>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The maximum number of projects owned by one account.
+		#[pallet::constant]
+		type MaxProjectsPerOwner: Get<u32>;
+		/// The maximum number of members of one project, of pending invitations to it and of accounts banned
+		/// from it.
+		#[pallet::constant]
+		type MaxMembersPerProject: Get<u32>;
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The project hash is already registered.
+		ProjectAlreadyExists,
+		/// The project was deleted, and its hash cannot be registered again.
+		ProjectWasDeleted,
+		/// The project does not exist.
+		ProjectDoesNotExist,
+		/// The project is owned by another account.
+		NotProjectOwner,
+		/// The account owns too many projects.
+		TooManyProjects,
+		/// The project cannot be closed from its current status.
+		ProjectCannotBeClosed,
+		/// The project cannot be reopened from its current status.
+		ProjectCannotBeReopened,
+		/// Only an open or reopened project can be reassigned.
+		ProjectCannotBeReassigned,
+		/// Only an open or reopened project takes new members.
+		ProjectNotOpen,
+		/// A project is deleted with `remove_project`, not by setting its status.
+		ProjectCannotBeDeletedThisWay,
+		/// The project cannot move from its current status to the requested one.
+		InvalidStatusTransition,
+		/// The caller does not manage the members of the project, or not those of this role.
+		NotAuthorised,
+		/// The role is not below the role of the caller, or is the owner role.
+		CannotGrantRole,
+		/// The account is already in the team of the project.
+		AlreadyMember,
+		/// The account is already invited to the project.
+		AlreadyInvited,
+		/// The account is not invited to the project.
+		NotInvited,
+		/// The account is neither in the team of the project nor invited to it.
+		NotMember,
+		/// The owner cannot leave, be removed from or be banned from their project.
+		CannotRemoveOwner,
+		/// The account is banned from the project.
+		MemberBanned,
+		/// The account is not banned from the project.
+		NotBanned,
+		/// The project has too many members.
+		TooManyMembers,
+		/// The project has too many pending invitations.
+		TooManyInvitations,
+		/// The project has too many banned accounts.
+		TooManyBans,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Registers a new project owned by the caller, under a hash of the project supplied by the caller.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::add_new_project())]
+		pub fn add_new_project(origin: OriginFor<T>, project_hash: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				!ProjectHashStatus::<T>::contains_key(project_hash),
+				Error::<T>::ProjectAlreadyExists
+			);
+			ensure!(
+				!DeletedProjects::<T>::contains_key(project_hash),
+				Error::<T>::ProjectWasDeleted
+			);
+
+			OwnerProjectsList::<T>::try_mutate(&who, |projects| {
+				projects.try_push(project_hash).map_err(|_| Error::<T>::TooManyProjects)
+			})?;
+			// The project starts open.
+			ProjectHashStatus::<T>::insert(project_hash, ProjectStatus::Open);
+			ProjectHashOwner::<T>::insert(project_hash, &who);
+			Self::deposit_event(Event::ProjectRegistered { project_hash, owner: who });
+
+			Ok(())
+		}
+
+		/// Deletes a project of the caller, with its team, invitations and bans. The project is kept as a
+		/// deleted project, and its hash cannot be registered again.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::remove_project()
+			.saturating_add(T::DbWeight::get().writes(T::MaxMembersPerProject::get().into())))]
+		pub fn remove_project(origin: OriginFor<T>, project_hash: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_project_owner(&who, project_hash)?;
+			let status = ProjectStatus::Deleted;
+			Self::ensure_transition(project_hash, status, Error::<T>::InvalidStatusTransition)?;
+
+			Self::remove_from_owner(&who, project_hash);
+			ProjectHashStatus::<T>::remove(project_hash);
+			ProjectHashOwner::<T>::remove(project_hash);
+			ProjectMembers::<T>::remove(project_hash);
+			ProjectInvitations::<T>::remove(project_hash);
+			// The bans are bounded by `MaxMembersPerProject`, so this clears them all.
+			let _ = BannedMembers::<T>::clear_prefix(
+				project_hash,
+				T::MaxMembersPerProject::get(),
+				None,
+			);
+			BannedMemberCount::<T>::remove(project_hash);
+			DeletedProjects::<T>::insert(
+				project_hash,
+				DeletedProject { owned_by: who.clone(), deleted_by: who.clone(), status },
+			);
+			Self::deposit_event(Event::ProjectDeleted {
+				project_hash,
+				owner: who.clone(),
+				deleted_by: who,
+				status,
+			});
+
+			Ok(())
+		}
+
+		/// Hands an open or reopened project of the caller over to another account that is not banned from it.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::reassign_project())]
+		pub fn reassign_project(
+			origin: OriginFor<T>,
+			new_owner: T::AccountId,
+			project_hash: T::Hash,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_project_owner(&who, project_hash)?;
+			ensure!(Self::is_project_valid(project_hash), Error::<T>::ProjectCannotBeReassigned);
+			ensure!(
+				!BannedMembers::<T>::contains_key(project_hash, &new_owner),
+				Error::<T>::MemberBanned
+			);
+
+			OwnerProjectsList::<T>::try_mutate(&new_owner, |projects| {
+				projects.try_push(project_hash).map_err(|_| Error::<T>::TooManyProjects)
+			})?;
+			Self::remove_from_owner(&who, project_hash);
+			// The new owner leaves the role it had in the team.
+			Self::remove_from_team(project_hash, &new_owner);
+			ProjectHashOwner::<T>::insert(project_hash, &new_owner);
+			Self::deposit_event(Event::ProjectReassigned {
+				project_hash,
+				new_owner,
+				changed_by: who,
+			});
+
+			Ok(())
+		}
+
+		/// Closes a project of the caller that is open, reopened or on hold.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::close_project())]
+		pub fn close_project(origin: OriginFor<T>, project_hash: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_project_owner(&who, project_hash)?;
+			Self::ensure_transition(
+				project_hash,
+				ProjectStatus::Closed,
+				Error::<T>::ProjectCannotBeClosed,
+			)?;
+
+			ProjectHashStatus::<T>::insert(project_hash, ProjectStatus::Closed);
+			Self::deposit_event(Event::ProjectClosed { project_hash, closed_by: who });
+
+			Ok(())
+		}
+
+		/// Reopens a project of the caller that is on hold, abandoned or closed.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::reopen_project())]
+		pub fn reopen_project(origin: OriginFor<T>, project_hash: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_project_owner(&who, project_hash)?;
+			Self::ensure_transition(
+				project_hash,
+				ProjectStatus::Reopened,
+				Error::<T>::ProjectCannotBeReopened,
+			)?;
+
+			ProjectHashStatus::<T>::insert(project_hash, ProjectStatus::Reopened);
+			Self::deposit_event(Event::ProjectReopened { project_hash, reopened_by: who });
+
+			Ok(())
+		}
+
+		/// Sets the status of a project of the caller, following the transitions allowed by
+		/// `is_transition_allowed`. Deleting a project is done with `remove_project`.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::set_status_project())]
+		pub fn set_status_project(
+			origin: OriginFor<T>,
+			project_hash: T::Hash,
+			project_status: ProjectStatus,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_project_owner(&who, project_hash)?;
+			ensure!(
+				project_status != ProjectStatus::Deleted,
+				Error::<T>::ProjectCannotBeDeletedThisWay
+			);
+			Self::ensure_transition(
+				project_hash,
+				project_status,
+				Error::<T>::InvalidStatusTransition,
+			)?;
+
+			ProjectHashStatus::<T>::insert(project_hash, project_status);
+			Self::deposit_event(Event::ProjectStatusChanged {
+				project_hash,
+				status: project_status,
+				changed_by: who,
+			});
+
+			Ok(())
+		}
+
+		/// Invites an account to the team of an open or reopened project of the caller, with a role below the
+		/// role of the caller. The account joins the team when it accepts the invitation.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::invite_member())]
+		pub fn invite_member(
+			origin: OriginFor<T>,
+			project_hash: T::Hash,
+			who: T::AccountId,
+			role: ProjectRole,
+		) -> DispatchResult {
+			let invited_by = ensure_signed(origin)?;
+			Self::ensure_outranks(&invited_by, project_hash, role, Error::<T>::CannotGrantRole)?;
+			ensure!(Self::is_project_valid(project_hash), Error::<T>::ProjectNotOpen);
+			ensure!(
+				!BannedMembers::<T>::contains_key(project_hash, &who),
+				Error::<T>::MemberBanned
+			);
+			ensure!(!Self::is_project_member(who.clone(), project_hash), Error::<T>::AlreadyMember);
+
+			ProjectInvitations::<T>::try_mutate(project_hash, |invitations| {
+				ensure!(!invitations.iter().any(|(a, _)| a == &who), Error::<T>::AlreadyInvited);
+				invitations
+					.try_push((who.clone(), role))
+					.map_err(|_| Error::<T>::TooManyInvitations)
+			})?;
+			Self::deposit_event(Event::MemberInvited { project_hash, who, role, invited_by });
+
+			Ok(())
+		}
+
+		/// Accepts an invitation of the caller, who joins the team of the project with the role offered. The
+		/// project must still be open or reopened.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::accept_invitation())]
+		pub fn accept_invitation(origin: OriginFor<T>, project_hash: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let role = Self::invitation_of(&who, project_hash).ok_or(Error::<T>::NotInvited)?;
+			ensure!(Self::is_project_valid(project_hash), Error::<T>::ProjectNotOpen);
+
+			ProjectMembers::<T>::try_mutate(project_hash, |members| {
+				members.try_push((who.clone(), role)).map_err(|_| Error::<T>::TooManyMembers)
+			})?;
+			Self::remove_invitation(project_hash, &who);
+			Self::deposit_event(Event::InvitationAccepted { project_hash, who, role });
+
+			Ok(())
+		}
+
+		/// Declines an invitation of the caller.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::decline_invitation())]
+		pub fn decline_invitation(origin: OriginFor<T>, project_hash: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::invitation_of(&who, project_hash).ok_or(Error::<T>::NotInvited)?;
+
+			Self::remove_invitation(project_hash, &who);
+			Self::deposit_event(Event::InvitationDeclined { project_hash, who });
+
+			Ok(())
+		}
+
+		/// Removes an account from the team of a project, or withdraws its invitation. The caller removes the
+		/// accounts below them, or leaves the team by removing themself.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::remove_member())]
+		pub fn remove_member(
+			origin: OriginFor<T>,
+			project_hash: T::Hash,
+			who: T::AccountId,
+		) -> DispatchResult {
+			let removed_by = ensure_signed(origin)?;
+			let role = Self::role_of(who.clone(), project_hash)
+				.or_else(|| Self::invitation_of(&who, project_hash))
+				.ok_or(Error::<T>::NotMember)?;
+			ensure!(role != ProjectRole::Owner, Error::<T>::CannotRemoveOwner);
+			if removed_by != who {
+				Self::ensure_outranks(&removed_by, project_hash, role, Error::<T>::NotAuthorised)?;
+			}
+
+			Self::remove_from_team(project_hash, &who);
+			Self::deposit_event(Event::MemberRemoved { project_hash, who, removed_by });
+
+			Ok(())
+		}
+
+		/// Bans an account from a project. It leaves the team or loses its invitation, and cannot be invited
+		/// again until it is unbanned. The caller bans the accounts below them, and any account outside the team.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::ban_member())]
+		pub fn ban_member(
+			origin: OriginFor<T>,
+			project_hash: T::Hash,
+			who: T::AccountId,
+		) -> DispatchResult {
+			let banned_by = ensure_signed(origin)?;
+			// An account outside the team is treated as a viewer, the lowest role.
+			let role = Self::role_of(who.clone(), project_hash)
+				.or_else(|| Self::invitation_of(&who, project_hash))
+				.unwrap_or(ProjectRole::Viewer);
+			ensure!(role != ProjectRole::Owner, Error::<T>::CannotRemoveOwner);
+			Self::ensure_outranks(&banned_by, project_hash, role, Error::<T>::NotAuthorised)?;
+			ensure!(
+				!BannedMembers::<T>::contains_key(project_hash, &who),
+				Error::<T>::MemberBanned
+			);
+
+			BannedMemberCount::<T>::try_mutate(project_hash, |count| {
+				ensure!(*count < T::MaxMembersPerProject::get(), Error::<T>::TooManyBans);
+				*count += 1;
+				Ok::<_, Error<T>>(())
+			})?;
+
+			Self::remove_from_team(project_hash, &who);
+			BannedMembers::<T>::insert(project_hash, &who, &banned_by);
+			Self::deposit_event(Event::MemberBanned { project_hash, who, banned_by });
+
+			Ok(())
+		}
+
+		/// Lifts the ban of an account from a project of which the caller manages the members.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::unban_member())]
+		pub fn unban_member(
+			origin: OriginFor<T>,
+			project_hash: T::Hash,
+			who: T::AccountId,
+		) -> DispatchResult {
+			let unbanned_by = ensure_signed(origin)?;
+			Self::ensure_outranks(
+				&unbanned_by,
+				project_hash,
+				ProjectRole::Viewer,
+				Error::<T>::NotAuthorised,
+			)?;
+			ensure!(BannedMembers::<T>::contains_key(project_hash, &who), Error::<T>::NotBanned);
+
+			BannedMembers::<T>::remove(project_hash, &who);
+			BannedMemberCount::<T>::mutate_exists(project_hash, |count| {
+				*count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0)
+			});
+			Self::deposit_event(Event::MemberUnbanned { project_hash, who, unbanned_by });
+
+			Ok(())
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A project was registered.
+		ProjectRegistered { project_hash: T::Hash, owner: T::AccountId },
+		/// A project was deleted.
+		ProjectDeleted {
+			project_hash: T::Hash,
+			owner: T::AccountId,
+			deleted_by: T::AccountId,
+			status: ProjectStatus,
+		},
+		/// A project was handed over to a new owner.
+		ProjectReassigned {
+			project_hash: T::Hash,
+			new_owner: T::AccountId,
+			changed_by: T::AccountId,
+		},
+		/// A project was closed.
+		ProjectClosed { project_hash: T::Hash, closed_by: T::AccountId },
+		/// A project was reopened.
+		ProjectReopened { project_hash: T::Hash, reopened_by: T::AccountId },
+		/// The status of a project was changed.
+		ProjectStatusChanged {
+			project_hash: T::Hash,
+			status: ProjectStatus,
+			changed_by: T::AccountId,
+		},
+		/// An account was invited to the team of a project.
+		MemberInvited {
+			project_hash: T::Hash,
+			who: T::AccountId,
+			role: ProjectRole,
+			invited_by: T::AccountId,
+		},
+		/// An account accepted its invitation and joined the team of a project.
+		InvitationAccepted { project_hash: T::Hash, who: T::AccountId, role: ProjectRole },
+		/// An account declined its invitation to a project.
+		InvitationDeclined { project_hash: T::Hash, who: T::AccountId },
+		/// An account left the team of a project, or lost its invitation.
+		MemberRemoved { project_hash: T::Hash, who: T::AccountId, removed_by: T::AccountId },
+		/// An account was banned from a project.
+		MemberBanned { project_hash: T::Hash, who: T::AccountId, banned_by: T::AccountId },
+		/// The ban of an account from a project was lifted.
+		MemberUnbanned { project_hash: T::Hash, who: T::AccountId, unbanned_by: T::AccountId },
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Checks that the project exists and is owned by `who`.
+		fn ensure_project_owner(who: &T::AccountId, project_hash: T::Hash) -> DispatchResult {
+			let owner =
+				Self::project_hash_owner(project_hash).ok_or(Error::<T>::ProjectDoesNotExist)?;
+			ensure!(&owner == who, Error::<T>::NotProjectOwner);
+
+			Ok(())
+		}
+
+		/// Checks that the project can move from its current status to `to`, failing with `error` otherwise.
+		fn ensure_transition(
+			project_hash: T::Hash,
+			to: ProjectStatus,
+			error: Error<T>,
+		) -> DispatchResult {
+			let from =
+				Self::project_hash_status(project_hash).ok_or(Error::<T>::ProjectDoesNotExist)?;
+			ensure!(is_transition_allowed(from, to), error);
+
+			Ok(())
+		}
+
+		/// Checks that the project exists and that the role of `who` in it manages members and outranks `role`,
+		/// failing with `error` otherwise.
+		fn ensure_outranks(
+			who: &T::AccountId,
+			project_hash: T::Hash,
+			role: ProjectRole,
+			error: Error<T>,
+		) -> DispatchResult {
+			ensure!(
+				ProjectHashOwner::<T>::contains_key(project_hash),
+				Error::<T>::ProjectDoesNotExist
+			);
+			let own_role = Self::role_of(who.clone(), project_hash)
+				.filter(|r| r.manages_members())
+				.ok_or(Error::<T>::NotAuthorised)?;
+			ensure!(own_role.outranks(role), error);
+
+			Ok(())
+		}
+
+		/// The role offered to `who` by a pending invitation to the project.
+		fn invitation_of(who: &T::AccountId, project_hash: T::Hash) -> Option<ProjectRole> {
+			Self::project_invitations(project_hash)
+				.into_iter()
+				.find_map(|(a, role)| (&a == who).then_some(role))
+		}
+
+		/// Removes `who` from the members of the project and from its pending invitations.
+		fn remove_from_team(project_hash: T::Hash, who: &T::AccountId) {
+			ProjectMembers::<T>::mutate_exists(project_hash, |members| {
+				if let Some(list) = members {
+					list.retain(|(a, _)| a != who);
+					if list.is_empty() {
+						*members = None;
+					}
+				}
+			});
+			Self::remove_invitation(project_hash, who);
+		}
+
+		fn remove_invitation(project_hash: T::Hash, who: &T::AccountId) {
+			ProjectInvitations::<T>::mutate_exists(project_hash, |invitations| {
+				if let Some(list) = invitations {
+					list.retain(|(a, _)| a != who);
+					if list.is_empty() {
+						*invitations = None;
+					}
+				}
+			});
+		}
+
+		fn remove_from_owner(owner: &T::AccountId, project_hash: T::Hash) {
+			OwnerProjectsList::<T>::mutate_exists(owner, |projects| {
+				if let Some(list) = projects {
+					list.retain(|h| h != &project_hash);
+					if list.is_empty() {
+						*projects = None;
+					}
+				}
+			});
+		}
+	}
+
+	impl<T: Config> Validating<T::AccountId, T::Hash> for Pallet<T> {
+		fn is_project_owner(o: T::AccountId, h: T::Hash) -> bool {
+			Self::project_hash_owner(h).map_or(false, |owner| owner == o)
+		}
+
+		/// Only open and reopened projects accept work.
+		fn is_project_valid(h: T::Hash) -> bool {
+			matches!(
+				Self::project_hash_status(h),
+				Some(ProjectStatus::Open | ProjectStatus::Reopened)
+			)
+		}
+
+		fn is_owner_and_project_valid(o: T::AccountId, h: T::Hash) -> bool {
+			Self::is_project_owner(o, h) && Self::is_project_valid(h)
+		}
+
+		fn is_project_member(o: T::AccountId, h: T::Hash) -> bool {
+			Self::role_of(o, h).is_some()
+		}
+
+		fn role_of(o: T::AccountId, h: T::Hash) -> Option<ProjectRole> {
+			if Self::is_project_owner(o.clone(), h) {
+				return Some(ProjectRole::Owner)
+			}
+			Self::project_members(h)
+				.into_iter()
+				.find_map(|(a, role)| (a == o).then_some(role))
+		}
+
+		fn is_banned(o: T::AccountId, h: T::Hash) -> bool {
+			BannedMembers::<T>::contains_key(h, o)
+		}
+	}
+}
@@ -0,0 +1,601 @@
+#![cfg(test)]
+
+use crate::{
+	mock::*, BannedMemberCount, BannedMembers, DeletedProjects, Error, Event, OwnerProjectsList,
+	ProjectHashOwner, ProjectHashStatus, ProjectInvitations, ProjectMembers,
+};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H256;
+use totem_primitives::teams::{
+	is_transition_allowed, DeletedProject, ProjectRole, ProjectStatus, Validating,
+};
+
+fn project(index: u8) -> H256 {
+	H256::repeat_byte(index)
+}
+
+fn add_project(owner: u64, index: u8) -> H256 {
+	assert_ok!(Teams::add_new_project(RuntimeOrigin::signed(owner), project(index)));
+	project(index)
+}
+
+fn join(project_hash: H256, invited_by: u64, who: u64, role: ProjectRole) {
+	assert_ok!(Teams::invite_member(RuntimeOrigin::signed(invited_by), project_hash, who, role));
+	assert_ok!(Teams::accept_invitation(RuntimeOrigin::signed(who), project_hash));
+}
+
+#[test]
+fn projects_are_registered_open_to_their_owner() {
+	new_test_ext().execute_with(|| {
+		let project_hash = add_project(1, 1);
+
+		assert_eq!(ProjectHashStatus::<Test>::get(project_hash), Some(ProjectStatus::Open));
+		assert_eq!(ProjectHashOwner::<Test>::get(project_hash), Some(1));
+		assert_eq!(OwnerProjectsList::<Test>::get(1).into_inner(), vec![project_hash]);
+		assert!(Teams::is_owner_and_project_valid(1, project_hash));
+		assert!(!Teams::is_project_owner(2, project_hash));
+		System::assert_last_event(Event::ProjectRegistered { project_hash, owner: 1 }.into());
+
+		assert_noop!(
+			Teams::add_new_project(RuntimeOrigin::signed(2), project_hash),
+			Error::<Test>::ProjectAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn an_owner_cannot_register_more_than_the_maximum_number_of_projects() {
+	new_test_ext().execute_with(|| {
+		for index in 1..=3 {
+			add_project(1, index);
+		}
+
+		assert_noop!(
+			Teams::add_new_project(RuntimeOrigin::signed(1), project(4)),
+			Error::<Test>::TooManyProjects
+		);
+	});
+}
+
+#[test]
+fn only_the_owner_can_change_a_project() {
+	new_test_ext().execute_with(|| {
+		let project_hash = add_project(1, 1);
+
+		assert_noop!(
+			Teams::close_project(RuntimeOrigin::signed(2), project_hash),
+			Error::<Test>::NotProjectOwner
+		);
+		assert_noop!(
+			Teams::remove_project(RuntimeOrigin::signed(2), project_hash),
+			Error::<Test>::NotProjectOwner
+		);
+		assert_noop!(
+			Teams::reassign_project(RuntimeOrigin::signed(2), 2, project_hash),
+			Error::<Test>::NotProjectOwner
+		);
+		assert_noop!(
+			Teams::set_status_project(
+				RuntimeOrigin::signed(2),
+				project_hash,
+				ProjectStatus::OnHold
+			),
+			Error::<Test>::NotProjectOwner
+		);
+		assert_noop!(
+			Teams::close_project(RuntimeOrigin::signed(1), project(2)),
+			Error::<Test>::ProjectDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn projects_are_closed_and_reopened() {
+	new_test_ext().execute_with(|| {
+		let project_hash = add_project(1, 1);
+		assert_noop!(
+			Teams::reopen_project(RuntimeOrigin::signed(1), project_hash),
+			Error::<Test>::ProjectCannotBeReopened
+		);
+
+		assert_ok!(Teams::close_project(RuntimeOrigin::signed(1), project_hash));
+
+		assert_eq!(ProjectHashStatus::<Test>::get(project_hash), Some(ProjectStatus::Closed));
+		assert!(!Teams::is_project_valid(project_hash));
+		assert_noop!(
+			Teams::close_project(RuntimeOrigin::signed(1), project_hash),
+			Error::<Test>::ProjectCannotBeClosed
+		);
+
+		assert_ok!(Teams::reopen_project(RuntimeOrigin::signed(1), project_hash));
+
+		assert_eq!(ProjectHashStatus::<Test>::get(project_hash), Some(ProjectStatus::Reopened));
+		assert!(Teams::is_project_valid(project_hash));
+		System::assert_last_event(Event::ProjectReopened { project_hash, reopened_by: 1 }.into());
+	});
+}
+
+#[test]
+fn projects_are_reassigned_to_a_new_owner() {
+	new_test_ext().execute_with(|| {
+		let project_hash = add_project(1, 1);
+
+		assert_ok!(Teams::reassign_project(RuntimeOrigin::signed(1), 2, project_hash));
+
+		assert_eq!(ProjectHashOwner::<Test>::get(project_hash), Some(2));
+		assert!(!OwnerProjectsList::<Test>::contains_key(1));
+		assert_eq!(OwnerProjectsList::<Test>::get(2).into_inner(), vec![project_hash]);
+		assert!(Teams::is_project_owner(2, project_hash));
+
+		assert_ok!(Teams::close_project(RuntimeOrigin::signed(2), project_hash));
+		assert_noop!(
+			Teams::reassign_project(RuntimeOrigin::signed(2), 1, project_hash),
+			Error::<Test>::ProjectCannotBeReassigned
+		);
+	});
+}
+
+#[test]
+fn deleted_projects_are_kept_and_cannot_be_registered_again() {
+	new_test_ext().execute_with(|| {
+		let project_hash = add_project(1, 1);
+
+		assert_ok!(Teams::remove_project(RuntimeOrigin::signed(1), project_hash));
+
+		assert_eq!(ProjectHashStatus::<Test>::get(project_hash), None);
+		assert_eq!(ProjectHashOwner::<Test>::get(project_hash), None);
+		assert!(!OwnerProjectsList::<Test>::contains_key(1));
+		assert_eq!(
+			DeletedProjects::<Test>::get(project_hash),
+			Some(DeletedProject { owned_by: 1, deleted_by: 1, status: ProjectStatus::Deleted })
+		);
+		assert!(!Teams::is_project_valid(project_hash));
+		assert_noop!(
+			Teams::add_new_project(RuntimeOrigin::signed(1), project_hash),
+			Error::<Test>::ProjectWasDeleted
+		);
+	});
+}
+
+#[test]
+fn the_status_can_be_set_but_not_to_deleted() {
+	new_test_ext().execute_with(|| {
+		let project_hash = add_project(1, 1);
+
+		assert_ok!(Teams::set_status_project(
+			RuntimeOrigin::signed(1),
+			project_hash,
+			ProjectStatus::OnHold
+		));
+
+		assert_eq!(ProjectHashStatus::<Test>::get(project_hash), Some(ProjectStatus::OnHold));
+		assert!(!Teams::is_project_valid(project_hash));
+		assert_noop!(
+			Teams::set_status_project(
+				RuntimeOrigin::signed(1),
+				project_hash,
+				ProjectStatus::Deleted
+			),
+			Error::<Test>::ProjectCannotBeDeletedThisWay
+		);
+	});
+}
+
+#[test]
+fn the_status_follows_the_allowed_transitions() {
+	// A deleted project has no status to set.
+	let statuses = ProjectStatus::ALL.into_iter().filter(|s| *s != ProjectStatus::Deleted);
+	for from in statuses {
+		for to in ProjectStatus::ALL {
+			new_test_ext().execute_with(|| {
+				let project_hash = add_project(1, 1);
+				ProjectHashStatus::<Test>::insert(project_hash, from);
+
+				let result = Teams::set_status_project(RuntimeOrigin::signed(1), project_hash, to);
+
+				if to == ProjectStatus::Deleted {
+					assert_eq!(result, Err(Error::<Test>::ProjectCannotBeDeletedThisWay.into()));
+				} else if is_transition_allowed(from, to) {
+					assert_eq!(result, Ok(()), "transition from {from:?} to {to:?}");
+					assert_eq!(ProjectHashStatus::<Test>::get(project_hash), Some(to));
+				} else {
+					assert_eq!(
+						result,
+						Err(Error::<Test>::InvalidStatusTransition.into()),
+						"transition from {from:?} to {to:?}"
+					);
+					assert_eq!(ProjectHashStatus::<Test>::get(project_hash), Some(from));
+				}
+			});
+		}
+	}
+}
+
+#[test]
+fn closing_and_reopening_follow_the_allowed_transitions() {
+	for from in ProjectStatus::ALL.into_iter().filter(|s| *s != ProjectStatus::Deleted) {
+		new_test_ext().execute_with(|| {
+			let project_hash = add_project(1, 1);
+			ProjectHashStatus::<Test>::insert(project_hash, from);
+
+			let closed = Teams::close_project(RuntimeOrigin::signed(1), project_hash);
+			match is_transition_allowed(from, ProjectStatus::Closed) {
+				true => assert_eq!(closed, Ok(())),
+				false => assert_eq!(closed, Err(Error::<Test>::ProjectCannotBeClosed.into())),
+			}
+
+			ProjectHashStatus::<Test>::insert(project_hash, from);
+			let reopened = Teams::reopen_project(RuntimeOrigin::signed(1), project_hash);
+			match is_transition_allowed(from, ProjectStatus::Reopened) {
+				true => assert_eq!(reopened, Ok(())),
+				false => assert_eq!(reopened, Err(Error::<Test>::ProjectCannotBeReopened.into())),
+			}
+		});
+	}
+}
+
+#[test]
+fn archived_projects_can_only_be_deleted() {
+	new_test_ext().execute_with(|| {
+		let project_hash = add_project(1, 1);
+		assert_noop!(
+			Teams::set_status_project(
+				RuntimeOrigin::signed(1),
+				project_hash,
+				ProjectStatus::Archived
+			),
+			Error::<Test>::InvalidStatusTransition
+		);
+
+		assert_ok!(Teams::close_project(RuntimeOrigin::signed(1), project_hash));
+		assert_ok!(Teams::set_status_project(
+			RuntimeOrigin::signed(1),
+			project_hash,
+			ProjectStatus::Archived
+		));
+
+		assert_noop!(
+			Teams::reopen_project(RuntimeOrigin::signed(1), project_hash),
+			Error::<Test>::ProjectCannotBeReopened
+		);
+		assert_ok!(Teams::remove_project(RuntimeOrigin::signed(1), project_hash));
+		assert!(DeletedProjects::<Test>::contains_key(project_hash));
+	});
+}
+
+#[test]
+fn invitations_must_be_accepted_to_join_the_team() {
+	new_test_ext().execute_with(|| {
+		let project_hash = add_project(1, 1);
+		assert_eq!(Teams::role_of(1, project_hash), Some(ProjectRole::Owner));
+
+		assert_ok!(Teams::invite_member(
+			RuntimeOrigin::signed(1),
+			project_hash,
+			2,
+			ProjectRole::Member
+		));
+
+		System::assert_last_event(
+			Event::MemberInvited { project_hash, who: 2, role: ProjectRole::Member, invited_by: 1 }
+				.into(),
+		);
+		assert!(!Teams::is_project_member(2, project_hash));
+		assert_eq!(Teams::role_of(2, project_hash), None);
+		assert_noop!(
+			Teams::invite_member(RuntimeOrigin::signed(1), project_hash, 2, ProjectRole::Viewer),
+			Error::<Test>::AlreadyInvited
+		);
+		assert_noop!(
+			Teams::accept_invitation(RuntimeOrigin::signed(3), project_hash),
+			Error::<Test>::NotInvited
+		);
+
+		assert_ok!(Teams::accept_invitation(RuntimeOrigin::signed(2), project_hash));
+
+		assert!(Teams::is_project_member(2, project_hash));
+		assert_eq!(Teams::role_of(2, project_hash), Some(ProjectRole::Member));
+		assert!(!ProjectInvitations::<Test>::contains_key(project_hash));
+		System::assert_last_event(
+			Event::InvitationAccepted { project_hash, who: 2, role: ProjectRole::Member }.into(),
+		);
+		assert_noop!(
+			Teams::invite_member(RuntimeOrigin::signed(1), project_hash, 2, ProjectRole::Viewer),
+			Error::<Test>::AlreadyMember
+		);
+		assert_noop!(
+			Teams::invite_member(RuntimeOrigin::signed(1), project_hash, 1, ProjectRole::Viewer),
+			Error::<Test>::AlreadyMember
+		);
+	});
+}
+
+#[test]
+fn invitations_can_be_declined() {
+	new_test_ext().execute_with(|| {
+		let project_hash = add_project(1, 1);
+		assert_ok!(Teams::invite_member(
+			RuntimeOrigin::signed(1),
+			project_hash,
+			2,
+			ProjectRole::Viewer
+		));
+
+		assert_ok!(Teams::decline_invitation(RuntimeOrigin::signed(2), project_hash));
+
+		assert!(!ProjectInvitations::<Test>::contains_key(project_hash));
+		assert!(!Teams::is_project_member(2, project_hash));
+		System::assert_last_event(Event::InvitationDeclined { project_hash, who: 2 }.into());
+		assert_noop!(
+			Teams::accept_invitation(RuntimeOrigin::signed(2), project_hash),
+			Error::<Test>::NotInvited
+		);
+	});
+}
+
+#[test]
+fn members_are_only_managed_by_the_roles_above_them() {
+	new_test_ext().execute_with(|| {
+		let project_hash = add_project(1, 1);
+		assert_noop!(
+			Teams::invite_member(RuntimeOrigin::signed(1), project_hash, 2, ProjectRole::Owner),
+			Error::<Test>::CannotGrantRole
+		);
+		join(project_hash, 1, 2, ProjectRole::Manager);
+
+		assert_noop!(
+			Teams::invite_member(RuntimeOrigin::signed(2), project_hash, 3, ProjectRole::Manager),
+			Error::<Test>::CannotGrantRole
+		);
+		join(project_hash, 2, 3, ProjectRole::Member);
+		assert_noop!(
+			Teams::invite_member(RuntimeOrigin::signed(3), project_hash, 4, ProjectRole::Viewer),
+			Error::<Test>::NotAuthorised
+		);
+		assert_noop!(
+			Teams::invite_member(RuntimeOrigin::signed(5), project_hash, 4, ProjectRole::Viewer),
+			Error::<Test>::NotAuthorised
+		);
+		assert_noop!(
+			Teams::remove_member(RuntimeOrigin::signed(3), project_hash, 2),
+			Error::<Test>::NotAuthorised
+		);
+		assert_noop!(
+			Teams::remove_member(RuntimeOrigin::signed(2), project_hash, 1),
+			Error::<Test>::CannotRemoveOwner
+		);
+		assert_noop!(
+			Teams::invite_member(RuntimeOrigin::signed(1), project(2), 4, ProjectRole::Viewer),
+			Error::<Test>::ProjectDoesNotExist
+		);
+
+		assert_ok!(Teams::remove_member(RuntimeOrigin::signed(2), project_hash, 3));
+
+		assert!(!Teams::is_project_member(3, project_hash));
+		System::assert_last_event(
+			Event::MemberRemoved { project_hash, who: 3, removed_by: 2 }.into(),
+		);
+		assert_noop!(
+			Teams::remove_member(RuntimeOrigin::signed(2), project_hash, 3),
+			Error::<Test>::NotMember
+		);
+	});
+}
+
+#[test]
+fn members_can_leave_but_the_owner_cannot() {
+	new_test_ext().execute_with(|| {
+		let project_hash = add_project(1, 1);
+		join(project_hash, 1, 2, ProjectRole::Viewer);
+
+		assert_ok!(Teams::remove_member(RuntimeOrigin::signed(2), project_hash, 2));
+
+		assert!(!ProjectMembers::<Test>::contains_key(project_hash));
+		assert_noop!(
+			Teams::remove_member(RuntimeOrigin::signed(1), project_hash, 1),
+			Error::<Test>::CannotRemoveOwner
+		);
+	});
+}
+
+#[test]
+fn pending_invitations_are_withdrawn_by_removing_the_account() {
+	new_test_ext().execute_with(|| {
+		let project_hash = add_project(1, 1);
+		assert_ok!(Teams::invite_member(
+			RuntimeOrigin::signed(1),
+			project_hash,
+			2,
+			ProjectRole::Member
+		));
+
+		assert_ok!(Teams::remove_member(RuntimeOrigin::signed(1), project_hash, 2));
+
+		assert_noop!(
+			Teams::accept_invitation(RuntimeOrigin::signed(2), project_hash),
+			Error::<Test>::NotInvited
+		);
+	});
+}
+
+#[test]
+fn banned_accounts_cannot_be_invited_until_they_are_unbanned() {
+	new_test_ext().execute_with(|| {
+		let project_hash = add_project(1, 1);
+		join(project_hash, 1, 2, ProjectRole::Manager);
+		join(project_hash, 1, 3, ProjectRole::Member);
+		assert_ok!(Teams::invite_member(
+			RuntimeOrigin::signed(1),
+			project_hash,
+			4,
+			ProjectRole::Member
+		));
+
+		assert_ok!(Teams::ban_member(RuntimeOrigin::signed(2), project_hash, 3));
+		assert_ok!(Teams::ban_member(RuntimeOrigin::signed(2), project_hash, 4));
+		assert_ok!(Teams::ban_member(RuntimeOrigin::signed(2), project_hash, 5));
+
+		assert!(!Teams::is_project_member(3, project_hash));
+		assert!(ProjectInvitations::<Test>::get(project_hash).is_empty());
+		assert_eq!(BannedMembers::<Test>::get(project_hash, 5), Some(2));
+		System::assert_last_event(
+			Event::MemberBanned { project_hash, who: 5, banned_by: 2 }.into(),
+		);
+		assert_noop!(
+			Teams::invite_member(RuntimeOrigin::signed(1), project_hash, 3, ProjectRole::Member),
+			Error::<Test>::MemberBanned
+		);
+		assert_noop!(
+			Teams::ban_member(RuntimeOrigin::signed(1), project_hash, 3),
+			Error::<Test>::MemberBanned
+		);
+		assert_noop!(
+			Teams::ban_member(RuntimeOrigin::signed(2), project_hash, 1),
+			Error::<Test>::CannotRemoveOwner
+		);
+
+		assert_ok!(Teams::unban_member(RuntimeOrigin::signed(1), project_hash, 3));
+
+		System::assert_last_event(
+			Event::MemberUnbanned { project_hash, who: 3, unbanned_by: 1 }.into(),
+		);
+		assert_noop!(
+			Teams::unban_member(RuntimeOrigin::signed(1), project_hash, 3),
+			Error::<Test>::NotBanned
+		);
+		join(project_hash, 1, 3, ProjectRole::Member);
+	});
+}
+
+#[test]
+fn only_open_projects_take_new_members() {
+	new_test_ext().execute_with(|| {
+		let project_hash = add_project(1, 1);
+		assert_ok!(Teams::invite_member(
+			RuntimeOrigin::signed(1),
+			project_hash,
+			2,
+			ProjectRole::Member
+		));
+		assert_ok!(Teams::close_project(RuntimeOrigin::signed(1), project_hash));
+
+		assert_noop!(
+			Teams::invite_member(RuntimeOrigin::signed(1), project_hash, 3, ProjectRole::Member),
+			Error::<Test>::ProjectNotOpen
+		);
+		assert_noop!(
+			Teams::accept_invitation(RuntimeOrigin::signed(2), project_hash),
+			Error::<Test>::ProjectNotOpen
+		);
+
+		assert_ok!(Teams::reopen_project(RuntimeOrigin::signed(1), project_hash));
+
+		assert_ok!(Teams::accept_invitation(RuntimeOrigin::signed(2), project_hash));
+		join(project_hash, 1, 3, ProjectRole::Member);
+	});
+}
+
+#[test]
+fn the_bans_of_a_project_are_bounded() {
+	new_test_ext().execute_with(|| {
+		let project_hash = add_project(1, 1);
+		for who in 2..=4 {
+			assert_ok!(Teams::ban_member(RuntimeOrigin::signed(1), project_hash, who));
+		}
+
+		assert_noop!(
+			Teams::ban_member(RuntimeOrigin::signed(1), project_hash, 5),
+			Error::<Test>::TooManyBans
+		);
+
+		assert_ok!(Teams::unban_member(RuntimeOrigin::signed(1), project_hash, 2));
+
+		assert_eq!(BannedMemberCount::<Test>::get(project_hash), 2);
+		assert_ok!(Teams::ban_member(RuntimeOrigin::signed(1), project_hash, 5));
+	});
+}
+
+#[test]
+fn projects_are_not_reassigned_to_banned_accounts() {
+	new_test_ext().execute_with(|| {
+		let project_hash = add_project(1, 1);
+		assert_ok!(Teams::ban_member(RuntimeOrigin::signed(1), project_hash, 2));
+
+		assert_noop!(
+			Teams::reassign_project(RuntimeOrigin::signed(1), 2, project_hash),
+			Error::<Test>::MemberBanned
+		);
+	});
+}
+
+#[test]
+fn the_team_and_its_invitations_are_bounded() {
+	new_test_ext().execute_with(|| {
+		let project_hash = add_project(1, 1);
+		for who in 2..=4 {
+			join(project_hash, 1, who, ProjectRole::Member);
+		}
+		for who in 5..=7 {
+			assert_ok!(Teams::invite_member(
+				RuntimeOrigin::signed(1),
+				project_hash,
+				who,
+				ProjectRole::Member
+			));
+		}
+
+		assert_noop!(
+			Teams::invite_member(RuntimeOrigin::signed(1), project_hash, 8, ProjectRole::Member),
+			Error::<Test>::TooManyInvitations
+		);
+		assert_noop!(
+			Teams::accept_invitation(RuntimeOrigin::signed(5), project_hash),
+			Error::<Test>::TooManyMembers
+		);
+	});
+}
+
+#[test]
+fn the_team_follows_the_project_when_it_is_reassigned_or_deleted() {
+	new_test_ext().execute_with(|| {
+		let project_hash = add_project(1, 1);
+		join(project_hash, 1, 2, ProjectRole::Manager);
+		join(project_hash, 1, 3, ProjectRole::Member);
+
+		assert_ok!(Teams::reassign_project(RuntimeOrigin::signed(1), 2, project_hash));
+
+		assert_eq!(Teams::role_of(2, project_hash), Some(ProjectRole::Owner));
+		assert_eq!(Teams::role_of(1, project_hash), None);
+		assert_eq!(
+			ProjectMembers::<Test>::get(project_hash).into_inner(),
+			vec![(3, ProjectRole::Member)]
+		);
+
+		assert_ok!(Teams::invite_member(
+			RuntimeOrigin::signed(2),
+			project_hash,
+			4,
+			ProjectRole::Viewer
+		));
+		assert_ok!(Teams::remove_project(RuntimeOrigin::signed(2), project_hash));
+
+		assert!(!ProjectMembers::<Test>::contains_key(project_hash));
+		assert!(!ProjectInvitations::<Test>::contains_key(project_hash));
+		assert!(!Teams::is_project_member(3, project_hash));
+	});
+}
+
+#[test]
+fn the_bans_of_a_project_are_cleared_when_it_is_deleted() {
+	new_test_ext().execute_with(|| {
+		let project_hash = add_project(1, 1);
+		for who in 2..=4 {
+			assert_ok!(Teams::ban_member(RuntimeOrigin::signed(1), project_hash, who));
+		}
+
+		assert_ok!(Teams::remove_project(RuntimeOrigin::signed(1), project_hash));
+
+		assert_eq!(BannedMembers::<Test>::iter_prefix(project_hash).count(), 0);
+		assert!(!BannedMemberCount::<Test>::contains_key(project_hash));
+	});
+}
@@ -0,0 +1,175 @@
+//                              Næ§@@@ÑÉ©
+//                        æ@@@@@@@@@@@@@@@@@@
+//                    Ñ@@@@?.?@@@@@@@@@@@@@@@@@@@N
+//                 ¶@@@@@?^%@@.=@@@@@@@@@@@@@@@@@@@@
+//               N@@@@@@@?^@@@»^@@@@@@@@@@@@@@@@@@@@@@
+//               @@@@@@@@?^@@@».............?@@@@@@@@@É
+//              Ñ@@@@@@@@?^@@@@@@@@@@@@@@@@@@'?@@@@@@@@Ñ
+//              @@@@@@@@@?^@@@»..............»@@@@@@@@@@
+//              @@@@@@@@@?^@@@»^@@@@@@@@@@@@@@@@@@@@@@@@
+//              @@@@@@@@@?^ë@@&.@@@@@@@@@@@@@@@@@@@@@@@@
+//               @@@@@@@@?^´@@@o.%@@@@@@@@@@@@@@@@@@@@©
+//                @@@@@@@?.´@@@@@ë.........*.±@@@@@@@æ
+//                 @@@@@@@@?´.I@@@@@@@@@@@@@@.&@@@@@N
+//                  N@@@@@@@@@@ë.*=????????=?@@@@@Ñ
+//                    @@@@@@@@@@@@@@@@@@@@@@@@@@@¶
+//                        É@@@@@@@@@@@@@@@@Ñ¶
+//                             Næ§@@@ÑÉ©
+
+// Copyright 2020 Chris D'Costa
+// This file is part of Totem Live Accounting.
+// Authors:
+// - Félix Daudré-Vignier   email: felix@totemaccounting.com
+// - Chris D'Costa          email: chris.dcosta@totemaccounting.com
+
+// Totem is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Totem is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Totem.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmarks for the timekeeping pallet. The projects are registered in the teams pallet, which the runtime
+//! uses as the projects of the timekeeping pallet.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use crate::Pallet as Timekeeping;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_system::RawOrigin;
+use pallet_teams::Pallet as Teams;
+use sp_runtime::traits::{Hash, SaturatedConversion};
+use totem_primitives::{teams::ProjectRole, timekeeping::*};
+
+const SEED: u32 = 0;
+
+/// Registers a project of `owner` with `worker` as a member of its team, at a block after the end of the
+/// records of the benchmarks.
+fn add_project<T: Config + pallet_teams::Config>(
+	owner: &T::AccountId,
+	worker: &T::AccountId,
+) -> T::Hash {
+	frame_system::Pallet::<T>::set_block_number(1_000_000u32.into());
+	let project_hash = T::Hashing::hash_of(&0u32);
+	Teams::<T>::add_new_project(RawOrigin::Signed(owner.clone()).into(), project_hash)
+		.expect("the owner has room for the project");
+	Teams::<T>::invite_member(
+		RawOrigin::Signed(owner.clone()).into(),
+		project_hash,
+		worker.clone(),
+		ProjectRole::Member,
+	)
+	.expect("the project has room for the invitation");
+	Teams::<T>::accept_invitation(RawOrigin::Signed(worker.clone()).into(), project_hash)
+		.expect("the project has room for the member");
+
+	project_hash
+}
+
+/// Submits a record of `worker` starting at `start_block`, and returns its hash.
+fn submit<T: Config>(
+	worker: &T::AccountId,
+	project_hash: T::Hash,
+	status: StatusOfTimeRecord,
+	start_block: StartOrEndBlockNumber,
+) -> T::Hash {
+	Timekeeping::<T>::submit_time(
+		RawOrigin::Signed(worker.clone()).into(),
+		project_hash,
+		status,
+		10,
+		0,
+		start_block,
+		start_block + 10,
+		0,
+	)
+	.expect("the worker submits time to the project");
+
+	T::Hashing::hash_of(&(worker, project_hash, start_block, start_block + 10))
+}
+
+benchmarks! {
+	where_clause { where T: pallet_teams::Config }
+
+	submit_time {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let caller: T::AccountId = whitelisted_caller();
+		let project_hash = add_project::<T>(&owner, &caller);
+	}: _(RawOrigin::Signed(caller.clone()), project_hash, StatusOfTimeRecord::Submitted, 10, 0, 0, 10, 0)
+	verify {
+		let record_hash = T::Hashing::hash_of(&(&caller, project_hash, 0u64, 10u64));
+		assert!(ProjectTimeRecords::<T>::contains_key(project_hash, record_hash));
+	}
+
+	amend_time {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let caller: T::AccountId = whitelisted_caller();
+		let project_hash = add_project::<T>(&owner, &caller);
+		let record_hash = submit::<T>(&caller, project_hash, StatusOfTimeRecord::Draft, 0);
+	}: _(RawOrigin::Signed(caller), record_hash, StatusOfTimeRecord::Submitted, 8, 1, 2)
+	verify {
+		let record = TimeRecord::<T>::get(record_hash).expect("the record exists");
+		assert_eq!(record.submit_status, StatusOfTimeRecord::Submitted);
+	}
+
+	authorise_time {
+		let caller: T::AccountId = whitelisted_caller();
+		let worker: T::AccountId = account("worker", 0, SEED);
+		let project_hash = add_project::<T>(&caller, &worker);
+		let record_hash = submit::<T>(&worker, project_hash, StatusOfTimeRecord::Submitted, 0);
+		// Only the rate of the project is set, so that both rates are read before the cost is posted.
+		let rate = BalanceOf::<T>::saturated_from(T::BlocksPerHour::get()) * 100u32.into();
+		Timekeeping::<T>::set_hourly_rate(
+			RawOrigin::Signed(caller.clone()).into(),
+			project_hash,
+			None,
+			Some(rate),
+		)?;
+	}: _(RawOrigin::Signed(caller), record_hash, StatusOfTimeRecord::Accepted, ReasonCodeStruct::default())
+	verify {
+		assert_eq!(TotalBlocksPerAddress::<T>::get(worker), 10);
+	}
+
+	set_hourly_rate {
+		let caller: T::AccountId = whitelisted_caller();
+		let worker: T::AccountId = account("worker", 0, SEED);
+		let project_hash = add_project::<T>(&caller, &worker);
+	}: _(RawOrigin::Signed(caller), project_hash, Some(worker.clone()), Some(100u32.into()))
+	verify {
+		assert_eq!(WorkerHourlyRate::<T>::get(project_hash, worker), Some(100u32.into()));
+	}
+
+	ban_worker {
+		let caller: T::AccountId = whitelisted_caller();
+		let worker: T::AccountId = account("worker", 0, SEED);
+		let project_hash = add_project::<T>(&caller, &worker);
+	}: _(RawOrigin::Signed(caller), project_hash, worker.clone(), ReasonCodeStruct(1, 1))
+	verify {
+		assert!(ProjectWorkersBanList::<T>::contains_key(project_hash, worker));
+	}
+
+	unban_worker {
+		let caller: T::AccountId = whitelisted_caller();
+		let worker: T::AccountId = account("worker", 0, SEED);
+		let project_hash = add_project::<T>(&caller, &worker);
+		Timekeeping::<T>::ban_worker(
+			RawOrigin::Signed(caller.clone()).into(),
+			project_hash,
+			worker.clone(),
+			ReasonCodeStruct(1, 1),
+		)?;
+	}: _(RawOrigin::Signed(caller), project_hash, worker.clone())
+	verify {
+		assert!(!ProjectWorkersBanList::<T>::contains_key(project_hash, worker));
+	}
+}
+
+impl_benchmark_test_suite!(Timekeeping, crate::mock::new_test_ext(), crate::mock::Test);
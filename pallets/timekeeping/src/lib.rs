@@ -0,0 +1,741 @@
+//                              Næ§@@@ÑÉ©
+//                        æ@@@@@@@@@@@@@@@@@@
+//                    Ñ@@@@?.?@@@@@@@@@@@@@@@@@@@N
+//                 ¶@@@@@?^%@@.=@@@@@@@@@@@@@@@@@@@@
+//               N@@@@@@@?^@@@»^@@@@@@@@@@@@@@@@@@@@@@
+//               @@@@@@@@?^@@@».............?@@@@@@@@@É
+//              Ñ@@@@@@@@?^@@@@@@@@@@@@@@@@@@'?@@@@@@@@Ñ
+//              @@@@@@@@@?^@@@»..............»@@@@@@@@@@
+//              @@@@@@@@@?^@@@»^@@@@@@@@@@@@@@@@@@@@@@@@
+//              @@@@@@@@@?^ë@@&.@@@@@@@@@@@@@@@@@@@@@@@@
+//               @@@@@@@@?^´@@@o.%@@@@@@@@@@@@@@@@@@@@©
+//                @@@@@@@?.´@@@@@ë.........*.±@@@@@@@æ
+//                 @@@@@@@@?´.I@@@@@@@@@@@@@@.&@@@@@N
+//                  N@@@@@@@@@@ë.*=????????=?@@@@@Ñ
+//                    @@@@@@@@@@@@@@@@@@@@@@@@@@@¶
+//                        É@@@@@@@@@@@@@@@@Ñ¶
+//                             Næ§@@@ÑÉ©
+
+// Copyright 2020 Chris D'Costa
+// This file is part of Totem Live Accounting.
+// Authors:
+// - Félix Daudré-Vignier   email: felix@totemaccounting.com
+// - Chris D'Costa          email: chris.dcosta@totemaccounting.com
+
+// Totem is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Totem is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Totem.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Timekeeping
+//!
+//! The time records of the workers of the Totem projects. A worker records the blocks spent on a project of the
+//! `teams` pallet, as a draft or submitted for review. The owner of the project accepts, rejects or disputes
+//! a submitted record through its `StatusOfTimeRecord`. An accepted record is locked and counted in the totals
+//! of the project and of the worker. A rejected or disputed record is amended and submitted again.
+//!
+//! An accepted record is posted to the accounting ledgers when the owner has set an hourly rate for the worker on
+//! the project, or for the project. The cost of the record is its blocks converted into hours at that rate. The
+//! owner of the project books it as direct labour costs owed as wages payable, and moves it into the work in
+//! progress of the project. The worker books it as income from services, receivable from the owner. The
+//! postings apply to the block at which the work ended, rather than to the block at which it was accepted.
+//! No posting is made for the time an owner records on their own project.
+//!
+//! Only the managers and members of the team of a valid project submit time to it. The bans of the `teams`
+//! pallet are authoritative: an account banned from a project there is out of its team and cannot submit time
+//! to it. On top of them, the owner can ban a worker from submitting time to a project with a `BannedStruct`
+//! giving the reason, while the worker stays in the team. A worker under either ban is refused.
+//!
+//! A worker archives the records that are no longer under review through `Validating::validate_and_archive`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+mod pallet {
+
+	use frame_support::{
+		dispatch::DispatchResult,
+		pallet_prelude::*,
+		traits::{Currency, PalletInfoAccess},
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{CheckedDiv, CheckedMul, Hash, SaturatedConversion};
+
+	use totem_common::TryConvert;
+
+	use totem_primitives::{
+		accounting::{
+			AccruedRevenuesReceivables, CurrentAssets, CurrentLiabilities, Indicator, Inventory,
+			Ledger, Posting, PostingSource, Record, Sales, A, B, CII, COGS, I, L, OPEX, P, X,
+		},
+		teams::{ProjectRole, Validating as ProjectValidating},
+		timekeeping::*,
+		LedgerBalance,
+	};
+
+	use crate::WeightInfo;
+
+	/// The balance of the currency the hourly rates are set in.
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// A time record of the pallet.
+	pub type TimeRecordOf<T> = Timekeeper<
+		<T as frame_system::Config>::AccountId,
+		<T as frame_system::Config>::Hash,
+		NumberOfBlocks,
+		LockStatus,
+		StatusOfTimeRecord,
+		ReasonCodeStruct,
+		PostingPeriod,
+		StartOrEndBlockNumber,
+		NumberOfBreaks,
+	>;
+
+	/// The fiscal periods a record is posted to are numbered from 0 to 14.
+	const POSTING_PERIODS: PostingPeriod = 15;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	/// The time records, by their hash.
+	#[pallet::storage]
+	#[pallet::getter(fn time_record)]
+	pub type TimeRecord<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, TimeRecordOf<T>>;
+
+	/// The records of each worker that are not archived.
+	#[pallet::storage]
+	pub type WorkerTimeRecords<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::Hash, ()>;
+
+	/// The archived records of each worker.
+	#[pallet::storage]
+	pub type WorkerTimeRecordsArchive<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::Hash, ()>;
+
+	/// The records submitted to each project.
+	#[pallet::storage]
+	pub type ProjectTimeRecords<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::Hash, Blake2_128Concat, T::Hash, ()>;
+
+	/// The workers banned from submitting time to each project, with the reason.
+	#[pallet::storage]
+	#[pallet::getter(fn project_workers_ban_list)]
+	pub type ProjectWorkersBanList<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::Hash,
+		Blake2_128Concat,
+		T::AccountId,
+		BannedStruct,
+	>;
+
+	/// The accepted blocks of each project.
+	#[pallet::storage]
+	#[pallet::getter(fn total_blocks_per_project)]
+	pub type TotalBlocksPerProject<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Hash, NumberOfBlocks, ValueQuery>;
+
+	/// The accepted blocks of each worker on each project.
+	#[pallet::storage]
+	#[pallet::getter(fn total_blocks_per_project_per_address)]
+	pub type TotalBlocksPerProjectPerAddress<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::Hash,
+		NumberOfBlocks,
+		ValueQuery,
+	>;
+
+	/// The accepted blocks of each worker.
+	#[pallet::storage]
+	#[pallet::getter(fn total_blocks_per_address)]
+	pub type TotalBlocksPerAddress<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, NumberOfBlocks, ValueQuery>;
+
+	/// The hourly rate of each project, for the workers without a rate of their own on it.
+	#[pallet::storage]
+	#[pallet::getter(fn project_hourly_rate)]
+	pub type ProjectHourlyRate<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, BalanceOf<T>>;
+
+	/// The hourly rate of each worker on a project.
+	#[pallet::storage]
+	#[pallet::getter(fn worker_hourly_rate)]
+	pub type WorkerHourlyRate<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::Hash,
+		Blake2_128Concat,
+		T::AccountId,
+		BalanceOf<T>,
+	>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The projects the time is recorded against.
+		type Projects: ProjectValidating<Self::AccountId, Self::Hash>;
+		/// The accounting pallet, to which accepted time is posted.
+		type Accounting: Posting<Self::AccountId, Self::Hash, Self::BlockNumber, BalanceOf<Self>>;
+		/// The currency of the hourly rates.
+		type Currency: Currency<Self::AccountId>;
+		/// Converts the cost of a record into a ledger amount.
+		type TimekeepingConverter: TryConvert<BalanceOf<Self>, LedgerBalance>;
+		/// The number of blocks in an hour, which converts the blocks of a record into hours. It cannot be zero.
+		#[pallet::constant]
+		type BlocksPerHour: Get<NumberOfBlocks>;
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The project is not open.
+		ProjectNotValid,
+		/// The worker is not a manager or a member of the team of the project.
+		NotProjectWorker,
+		/// The worker is banned from the project, or from submitting time to it.
+		WorkerBanned,
+		/// The worker is not banned from the project.
+		WorkerNotBanned,
+		/// The caller is not the owner of the project.
+		NotProjectOwner,
+		/// A record of the worker for the same project and blocks already exists.
+		TimeRecordAlreadyExists,
+		/// The time record does not exist.
+		TimeRecordDoesNotExist,
+		/// The time record belongs to another worker.
+		NotTimeRecordOwner,
+		/// The time record is locked.
+		TimeRecordLocked,
+		/// The time record cannot be amended in its current status.
+		TimeRecordCannotBeAmended,
+		/// A worker only keeps a record as a draft or submits it.
+		InvalidSubmitStatus,
+		/// The owner cannot give the record this status from its current one.
+		InvalidReviewStatus,
+		/// The blocks of the record do not fit between its start and end blocks.
+		InvalidBlocks,
+		/// The record ends after the current block.
+		EndBlockInFuture,
+		/// The posting period is not between 0 and 14.
+		InvalidPostingPeriod,
+		/// The cost of the record does not fit in a ledger amount.
+		AmountOverflow,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn integrity_test() {
+			assert!(T::BlocksPerHour::get() != 0, "`BlocksPerHour` cannot be zero");
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Records time of the caller against a project, as a draft or submitted for review.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::submit_time())]
+		#[allow(clippy::too_many_arguments)]
+		pub fn submit_time(
+			origin: OriginFor<T>,
+			project_hash: T::Hash,
+			submit_status: StatusOfTimeRecord,
+			number_of_blocks: NumberOfBlocks,
+			posting_period: PostingPeriod,
+			start_block: StartOrEndBlockNumber,
+			end_block: StartOrEndBlockNumber,
+			nr_of_breaks: NumberOfBreaks,
+		) -> DispatchResult {
+			let worker = ensure_signed(origin)?;
+			Self::ensure_worker(&worker, project_hash)?;
+			Self::ensure_submission(
+				submit_status,
+				number_of_blocks,
+				posting_period,
+				start_block,
+				end_block,
+			)?;
+			let record_hash = T::Hashing::hash_of(&(&worker, project_hash, start_block, end_block));
+			ensure!(
+				!TimeRecord::<T>::contains_key(record_hash),
+				Error::<T>::TimeRecordAlreadyExists
+			);
+
+			WorkerTimeRecords::<T>::insert(&worker, record_hash, ());
+			ProjectTimeRecords::<T>::insert(project_hash, record_hash, ());
+			TimeRecord::<T>::insert(
+				record_hash,
+				Timekeeper {
+					worker: worker.clone(),
+					project_hash,
+					total_blocks: number_of_blocks,
+					locked_status: false,
+					locked_reason: ReasonCodeStruct::default(),
+					submit_status,
+					reason_code: ReasonCodeStruct::default(),
+					posting_period,
+					start_block,
+					end_block,
+					nr_of_breaks,
+				},
+			);
+			Self::deposit_event(Event::TimeRecordSubmitted {
+				record_hash,
+				project_hash,
+				worker,
+				status: submit_status,
+			});
+
+			Ok(())
+		}
+
+		/// Amends a draft, rejected or disputed record of the caller, and keeps it as a draft or submits it again.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::amend_time())]
+		pub fn amend_time(
+			origin: OriginFor<T>,
+			record_hash: T::Hash,
+			submit_status: StatusOfTimeRecord,
+			number_of_blocks: NumberOfBlocks,
+			posting_period: PostingPeriod,
+			nr_of_breaks: NumberOfBreaks,
+		) -> DispatchResult {
+			let worker = ensure_signed(origin)?;
+			let mut record =
+				Self::time_record(record_hash).ok_or(Error::<T>::TimeRecordDoesNotExist)?;
+			ensure!(record.worker == worker, Error::<T>::NotTimeRecordOwner);
+			ensure!(!record.locked_status, Error::<T>::TimeRecordLocked);
+			ensure!(
+				matches!(
+					record.submit_status,
+					StatusOfTimeRecord::Draft |
+						StatusOfTimeRecord::Rejected |
+						StatusOfTimeRecord::Disputed
+				),
+				Error::<T>::TimeRecordCannotBeAmended
+			);
+			Self::ensure_worker(&worker, record.project_hash)?;
+			Self::ensure_submission(
+				submit_status,
+				number_of_blocks,
+				posting_period,
+				record.start_block,
+				record.end_block,
+			)?;
+
+			record.submit_status = submit_status;
+			record.total_blocks = number_of_blocks;
+			record.posting_period = posting_period;
+			record.nr_of_breaks = nr_of_breaks;
+			let project_hash = record.project_hash;
+			TimeRecord::<T>::insert(record_hash, record);
+			Self::deposit_event(Event::TimeRecordSubmitted {
+				record_hash,
+				project_hash,
+				worker,
+				status: submit_status,
+			});
+
+			Ok(())
+		}
+
+		/// Reviews a submitted or disputed record of a project of the caller. An accepted record is locked,
+		/// counted in the totals of the project and of the worker, and posted to the ledgers of both at the
+		/// hourly rate of the worker.
+		#[pallet::call_index(2)]
+		#[pallet::weight(
+			T::WeightInfo::authorise_time().saturating_add(T::Accounting::posting_weight(6))
+		)]
+		pub fn authorise_time(
+			origin: OriginFor<T>,
+			record_hash: T::Hash,
+			status: StatusOfTimeRecord,
+			reason: ReasonCodeStruct,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut record =
+				Self::time_record(record_hash).ok_or(Error::<T>::TimeRecordDoesNotExist)?;
+			ensure!(
+				T::Projects::is_project_owner(who.clone(), record.project_hash),
+				Error::<T>::NotProjectOwner
+			);
+			ensure!(!record.locked_status, Error::<T>::TimeRecordLocked);
+			ensure!(
+				Self::is_review_allowed(record.submit_status, status),
+				Error::<T>::InvalidReviewStatus
+			);
+
+			if status == StatusOfTimeRecord::Accepted {
+				Self::post_labour(&record, &who)?;
+				Self::accept(&record);
+				record.locked_status = true;
+				record.locked_reason = reason.clone();
+			}
+			record.submit_status = status;
+			record.reason_code = reason;
+			TimeRecord::<T>::insert(record_hash, record);
+			Self::deposit_event(Event::TimeRecordReviewed {
+				record_hash,
+				status,
+				reviewed_by: who,
+			});
+
+			Ok(())
+		}
+
+		/// Bans a worker from submitting or amending time on a project of the caller.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::ban_worker())]
+		pub fn ban_worker(
+			origin: OriginFor<T>,
+			project_hash: T::Hash,
+			worker: T::AccountId,
+			reason: ReasonCodeStruct,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(T::Projects::is_project_owner(who, project_hash), Error::<T>::NotProjectOwner);
+			ensure!(!Self::is_banned(project_hash, &worker), Error::<T>::WorkerBanned);
+
+			ProjectWorkersBanList::<T>::insert(
+				project_hash,
+				&worker,
+				BannedStruct(true, reason.clone()),
+			);
+			Self::deposit_event(Event::WorkerBanned { project_hash, worker, reason });
+
+			Ok(())
+		}
+
+		/// Lifts the ban of a worker from a project of the caller.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::unban_worker())]
+		pub fn unban_worker(
+			origin: OriginFor<T>,
+			project_hash: T::Hash,
+			worker: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(T::Projects::is_project_owner(who, project_hash), Error::<T>::NotProjectOwner);
+			ensure!(Self::is_banned(project_hash, &worker), Error::<T>::WorkerNotBanned);
+
+			ProjectWorkersBanList::<T>::remove(project_hash, &worker);
+			Self::deposit_event(Event::WorkerUnbanned { project_hash, worker });
+
+			Ok(())
+		}
+
+		/// Sets or clears the hourly rate of a project of the caller, or of a worker on it when `worker` is given.
+		/// The rate of a worker comes before the rate of the project.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::set_hourly_rate())]
+		pub fn set_hourly_rate(
+			origin: OriginFor<T>,
+			project_hash: T::Hash,
+			worker: Option<T::AccountId>,
+			rate: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(T::Projects::is_project_owner(who, project_hash), Error::<T>::NotProjectOwner);
+
+			match &worker {
+				Some(worker) => WorkerHourlyRate::<T>::set(project_hash, worker, rate),
+				None => ProjectHourlyRate::<T>::set(project_hash, rate),
+			}
+			Self::deposit_event(Event::HourlyRateSet { project_hash, worker, rate });
+
+			Ok(())
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A worker recorded or amended time on a project.
+		TimeRecordSubmitted {
+			record_hash: T::Hash,
+			project_hash: T::Hash,
+			worker: T::AccountId,
+			status: StatusOfTimeRecord,
+		},
+		/// The owner of a project reviewed a time record.
+		TimeRecordReviewed {
+			record_hash: T::Hash,
+			status: StatusOfTimeRecord,
+			reviewed_by: T::AccountId,
+		},
+		/// A worker was banned from a project.
+		WorkerBanned { project_hash: T::Hash, worker: T::AccountId, reason: ReasonCodeStruct },
+		/// The ban of a worker from a project was lifted.
+		WorkerUnbanned { project_hash: T::Hash, worker: T::AccountId },
+		/// A worker archived or restored a time record.
+		TimeRecordArchived { record_hash: T::Hash, worker: T::AccountId, archived: bool },
+		/// The hourly rate of a project, or of a worker on it, was set or cleared.
+		HourlyRateSet {
+			project_hash: T::Hash,
+			worker: Option<T::AccountId>,
+			rate: Option<BalanceOf<T>>,
+		},
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Checks that the project is valid, and that `worker` is banned from it neither by the teams nor here,
+		/// and works on it.
+		fn ensure_worker(worker: &T::AccountId, project_hash: T::Hash) -> DispatchResult {
+			ensure!(T::Projects::is_project_valid(project_hash), Error::<T>::ProjectNotValid);
+			ensure!(
+				!T::Projects::is_banned(worker.clone(), project_hash) &&
+					!Self::is_banned(project_hash, worker),
+				Error::<T>::WorkerBanned
+			);
+			// Viewers follow the project without working on it.
+			ensure!(
+				matches!(
+					T::Projects::role_of(worker.clone(), project_hash),
+					Some(ProjectRole::Owner | ProjectRole::Manager | ProjectRole::Member)
+				),
+				Error::<T>::NotProjectWorker
+			);
+
+			Ok(())
+		}
+
+		/// Checks the status, the blocks and the posting period given by a worker. The work must have ended by
+		/// the current block.
+		fn ensure_submission(
+			submit_status: StatusOfTimeRecord,
+			number_of_blocks: NumberOfBlocks,
+			posting_period: PostingPeriod,
+			start_block: StartOrEndBlockNumber,
+			end_block: StartOrEndBlockNumber,
+		) -> DispatchResult {
+			ensure!(
+				matches!(submit_status, StatusOfTimeRecord::Draft | StatusOfTimeRecord::Submitted),
+				Error::<T>::InvalidSubmitStatus
+			);
+			let span = end_block.checked_sub(start_block).ok_or(Error::<T>::InvalidBlocks)?;
+			ensure!(number_of_blocks <= span, Error::<T>::InvalidBlocks);
+			let current_block: StartOrEndBlockNumber =
+				frame_system::Pallet::<T>::block_number().saturated_into();
+			ensure!(end_block <= current_block, Error::<T>::EndBlockInFuture);
+			ensure!(posting_period < POSTING_PERIODS, Error::<T>::InvalidPostingPeriod);
+
+			Ok(())
+		}
+
+		/// The statuses the owner of a project gives to a record, from the status of the record.
+		fn is_review_allowed(from: StatusOfTimeRecord, to: StatusOfTimeRecord) -> bool {
+			use StatusOfTimeRecord::*;
+
+			match from {
+				Submitted => matches!(to, Accepted | Rejected | Disputed),
+				Disputed => matches!(to, Accepted | Rejected),
+				_ => false,
+			}
+		}
+
+		fn is_banned(project_hash: T::Hash, worker: &T::AccountId) -> bool {
+			Self::project_workers_ban_list(project_hash, worker)
+				.map_or(false, |BannedStruct(banned, _)| banned)
+		}
+
+		/// The cost of the blocks of a record at the hourly rate of its worker, if a rate is set.
+		fn cost_of(record: &TimeRecordOf<T>) -> Result<Option<LedgerBalance>, Error<T>> {
+			let rate = match Self::worker_hourly_rate(record.project_hash, &record.worker)
+				.or_else(|| Self::project_hourly_rate(record.project_hash))
+			{
+				Some(rate) => rate,
+				None => return Ok(None),
+			};
+			let blocks: BalanceOf<T> = record.total_blocks.saturated_into();
+			let blocks_per_hour: BalanceOf<T> = T::BlocksPerHour::get().saturated_into();
+			let cost = rate
+				.checked_mul(&blocks)
+				.and_then(|amount| amount.checked_div(&blocks_per_hour))
+				.ok_or(Error::<T>::AmountOverflow)?;
+
+			T::TimekeepingConverter::try_convert(cost)
+				.map(Some)
+				.ok_or(Error::<T>::AmountOverflow)
+		}
+
+		/// Posts the cost of an accepted record. The owner of the project books the labour cost, owed to the
+		/// worker as wages and moved into the work in progress of the project. The worker books the income,
+		/// receivable from the owner.
+		fn post_labour(record: &TimeRecordOf<T>, owner: &T::AccountId) -> DispatchResult {
+			if &record.worker == owner {
+				return Ok(())
+			}
+			let cost = match Self::cost_of(record)? {
+				Some(cost) if cost > 0 => cost,
+				_ => return Ok(()),
+			};
+
+			let source = PostingSource::Pallet {
+				pallet_index: <Self as PalletInfoAccess>::index() as u8,
+				call_index: 2,
+			};
+			let reference_hash = T::Accounting::reference_hash(source);
+			let current_block = frame_system::Pallet::<T>::block_number();
+			// The work is accounted for in the period in which it was done. A party that has closed that period
+			// since accounts for it in its first open period.
+			let work_period: T::BlockNumber = record.end_block.saturated_into();
+			let line = |primary_party: &T::AccountId,
+			            counterparty: &T::AccountId,
+			            ledger: Ledger,
+			            amount: LedgerBalance,
+			            debit_credit: Indicator| Record {
+				primary_party: primary_party.clone(),
+				counterparty: counterparty.clone(),
+				ledger,
+				amount,
+				debit_credit,
+				reference_hash,
+				changed_on_blocknumber: current_block,
+				applicable_period_blocknumber: T::Accounting::first_open_block(
+					primary_party,
+					work_period,
+				),
+			};
+			let worker = &record.worker;
+
+			let keys = [
+				line(
+					owner,
+					worker,
+					Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::CostOfGoodsSold(
+						COGS::DirectLabourCosts,
+					)))),
+					cost,
+					Indicator::Debit,
+				),
+				line(
+					owner,
+					worker,
+					Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(
+						CurrentLiabilities::WagesPayable,
+					))),
+					cost,
+					Indicator::Credit,
+				),
+				line(
+					owner,
+					worker,
+					Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::Inventory(
+						Inventory::WorkInProgress,
+					)))),
+					cost,
+					Indicator::Debit,
+				),
+				line(
+					owner,
+					worker,
+					Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::CostOfGoodsSold(
+						COGS::ChangesInInventories(CII::WorkInProgress),
+					)))),
+					-cost,
+					Indicator::Credit,
+				),
+				line(
+					worker,
+					owner,
+					Ledger::BalanceSheet(B::Assets(A::CurrentAssets(
+						CurrentAssets::AccruedRevenuesReceivables(
+							AccruedRevenuesReceivables::Other,
+						),
+					))),
+					cost,
+					Indicator::Debit,
+				),
+				line(
+					worker,
+					owner,
+					Ledger::ProfitLoss(P::Income(I::Sales(Sales::SalesOfServices))),
+					cost,
+					Indicator::Credit,
+				),
+			];
+
+			T::Accounting::handle_multiposting_amounts(&keys, source)
+		}
+
+		/// Counts the blocks of an accepted record in the totals.
+		fn accept(record: &TimeRecordOf<T>) {
+			let blocks = record.total_blocks;
+			TotalBlocksPerProject::<T>::mutate(record.project_hash, |total| {
+				*total = total.saturating_add(blocks)
+			});
+			TotalBlocksPerProjectPerAddress::<T>::mutate(
+				&record.worker,
+				record.project_hash,
+				|total| *total = total.saturating_add(blocks),
+			);
+			TotalBlocksPerAddress::<T>::mutate(&record.worker, |total| {
+				*total = total.saturating_add(blocks)
+			});
+		}
+	}
+
+	impl<T: Config> Validating<T::AccountId, T::Hash> for Pallet<T> {
+		fn is_time_record_owner(o: T::AccountId, h: T::Hash) -> bool {
+			Self::time_record(h).map_or(false, |record| record.worker == o)
+		}
+
+		/// Only the records that are no longer under review are archived, that is those that are accepted,
+		/// rejected, invoiced or blocked.
+		fn validate_and_archive(o: T::AccountId, h: T::Hash, a: bool) -> bool {
+			match Self::time_record(h) {
+				Some(record) if record.worker == o => {
+					if matches!(
+						record.submit_status,
+						StatusOfTimeRecord::Draft |
+							StatusOfTimeRecord::Submitted |
+							StatusOfTimeRecord::Disputed
+					) {
+						return false
+					}
+				},
+				_ => return false,
+			}
+
+			let moved = match a {
+				true => WorkerTimeRecords::<T>::take(&o, h)
+					.map(|()| WorkerTimeRecordsArchive::<T>::insert(&o, h, ())),
+				false => WorkerTimeRecordsArchive::<T>::take(&o, h)
+					.map(|()| WorkerTimeRecords::<T>::insert(&o, h, ())),
+			};
+			if moved.is_none() {
+				return false
+			}
+			Self::deposit_event(Event::TimeRecordArchived {
+				record_hash: h,
+				worker: o,
+				archived: a,
+			});
+
+			true
+		}
+	}
+}
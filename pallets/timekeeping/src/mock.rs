@@ -0,0 +1,168 @@
+//                              Næ§@@@ÑÉ©
+//                        æ@@@@@@@@@@@@@@@@@@
+//                    Ñ@@@@?.?@@@@@@@@@@@@@@@@@@@N
+//                 ¶@@@@@?^%@@.=@@@@@@@@@@@@@@@@@@@@
+//               N@@@@@@@?^@@@»^@@@@@@@@@@@@@@@@@@@@@@
+//               @@@@@@@@?^@@@».............?@@@@@@@@@É
+//              Ñ@@@@@@@@?^@@@@@@@@@@@@@@@@@@'?@@@@@@@@Ñ
+//              @@@@@@@@@?^@@@»..............»@@@@@@@@@@
+//              @@@@@@@@@?^@@@»^@@@@@@@@@@@@@@@@@@@@@@@@
+//              @@@@@@@@@?^ë@@&.@@@@@@@@@@@@@@@@@@@@@@@@
+//               @@@@@@@@?^´@@@o.%@@@@@@@@@@@@@@@@@@@@©
+//                @@@@@@@?.´@@@@@ë.........*.±@@@@@@@æ
+//                 @@@@@@@@?´.I@@@@@@@@@@@@@@.&@@@@@N
+//                  N@@@@@@@@@@ë.*=????????=?@@@@@Ñ
+//                    @@@@@@@@@@@@@@@@@@@@@@@@@@@¶
+//                        É@@@@@@@@@@@@@@@@Ñ¶
+//                             Næ§@@@ÑÉ©
+
+// Copyright 2020 Chris D'Costa
+// This file is part of Totem Live Accounting.
+// Authors:
+// - Félix Daudré-Vignier   email: felix@totemaccounting.com
+// - Chris D'Costa          email: chris.dcosta@totemaccounting.com
+
+// Totem is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Totem is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Totem.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use crate as pallet_timekeeping;
+use frame_support::{
+	parameter_types,
+	traits::{ConstBool, ConstU32, ConstU64},
+};
+use frame_system as system;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, Convert, IdentityLookup},
+};
+use totem_common::TryConvert;
+use totem_primitives::LedgerBalance;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances_totem::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Accounting: pallet_accounting::{Pallet, Call, Storage, Event<T>},
+		Teams: pallet_teams::{Pallet, Call, Storage, Event<T>},
+		Timekeeping: pallet_timekeeping::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+impl system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances_totem::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances_totem::Config for Test {
+	type Balance = u64;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = pallet_balances_totem::weights::SubstrateWeight<Test>;
+	type MaxLocks = ();
+	type MaxReserves = ConstU32<2>;
+	type ReserveIdentifier = [u8; 8];
+	type Accounting = Accounting;
+}
+
+/// Converts the mock balance and account types for use in the accounting.
+pub struct MockConverter;
+
+impl TryConvert<u64, LedgerBalance> for MockConverter {
+	fn try_convert(x: u64) -> Option<LedgerBalance> {
+		Some(x.into())
+	}
+}
+
+impl Convert<[u8; 32], u64> for MockConverter {
+	fn convert(a: [u8; 32]) -> u64 {
+		let mut account = [0; 8];
+		account.copy_from_slice(&a[..8]);
+		u64::from_le_bytes(account)
+	}
+}
+
+impl pallet_accounting::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AccountingConverter = MockConverter;
+	type Currency = Balances;
+	type EnforcePartyBalance = ConstBool<true>;
+	type MaxJournalLines = ConstU32<10>;
+	type MaxDeferredPostingsPerBlock = ConstU32<2>;
+	type MaxReconciledAccountsPerBlock = ConstU32<2>;
+	type WeightInfo = ();
+}
+
+impl pallet_teams::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxProjectsPerOwner = ConstU32<3>;
+	type MaxMembersPerProject = ConstU32<3>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub static BlocksPerHour: u64 = 10;
+}
+
+impl pallet_timekeeping::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Projects = Teams;
+	type Accounting = Accounting;
+	type Currency = Balances;
+	type TimekeepingConverter = MockConverter;
+	type BlocksPerHour = BlocksPerHour;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let storage = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let mut ext = sp_io::TestExternalities::new(storage);
+	// Events are not deposited on the genesis block.
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,613 @@
+#![cfg(test)]
+
+use crate::{
+	mock::*, Error, Event, ProjectHourlyRate, ProjectTimeRecords, ProjectWorkersBanList,
+	TimeRecord, TotalBlocksPerAddress, TotalBlocksPerProject, TotalBlocksPerProjectPerAddress,
+	WorkerHourlyRate, WorkerTimeRecords, WorkerTimeRecordsArchive,
+};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use pallet_accounting::{BalanceByLedger, PostingDetail};
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+use totem_primitives::{accounting::*, teams::ProjectRole, timekeeping::*, LedgerBalance};
+
+const OWNER: u64 = 1;
+const WORKER: u64 = 2;
+const VIEWER: u64 = 3;
+
+fn project() -> H256 {
+	H256::repeat_byte(1)
+}
+
+/// Registers the project of `OWNER`, with `WORKER` as a member and `VIEWER` as a viewer of its team, at block
+/// 100 so that the records of the tests have ended.
+fn new_project() -> H256 {
+	System::set_block_number(100);
+	let project_hash = project();
+	assert_ok!(Teams::add_new_project(RuntimeOrigin::signed(OWNER), project_hash));
+	for (who, role) in [(WORKER, ProjectRole::Member), (VIEWER, ProjectRole::Viewer)] {
+		assert_ok!(Teams::invite_member(RuntimeOrigin::signed(OWNER), project_hash, who, role));
+		assert_ok!(Teams::accept_invitation(RuntimeOrigin::signed(who), project_hash));
+	}
+	project_hash
+}
+
+fn submit(worker: u64, status: StatusOfTimeRecord, start_block: u64) -> H256 {
+	assert_ok!(Timekeeping::submit_time(
+		RuntimeOrigin::signed(worker),
+		project(),
+		status,
+		10,
+		0,
+		start_block,
+		start_block + 10,
+		0
+	));
+	BlakeTwo256::hash_of(&(worker, project(), start_block, start_block + 10))
+}
+
+fn review(record_hash: H256, status: StatusOfTimeRecord) {
+	assert_ok!(Timekeeping::authorise_time(
+		RuntimeOrigin::signed(OWNER),
+		record_hash,
+		status,
+		ReasonCodeStruct(1, 1)
+	));
+}
+
+fn status_of(record_hash: H256) -> StatusOfTimeRecord {
+	TimeRecord::<Test>::get(record_hash).unwrap().submit_status
+}
+
+fn set_rate(worker: Option<u64>, rate: u64) {
+	assert_ok!(Timekeeping::set_hourly_rate(
+		RuntimeOrigin::signed(OWNER),
+		project(),
+		worker,
+		Some(rate)
+	));
+}
+
+fn sales_of_services() -> Ledger {
+	Ledger::ProfitLoss(P::Income(I::Sales(Sales::SalesOfServices)))
+}
+
+/// The balances of the ledgers the cost of accepted time is posted to, with the owner's first.
+fn labour_balances() -> [LedgerBalance; 6] {
+	let balance = |who, ledger| BalanceByLedger::<Test>::get(who, ledger).unwrap_or_default();
+	[
+		balance(
+			OWNER,
+			Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::CostOfGoodsSold(
+				COGS::DirectLabourCosts,
+			)))),
+		),
+		balance(
+			OWNER,
+			Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(
+				CurrentLiabilities::WagesPayable,
+			))),
+		),
+		balance(
+			OWNER,
+			Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::Inventory(
+				Inventory::WorkInProgress,
+			)))),
+		),
+		balance(
+			OWNER,
+			Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::CostOfGoodsSold(
+				COGS::ChangesInInventories(CII::WorkInProgress),
+			)))),
+		),
+		balance(
+			WORKER,
+			Ledger::BalanceSheet(B::Assets(A::CurrentAssets(
+				CurrentAssets::AccruedRevenuesReceivables(AccruedRevenuesReceivables::Other),
+			))),
+		),
+		balance(WORKER, sales_of_services()),
+	]
+}
+
+#[test]
+fn the_team_of_a_valid_project_submits_time() {
+	new_test_ext().execute_with(|| {
+		let project_hash = new_project();
+
+		let record_hash = submit(WORKER, StatusOfTimeRecord::Submitted, 0);
+
+		assert_eq!(
+			TimeRecord::<Test>::get(record_hash),
+			Some(Timekeeper {
+				worker: WORKER,
+				project_hash,
+				total_blocks: 10,
+				locked_status: false,
+				locked_reason: ReasonCodeStruct::default(),
+				submit_status: StatusOfTimeRecord::Submitted,
+				reason_code: ReasonCodeStruct::default(),
+				posting_period: 0,
+				start_block: 0,
+				end_block: 10,
+				nr_of_breaks: 0,
+			})
+		);
+		assert!(WorkerTimeRecords::<Test>::contains_key(WORKER, record_hash));
+		assert!(ProjectTimeRecords::<Test>::contains_key(project_hash, record_hash));
+		System::assert_last_event(
+			Event::TimeRecordSubmitted {
+				record_hash,
+				project_hash,
+				worker: WORKER,
+				status: StatusOfTimeRecord::Submitted,
+			}
+			.into(),
+		);
+		submit(OWNER, StatusOfTimeRecord::Draft, 0);
+
+		let submit_as = |who| {
+			Timekeeping::submit_time(
+				RuntimeOrigin::signed(who),
+				project_hash,
+				StatusOfTimeRecord::Submitted,
+				10,
+				0,
+				0,
+				10,
+				0,
+			)
+		};
+		assert_noop!(submit_as(WORKER), Error::<Test>::TimeRecordAlreadyExists);
+		assert_noop!(submit_as(VIEWER), Error::<Test>::NotProjectWorker);
+		assert_noop!(submit_as(4), Error::<Test>::NotProjectWorker);
+
+		assert_ok!(Teams::close_project(RuntimeOrigin::signed(OWNER), project_hash));
+		assert_noop!(submit_as(OWNER), Error::<Test>::ProjectNotValid);
+	});
+}
+
+#[test]
+fn submissions_are_checked() {
+	new_test_ext().execute_with(|| {
+		let project_hash = new_project();
+		let submit_with = |status, blocks, period, start, end| {
+			Timekeeping::submit_time(
+				RuntimeOrigin::signed(WORKER),
+				project_hash,
+				status,
+				blocks,
+				period,
+				start,
+				end,
+				0,
+			)
+		};
+
+		assert_noop!(
+			submit_with(StatusOfTimeRecord::Accepted, 10, 0, 0, 10),
+			Error::<Test>::InvalidSubmitStatus
+		);
+		assert_noop!(
+			submit_with(StatusOfTimeRecord::Submitted, 0, 0, 10, 0),
+			Error::<Test>::InvalidBlocks
+		);
+		assert_noop!(
+			submit_with(StatusOfTimeRecord::Submitted, 11, 0, 0, 10),
+			Error::<Test>::InvalidBlocks
+		);
+		assert_noop!(
+			submit_with(StatusOfTimeRecord::Submitted, 10, 15, 0, 10),
+			Error::<Test>::InvalidPostingPeriod
+		);
+		assert_noop!(
+			submit_with(StatusOfTimeRecord::Submitted, 10, 0, 95, 105),
+			Error::<Test>::EndBlockInFuture
+		);
+		assert_ok!(submit_with(StatusOfTimeRecord::Submitted, 8, 14, 0, 10));
+		assert_ok!(submit_with(StatusOfTimeRecord::Submitted, 10, 0, 90, 100));
+	});
+}
+
+#[test]
+fn accepted_time_is_locked_and_counted() {
+	new_test_ext().execute_with(|| {
+		let project_hash = new_project();
+		let record_hash = submit(WORKER, StatusOfTimeRecord::Submitted, 0);
+		let draft_hash = submit(WORKER, StatusOfTimeRecord::Draft, 20);
+		assert_noop!(
+			Timekeeping::authorise_time(
+				RuntimeOrigin::signed(WORKER),
+				record_hash,
+				StatusOfTimeRecord::Accepted,
+				ReasonCodeStruct::default()
+			),
+			Error::<Test>::NotProjectOwner
+		);
+		assert_noop!(
+			Timekeeping::authorise_time(
+				RuntimeOrigin::signed(OWNER),
+				draft_hash,
+				StatusOfTimeRecord::Accepted,
+				ReasonCodeStruct::default()
+			),
+			Error::<Test>::InvalidReviewStatus
+		);
+
+		review(record_hash, StatusOfTimeRecord::Accepted);
+
+		let record = TimeRecord::<Test>::get(record_hash).unwrap();
+		assert_eq!(record.submit_status, StatusOfTimeRecord::Accepted);
+		assert!(record.locked_status);
+		assert_eq!(record.locked_reason, ReasonCodeStruct(1, 1));
+		assert_eq!(TotalBlocksPerProject::<Test>::get(project_hash), 10);
+		assert_eq!(TotalBlocksPerProjectPerAddress::<Test>::get(WORKER, project_hash), 10);
+		assert_eq!(TotalBlocksPerAddress::<Test>::get(WORKER), 10);
+		System::assert_last_event(
+			Event::TimeRecordReviewed {
+				record_hash,
+				status: StatusOfTimeRecord::Accepted,
+				reviewed_by: OWNER,
+			}
+			.into(),
+		);
+		assert_noop!(
+			Timekeeping::authorise_time(
+				RuntimeOrigin::signed(OWNER),
+				record_hash,
+				StatusOfTimeRecord::Rejected,
+				ReasonCodeStruct::default()
+			),
+			Error::<Test>::TimeRecordLocked
+		);
+		assert_noop!(
+			Timekeeping::amend_time(
+				RuntimeOrigin::signed(WORKER),
+				record_hash,
+				StatusOfTimeRecord::Submitted,
+				5,
+				0,
+				0
+			),
+			Error::<Test>::TimeRecordLocked
+		);
+	});
+}
+
+#[test]
+fn rejected_and_disputed_time_is_amended_and_submitted_again() {
+	new_test_ext().execute_with(|| {
+		new_project();
+		let record_hash = submit(WORKER, StatusOfTimeRecord::Submitted, 0);
+		let amend = |who, status, blocks| {
+			Timekeeping::amend_time(RuntimeOrigin::signed(who), record_hash, status, blocks, 1, 2)
+		};
+		assert_noop!(
+			amend(WORKER, StatusOfTimeRecord::Submitted, 8),
+			Error::<Test>::TimeRecordCannotBeAmended
+		);
+
+		review(record_hash, StatusOfTimeRecord::Disputed);
+		assert_noop!(
+			Timekeeping::authorise_time(
+				RuntimeOrigin::signed(OWNER),
+				record_hash,
+				StatusOfTimeRecord::Disputed,
+				ReasonCodeStruct::default()
+			),
+			Error::<Test>::InvalidReviewStatus
+		);
+		assert_noop!(
+			amend(OWNER, StatusOfTimeRecord::Submitted, 8),
+			Error::<Test>::NotTimeRecordOwner
+		);
+		assert_noop!(
+			amend(WORKER, StatusOfTimeRecord::Submitted, 11),
+			Error::<Test>::InvalidBlocks
+		);
+		assert_ok!(amend(WORKER, StatusOfTimeRecord::Submitted, 8));
+
+		let record = TimeRecord::<Test>::get(record_hash).unwrap();
+		assert_eq!(record.submit_status, StatusOfTimeRecord::Submitted);
+		assert_eq!((record.total_blocks, record.posting_period, record.nr_of_breaks), (8, 1, 2));
+
+		review(record_hash, StatusOfTimeRecord::Rejected);
+		assert_ok!(amend(WORKER, StatusOfTimeRecord::Draft, 6));
+		assert_eq!(status_of(record_hash), StatusOfTimeRecord::Draft);
+		assert_ok!(amend(WORKER, StatusOfTimeRecord::Submitted, 6));
+		review(record_hash, StatusOfTimeRecord::Accepted);
+
+		assert_eq!(TotalBlocksPerAddress::<Test>::get(WORKER), 6);
+	});
+}
+
+#[test]
+fn banned_workers_cannot_submit_time_until_they_are_unbanned() {
+	new_test_ext().execute_with(|| {
+		let project_hash = new_project();
+		let draft_hash = submit(WORKER, StatusOfTimeRecord::Draft, 0);
+		assert_noop!(
+			Timekeeping::ban_worker(
+				RuntimeOrigin::signed(WORKER),
+				project_hash,
+				VIEWER,
+				ReasonCodeStruct::default()
+			),
+			Error::<Test>::NotProjectOwner
+		);
+
+		assert_ok!(Timekeeping::ban_worker(
+			RuntimeOrigin::signed(OWNER),
+			project_hash,
+			WORKER,
+			ReasonCodeStruct(2, 1)
+		));
+
+		assert_eq!(
+			ProjectWorkersBanList::<Test>::get(project_hash, WORKER),
+			Some(BannedStruct(true, ReasonCodeStruct(2, 1)))
+		);
+		System::assert_last_event(
+			Event::WorkerBanned { project_hash, worker: WORKER, reason: ReasonCodeStruct(2, 1) }
+				.into(),
+		);
+		assert_noop!(
+			Timekeeping::submit_time(
+				RuntimeOrigin::signed(WORKER),
+				project_hash,
+				StatusOfTimeRecord::Submitted,
+				10,
+				0,
+				20,
+				30,
+				0
+			),
+			Error::<Test>::WorkerBanned
+		);
+		assert_noop!(
+			Timekeeping::amend_time(
+				RuntimeOrigin::signed(WORKER),
+				draft_hash,
+				StatusOfTimeRecord::Submitted,
+				10,
+				0,
+				0
+			),
+			Error::<Test>::WorkerBanned
+		);
+		assert_noop!(
+			Timekeeping::ban_worker(
+				RuntimeOrigin::signed(OWNER),
+				project_hash,
+				WORKER,
+				ReasonCodeStruct::default()
+			),
+			Error::<Test>::WorkerBanned
+		);
+
+		assert_ok!(Timekeeping::unban_worker(RuntimeOrigin::signed(OWNER), project_hash, WORKER));
+
+		System::assert_last_event(Event::WorkerUnbanned { project_hash, worker: WORKER }.into());
+		assert_noop!(
+			Timekeeping::unban_worker(RuntimeOrigin::signed(OWNER), project_hash, WORKER),
+			Error::<Test>::WorkerNotBanned
+		);
+		submit(WORKER, StatusOfTimeRecord::Submitted, 20);
+	});
+}
+
+#[test]
+fn workers_banned_from_the_team_cannot_submit_time() {
+	new_test_ext().execute_with(|| {
+		let project_hash = new_project();
+		let draft_hash = submit(WORKER, StatusOfTimeRecord::Draft, 0);
+
+		assert_ok!(Teams::ban_member(RuntimeOrigin::signed(OWNER), project_hash, WORKER));
+
+		assert_noop!(
+			Timekeeping::submit_time(
+				RuntimeOrigin::signed(WORKER),
+				project_hash,
+				StatusOfTimeRecord::Submitted,
+				10,
+				0,
+				20,
+				30,
+				0
+			),
+			Error::<Test>::WorkerBanned
+		);
+		assert_noop!(
+			Timekeeping::amend_time(
+				RuntimeOrigin::signed(WORKER),
+				draft_hash,
+				StatusOfTimeRecord::Submitted,
+				10,
+				0,
+				0
+			),
+			Error::<Test>::WorkerBanned
+		);
+	});
+}
+
+#[test]
+#[should_panic(expected = "`BlocksPerHour` cannot be zero")]
+fn blocks_per_hour_cannot_be_zero() {
+	BlocksPerHour::set(0);
+
+	Timekeeping::integrity_test();
+}
+
+#[test]
+fn the_records_of_workers_and_projects_are_not_capped() {
+	new_test_ext().execute_with(|| {
+		let project_hash = new_project();
+		let records = (0..10)
+			.map(|index| submit(WORKER, StatusOfTimeRecord::Submitted, index * 5))
+			.collect::<Vec<_>>();
+
+		for record_hash in records {
+			assert!(WorkerTimeRecords::<Test>::contains_key(WORKER, record_hash));
+			assert!(ProjectTimeRecords::<Test>::contains_key(project_hash, record_hash));
+		}
+	});
+}
+
+#[test]
+fn records_no_longer_under_review_are_archived_and_restored() {
+	new_test_ext().execute_with(|| {
+		new_project();
+		let record_hash = submit(WORKER, StatusOfTimeRecord::Submitted, 0);
+		assert!(Timekeeping::is_time_record_owner(WORKER, record_hash));
+		assert!(!Timekeeping::is_time_record_owner(OWNER, record_hash));
+		assert!(!Timekeeping::validate_and_archive(WORKER, record_hash, true));
+
+		review(record_hash, StatusOfTimeRecord::Accepted);
+		assert!(!Timekeeping::validate_and_archive(OWNER, record_hash, true));
+		assert!(!Timekeeping::validate_and_archive(WORKER, H256::repeat_byte(9), true));
+		assert!(!Timekeeping::validate_and_archive(WORKER, record_hash, false));
+
+		assert!(Timekeeping::validate_and_archive(WORKER, record_hash, true));
+
+		assert!(!WorkerTimeRecords::<Test>::contains_key(WORKER, record_hash));
+		assert!(WorkerTimeRecordsArchive::<Test>::contains_key(WORKER, record_hash));
+		System::assert_last_event(
+			Event::TimeRecordArchived { record_hash, worker: WORKER, archived: true }.into(),
+		);
+		assert!(!Timekeeping::validate_and_archive(WORKER, record_hash, true));
+
+		assert!(Timekeeping::validate_and_archive(WORKER, record_hash, false));
+
+		assert!(WorkerTimeRecords::<Test>::contains_key(WORKER, record_hash));
+		assert!(!WorkerTimeRecordsArchive::<Test>::contains_key(WORKER, record_hash));
+	});
+}
+
+#[test]
+fn only_the_owner_sets_hourly_rates() {
+	new_test_ext().execute_with(|| {
+		let project_hash = new_project();
+		assert_noop!(
+			Timekeeping::set_hourly_rate(
+				RuntimeOrigin::signed(WORKER),
+				project_hash,
+				Some(WORKER),
+				Some(100)
+			),
+			Error::<Test>::NotProjectOwner
+		);
+
+		set_rate(None, 50);
+		set_rate(Some(WORKER), 80);
+
+		assert_eq!(ProjectHourlyRate::<Test>::get(project_hash), Some(50));
+		assert_eq!(WorkerHourlyRate::<Test>::get(project_hash, WORKER), Some(80));
+		System::assert_last_event(
+			Event::HourlyRateSet { project_hash, worker: Some(WORKER), rate: Some(80) }.into(),
+		);
+
+		assert_ok!(Timekeeping::set_hourly_rate(
+			RuntimeOrigin::signed(OWNER),
+			project_hash,
+			None,
+			None
+		));
+
+		assert!(!ProjectHourlyRate::<Test>::contains_key(project_hash));
+		assert_eq!(WorkerHourlyRate::<Test>::get(project_hash, WORKER), Some(80));
+	});
+}
+
+#[test]
+fn accepted_time_is_posted_at_the_hourly_rate_of_the_project() {
+	new_test_ext().execute_with(|| {
+		new_project();
+		set_rate(None, 50);
+		let record_hash = submit(WORKER, StatusOfTimeRecord::Submitted, 20);
+		review(record_hash, StatusOfTimeRecord::Disputed);
+		assert_eq!(labour_balances(), [0; 6]);
+		assert_ok!(Timekeeping::amend_time(
+			RuntimeOrigin::signed(WORKER),
+			record_hash,
+			StatusOfTimeRecord::Submitted,
+			5,
+			0,
+			0
+		));
+		System::set_block_number(200);
+
+		review(record_hash, StatusOfTimeRecord::Accepted);
+
+		// Five blocks are half an hour at ten blocks an hour.
+		assert_eq!(labour_balances(), [25, 25, 25, -25, 25, 25]);
+		let details: Vec<_> =
+			PostingDetail::<Test>::iter_prefix_values((WORKER, sales_of_services())).collect();
+		assert_eq!(details.len(), 1);
+		assert_eq!(details[0].counterparty, OWNER);
+		assert_eq!(details[0].changed_on_blocknumber, 200);
+		assert_eq!(details[0].applicable_period_blocknumber, 30);
+	});
+}
+
+#[test]
+fn time_of_a_closed_period_is_posted_to_the_first_open_period() {
+	new_test_ext().execute_with(|| {
+		new_project();
+		set_rate(None, 50);
+		let record_hash = submit(WORKER, StatusOfTimeRecord::Submitted, 20);
+		assert_ok!(Accounting::set_fiscal_calendar(
+			RuntimeOrigin::signed(OWNER),
+			FiscalCalendar { period_length: 50, year_end: 49, periods_per_year: 12 }
+		));
+		assert_ok!(Accounting::close_period(RuntimeOrigin::signed(OWNER), 30));
+
+		review(record_hash, StatusOfTimeRecord::Accepted);
+
+		// The owner closed the period ending at block 49, the worker keeps no calendar.
+		assert_eq!(labour_balances(), [50, 50, 50, -50, 50, 50]);
+		let period_of = |who, ledger| {
+			PostingDetail::<Test>::iter_prefix_values((who, ledger))
+				.map(|detail| detail.applicable_period_blocknumber)
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(
+			period_of(
+				OWNER,
+				Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(
+					CurrentLiabilities::WagesPayable,
+				)))
+			),
+			vec![50]
+		);
+		assert_eq!(period_of(WORKER, sales_of_services()), vec![30]);
+	});
+}
+
+#[test]
+fn the_hourly_rate_of_the_worker_comes_before_the_rate_of_the_project() {
+	new_test_ext().execute_with(|| {
+		new_project();
+		set_rate(None, 50);
+		set_rate(Some(WORKER), 80);
+
+		review(submit(WORKER, StatusOfTimeRecord::Submitted, 0), StatusOfTimeRecord::Accepted);
+
+		assert_eq!(labour_balances(), [80, 80, 80, -80, 80, 80]);
+	});
+}
+
+#[test]
+fn time_without_a_rate_or_of_the_owner_is_not_posted() {
+	new_test_ext().execute_with(|| {
+		new_project();
+
+		review(submit(WORKER, StatusOfTimeRecord::Submitted, 0), StatusOfTimeRecord::Accepted);
+		set_rate(None, 50);
+		review(submit(OWNER, StatusOfTimeRecord::Submitted, 0), StatusOfTimeRecord::Accepted);
+
+		assert_eq!(BalanceByLedger::<Test>::iter().count(), 0);
+		assert_eq!(TotalBlocksPerAddress::<Test>::get(WORKER), 10);
+		assert_eq!(TotalBlocksPerAddress::<Test>::get(OWNER), 10);
+	});
+}
@@ -619,7 +619,13 @@ where
 			// length fee. this is adjusted via `LengthToFee`.
 			let len_fee = Self::length_to_fee(len);
 
-			let base_fee = Self::weight_to_fee(T::BlockWeights::get().get(class).base_extrinsic);
+			// Totem Accounting: the postings of the fee and of its refund are part of the base fee.
+			let base_fee = Self::weight_to_fee(
+				T::BlockWeights::get()
+					.get(class)
+					.base_extrinsic
+					.saturating_add(Self::accounting_weight()),
+			);
 			FeeDetails {
 				inclusion_fee: Some(InclusionFee { base_fee, len_fee, adjusted_weight_fee }),
 				tip,
@@ -629,6 +635,12 @@ where
 		}
 	}
 
+	/// The weight of the postings made when a fee is paid: the fee, and the refund of the part of it
+	/// that was overpaid.
+	pub fn accounting_weight() -> Weight {
+		T::Accounting::posting_weight(2).saturating_mul(2)
+	}
+
 	fn length_to_fee(length: u32) -> BalanceOf<T> {
 		T::LengthToFee::weight_to_fee(&Weight::from_ref_time(length as u64))
 	}
@@ -836,6 +848,11 @@ where
 		len: usize,
 	) -> Result<Self::Pre, TransactionValidityError> {
 		let (_fee, imbalance) = self.withdraw_fee(who, call, info, len)?;
+		// Totem Accounting: the postings of the fee are not part of the weight of the call.
+		frame_system::Pallet::<T>::register_extra_weight_unchecked(
+			Pallet::<T>::accounting_weight(),
+			info.class,
+		);
 		Ok((self.0, who.clone(), imbalance))
 	}
 
@@ -849,17 +866,8 @@ where
 		if let Some((tip, who, imbalance)) = maybe_pre {
 			let actual_fee = Pallet::<T>::compute_actual_fee(len as u32, info, post_info, tip);
 
-			// Added for Totem Accounting
-			// If the actual fee is zero no need to record an entry unnecessarily
-			if !actual_fee.is_zero() {
-				let real_fee = T::TransactionConverter::convert(actual_fee.clone());
-				// Error should not happen, because there is no way the fees can overflow
-				// when converting i128 -> u128
-				T::Accounting::account_for_fees(real_fee, who.clone()).map_err(|_| {
-					TransactionValidityError::Invalid(InvalidTransaction::Custom(99))
-				})?;
-			}
-
+			// Totem Accounting: the fee is booked by the currency when it is withdrawn, and the
+			// refund of any overpaid fee when it is corrected.
 			T::OnChargeTransaction::correct_and_deposit_fee(
 				&who, info, post_info, actual_fee, tip, imbalance,
 			)?;
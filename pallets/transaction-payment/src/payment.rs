@@ -1,11 +1,12 @@
 /// ! Traits and default implementation for paying transaction fees.
 use crate::Config;
+use totem_primitives::accounting::Posting;
 
 use codec::FullCodec;
 use sp_runtime::{
 	traits::{
-		AtLeast32BitUnsigned, DispatchInfoOf, MaybeSerializeDeserialize, PostDispatchInfoOf,
-		Saturating, Zero,
+		AtLeast32BitUnsigned, Convert, DispatchInfoOf, MaybeSerializeDeserialize,
+		PostDispatchInfoOf, Saturating, Zero,
 	},
 	transaction_validity::InvalidTransaction,
 };
@@ -19,6 +20,9 @@ use frame_support::{
 type NegativeImbalanceOf<C, T> =
 	<C as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
 
+type AccountingBalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
 /// Handle withdrawing, refunding and depositing of transaction fees.
 pub trait OnChargeTransaction<T: Config> {
 	/// The underlying integer type in which fees are calculated.
@@ -83,6 +87,7 @@ where
 		Opposite = C::PositiveImbalance,
 	>,
 	OU: OnUnbalanced<NegativeImbalanceOf<C, T>>,
+	T::TransactionConverter: Convert<C::Balance, AccountingBalanceOf<T>>,
 {
 	type LiquidityInfo = Option<NegativeImbalanceOf<C, T>>;
 	type Balance = <C as Currency<<T as frame_system::Config>::AccountId>>::Balance;
@@ -134,6 +139,14 @@ where
 			// that case we don't refund anything.
 			let refund_imbalance = C::deposit_into_existing(who, refund_amount)
 				.unwrap_or_else(|_| C::PositiveImbalance::zero());
+			// Added for Totem Accounting
+			// The whole fee was booked when it was withdrawn, and the refund was booked as a mint.
+			if !refund_imbalance.peek().is_zero() {
+				let refund = T::TransactionConverter::convert(refund_imbalance.peek());
+				T::Accounting::account_for_fee_refund(refund, who.clone()).map_err(|_| {
+					TransactionValidityError::Invalid(InvalidTransaction::Custom(99))
+				})?;
+			}
 			// merge the imbalance caused by paying the fees and refunding parts of it again.
 			let adjusted_paid = paid
 				.offset(refund_imbalance)
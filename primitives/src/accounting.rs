@@ -35,25 +35,31 @@
 // You should have received a copy of the GNU General Public License
 // along with Totem.  If not, see <http://www.gnu.org/licenses/>.
 
+mod account_codes;
 mod chart_of_accounts;
 // pub use chart_of_accounts::{Ledger, {CurrentAssets, Sales, OperatingExpenses, _0030_, B,A,P,I,X,Cogs,Commissions,_0009_}};
+pub use account_codes::LEDGERS;
 pub use chart_of_accounts::{Ledger, *};
 
 use crate::LedgerBalance;
 use frame_support::{
-	dispatch::{DispatchResult, EncodeLike, TypeInfo},
+	dispatch::{DispatchError, DispatchResult, EncodeLike, TypeInfo},
 	pallet_prelude::*,
 };
 // use scale_info::TypeInfo;
-use sp_runtime::traits::Member;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::traits::{AtLeast32BitUnsigned, Member, One};
 use sp_std::prelude::*;
 
 /// Main Totem accounting trait.
 pub trait Posting<AccountId, Hash, BlockNumber, CoinAmount> {
 	type PostingIndex: Member + Copy + Into<u128> + Encode + Decode + Eq;
 
-	fn handle_multiposting_amounts(keys: &[Record<AccountId, Hash, BlockNumber>])
-		-> DispatchResult;
+	fn handle_multiposting_amounts(
+		keys: &[Record<AccountId, Hash, BlockNumber>],
+		source: PostingSource,
+	) -> DispatchResult;
 
 	fn account_for_simple_transfer(
 		from: AccountId,
@@ -75,6 +81,25 @@ pub trait Posting<AccountId, Hash, BlockNumber, CoinAmount> {
 	) -> DispatchResult;
 
 	fn account_for_fees(fee: CoinAmount, payer: AccountId) -> DispatchResult;
+
+	/// Books the part of a fee that was refunded to the payer. The refund itself is booked as a mint.
+	fn account_for_fee_refund(refund: CoinAmount, payer: AccountId) -> DispatchResult;
+
+	/// Books coins created into the free balance of `beneficiary`.
+	fn account_for_mint(beneficiary: AccountId, amount: CoinAmount) -> DispatchResult;
+
+	/// Books coins destroyed from the free balance of `loser`.
+	fn account_for_burn(loser: AccountId, amount: CoinAmount) -> DispatchResult;
+
+	/// Books coins slashed from the free balance of `loser`.
+	fn account_for_slash(loser: AccountId, amount: CoinAmount) -> DispatchResult;
+
+	/// Books the free and reserved balance lost by an account reaped below the existential deposit.
+	fn account_for_dust(
+		loser: AccountId,
+		free_amount: CoinAmount,
+		reserved_amount: CoinAmount,
+	) -> DispatchResult;
 	// fn account_for_burnt_fees(fee: CoinAmount, loser: AccountId) -> DispatchResult;
 	// fn distribute_fees_rewards(fee: CoinAmount, author: AccountId) -> DispatchResult;
 
@@ -82,12 +107,37 @@ pub trait Posting<AccountId, Hash, BlockNumber, CoinAmount> {
 
 	fn get_netfees_account() -> AccountId;
 
-	fn get_pseudo_random_hash(s: AccountId, r: AccountId) -> Hash;
+	/// The reference hash of a posting made now by `source`. It is derived from the reference that
+	/// `handle_multiposting_amounts` records for the posting, as long as no event is deposited in between.
+	fn reference_hash(source: PostingSource) -> Hash;
+
+	/// Posts the mirror image of the lines recorded for `who` under `posting_index`, and returns the posting
+	/// index of the reversal. A posting can only be reversed once for each account.
+	/// `ledger_count` is the number of ledgers `who` has posted to, and bounds the work of the reversal.
+	fn reverse_posting(
+		who: AccountId,
+		posting_index: Self::PostingIndex,
+		ledger_count: u32,
+	) -> Result<Self::PostingIndex, DispatchError>;
+
+	/// Queues a recipe that failed where the balance could not be left unchanged, to be posted later.
+	fn defer_posting(posting: DeferredPosting<AccountId, CoinAmount>);
+
+	/// The balance of a ledger of an account once the deferred postings waiting in the queue are posted.
+	fn settled_balance(who: &AccountId, ledger: Ledger) -> LedgerBalance;
+
+	/// The first block from `block` onwards that `who` can still post to: `block` itself when its period is
+	/// open, otherwise the block following the latest period closed by `who`.
+	fn first_open_block(who: &AccountId, block: BlockNumber) -> BlockNumber;
+
+	/// The weight of a posting of `records` records, to be added to the weight of the calls posting recipes.
+	fn posting_weight(records: u32) -> Weight;
 }
 
 /// Debit or Credit Indicator
 /// Debit and Credit balances are account specific - see chart of accounts.
-#[derive(MaxEncodedLen, Clone, Decode, Encode, Copy, TypeInfo)]
+#[derive(MaxEncodedLen, Debug, Clone, Decode, Encode, Copy, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum Indicator {
 	/// Debit
@@ -109,6 +159,7 @@ pub struct Record<AccountId, Hash, BlockNumber> {
 }
 
 #[derive(MaxEncodedLen, Clone, Decode, Encode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct Detail<AccountId, Hash, BlockNumber> {
 	pub counterparty: AccountId,
 	pub amount: LedgerBalance,
@@ -118,6 +169,136 @@ pub struct Detail<AccountId, Hash, BlockNumber> {
 	pub applicable_period_blocknumber: BlockNumber,
 }
 
+/// The pallet and call that made a posting.
+#[derive(MaxEncodedLen, Debug, Clone, Copy, Decode, Encode, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[scale_info(capture_docs = "always")]
+pub enum PostingSource {
+	/// Accounting: a manual journal entry.
+	JournalEntry,
+	/// Accounting: the reversal of a posting.
+	Reversal,
+	/// Accounting: the closing entries of a fiscal year.
+	YearEndClose,
+	/// Balances: a transfer of free balance.
+	Transfer,
+	/// Transaction Payment: the fee of a transaction.
+	Fees,
+	/// Balances: free balance moved to the reserved balance.
+	Reserve,
+	/// Balances: reserved balance moved back to the free balance.
+	Unreserve,
+	/// Balances: reserved balance slashed.
+	SlashReserved,
+	/// Balances: reserved balance moved to another account.
+	RepatriateReserved,
+	/// Transaction Payment: the refunded part of a fee.
+	FeeRefund,
+	/// Balances: coins created into a free balance.
+	Mint,
+	/// Balances: coins destroyed from a free balance.
+	Burn,
+	/// Balances: free balance slashed.
+	Slash,
+	/// Balances: the balance lost by an account reaped below the existential deposit.
+	Dust,
+	/// Accounting: the adjustment of the internal balance ledgers to the balances.
+	Reconciliation,
+	/// Accounting: the opening balances of an account.
+	OpeningBalances,
+	/// Any other pallet, by its index in the runtime and the index of its call.
+	Pallet { pallet_index: u8, call_index: u8 },
+}
+
+/// A recipe that could not be posted when the balance it accounts for was moved. It is kept until it is
+/// posted, so that the ledgers catch up with the balances.
+#[derive(MaxEncodedLen, Debug, Clone, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub enum DeferredPosting<AccountId, CoinAmount> {
+	/// Reserved balance moved back to the free balance.
+	Unreserve { beneficiary: AccountId, amount: CoinAmount },
+	/// Reserved balance slashed.
+	SlashReserve { beneficiary: AccountId, amount: CoinAmount },
+	/// Free balance slashed.
+	Slash { beneficiary: AccountId, amount: CoinAmount },
+}
+
+/// Where a reconciliation of the internal balance ledgers with the balances resumes.
+#[derive(MaxEncodedLen, Debug, Clone, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub enum ReconciliationCursor<AccountId> {
+	/// After this account of the balances.
+	Account(AccountId),
+	/// Among the accounts that hold ledgers but no balance, such as reaped accounts, after this ledger of this
+	/// account. `None` when no ledger has been visited yet.
+	Ledger(Option<(AccountId, Ledger)>),
+}
+
+/// The progress of a reconciliation of the internal balance ledgers with the balances.
+/// The accounts of the balances are reconciled first, then the accounts that only hold ledgers.
+#[derive(MaxEncodedLen, Debug, Default, Clone, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub struct Reconciliation<AccountId> {
+	/// Where the reconciliation resumes, `None` before it has started.
+	pub cursor: Option<ReconciliationCursor<AccountId>>,
+	/// The number of accounts reconciled.
+	pub accounts: u32,
+	/// The number of accounts whose ledgers were adjusted.
+	pub adjusted: u32,
+	/// The number of accounts whose adjustment could not be posted.
+	pub failed: u32,
+}
+
+/// Where on chain a posting was made, and by which call. The reference hash of the posting is the hash of its
+/// reference.
+#[derive(MaxEncodedLen, Debug, Clone, Copy, Decode, Encode, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct PostingReference<BlockNumber> {
+	pub block_number: BlockNumber,
+	/// `None` when the posting was not made by an extrinsic, for example in a hook.
+	pub extrinsic_index: Option<u32>,
+	/// The number of events deposited in the block before the posting.
+	pub event_index: u32,
+	pub source: PostingSource,
+}
+
+/// A line of a manual journal entry, posted to the books of the account that submits it.
+/// The amount is never negative: the line increases its ledger on the normal side of the ledger and decreases
+/// it on the other side.
+#[derive(MaxEncodedLen, Debug, Clone, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub struct JournalLine<AccountId, BlockNumber> {
+	pub ledger: Ledger,
+	pub counterparty: AccountId,
+	pub amount: LedgerBalance,
+	pub debit_credit: Indicator,
+	pub applicable_period_blocknumber: BlockNumber,
+}
+
+/// The fiscal calendar of an account.
+/// Periods are `period_length` blocks long and are aligned so that one of them ends on the `year_end` block.
+/// A fiscal year is `periods_per_year` periods long, so the years end every that many periods from `year_end`.
+#[derive(MaxEncodedLen, Debug, Clone, Copy, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub struct FiscalCalendar<BlockNumber> {
+	pub period_length: BlockNumber,
+	pub year_end: BlockNumber,
+	pub periods_per_year: u32,
+}
+
+/// The balance of a ledger in a trial balance, shown on its debit or its credit side.
+#[derive(Debug, Clone, Decode, Encode, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct TrialBalanceLine {
+	pub ledger: Ledger,
+	pub debit: LedgerBalance,
+	pub credit: LedgerBalance,
+}
+
+/// The trial balance of an account. The totals agree when the books of the account are balanced.
+#[derive(Debug, Clone, Default, Decode, Encode, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct TrialBalance {
+	pub lines: Vec<TrialBalanceLine>,
+	pub total_debit: LedgerBalance,
+	pub total_credit: LedgerBalance,
+}
+
 // Implementations
 
 impl EncodeLike<Indicator> for bool {}
@@ -131,6 +312,30 @@ impl Indicator {
 	}
 }
 
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> FiscalCalendar<BlockNumber> {
+	/// The last block of the period that contains `block`. The period length must not be zero.
+	pub fn period_end(&self, block: BlockNumber) -> BlockNumber {
+		if block <= self.year_end {
+			let periods = (self.year_end - block) / self.period_length;
+			self.year_end - periods * self.period_length
+		} else {
+			let periods = (block - self.year_end).saturating_add(self.period_length - One::one()) /
+				self.period_length;
+			self.year_end.saturating_add(periods.saturating_mul(self.period_length))
+		}
+	}
+
+	/// Whether a fiscal year ends on `block`. The period length and the number of periods must not be zero.
+	pub fn is_year_end(&self, block: BlockNumber) -> bool {
+		let year_length = self.period_length.saturating_mul(self.periods_per_year.into());
+		let distance = match block <= self.year_end {
+			true => self.year_end - block,
+			false => block - self.year_end,
+		};
+		(distance % year_length).is_zero()
+	}
+}
+
 #[cfg(any(test, feature = "mock"))]
 impl<AccountId, Hash, BlockNumber, CoinAmount> Posting<AccountId, Hash, BlockNumber, CoinAmount>
 	for ()
@@ -139,6 +344,7 @@ impl<AccountId, Hash, BlockNumber, CoinAmount> Posting<AccountId, Hash, BlockNum
 
 	fn handle_multiposting_amounts(
 		_fwd: &[Record<AccountId, Hash, BlockNumber>],
+		_source: PostingSource,
 	) -> DispatchResult {
 		unimplemented!("Used as a mock, shouldn't be called")
 	}
@@ -155,6 +361,30 @@ impl<AccountId, Hash, BlockNumber, CoinAmount> Posting<AccountId, Hash, BlockNum
 		unimplemented!("Used as a mock, shouldn't be called")
 	}
 
+	fn account_for_fee_refund(_r: CoinAmount, _p: AccountId) -> DispatchResult {
+		unimplemented!("Used as a mock, shouldn't be called")
+	}
+
+	fn account_for_mint(_beneficiary: AccountId, _amount: CoinAmount) -> DispatchResult {
+		unimplemented!("Used as a mock, shouldn't be called")
+	}
+
+	fn account_for_burn(_loser: AccountId, _amount: CoinAmount) -> DispatchResult {
+		unimplemented!("Used as a mock, shouldn't be called")
+	}
+
+	fn account_for_slash(_loser: AccountId, _amount: CoinAmount) -> DispatchResult {
+		unimplemented!("Used as a mock, shouldn't be called")
+	}
+
+	fn account_for_dust(
+		_loser: AccountId,
+		_free_amount: CoinAmount,
+		_reserved_amount: CoinAmount,
+	) -> DispatchResult {
+		unimplemented!("Used as a mock, shouldn't be called")
+	}
+
 	fn set_reserve_amount(_beneficiary: AccountId, _amount: CoinAmount) -> DispatchResult {
 		unimplemented!("Used as a mock, shouldn't be called")
 	}
@@ -192,7 +422,31 @@ impl<AccountId, Hash, BlockNumber, CoinAmount> Posting<AccountId, Hash, BlockNum
 		unimplemented!("Used as a mock, shouldn't be called")
 	}
 
-	fn get_pseudo_random_hash(_s: AccountId, _r: AccountId) -> Hash {
+	fn reference_hash(_source: PostingSource) -> Hash {
+		unimplemented!("Used as a mock, shouldn't be called")
+	}
+
+	fn reverse_posting(
+		_who: AccountId,
+		_posting_index: Self::PostingIndex,
+		_ledger_count: u32,
+	) -> Result<Self::PostingIndex, DispatchError> {
+		unimplemented!("Used as a mock, shouldn't be called")
+	}
+
+	fn defer_posting(_posting: DeferredPosting<AccountId, CoinAmount>) {
 		unimplemented!("Used as a mock, shouldn't be called")
 	}
+
+	fn settled_balance(_who: &AccountId, _ledger: Ledger) -> LedgerBalance {
+		unimplemented!("Used as a mock, shouldn't be called")
+	}
+
+	fn first_open_block(_who: &AccountId, _block: BlockNumber) -> BlockNumber {
+		unimplemented!("Used as a mock, shouldn't be called")
+	}
+
+	fn posting_weight(_records: u32) -> Weight {
+		Weight::zero()
+	}
 }
@@ -0,0 +1,792 @@
+//                              Næ§@@@ÑÉ©
+//                        æ@@@@@@@@@@@@@@@@@@
+//                    Ñ@@@@?.?@@@@@@@@@@@@@@@@@@@N
+//                 ¶@@@@@?^%@@.=@@@@@@@@@@@@@@@@@@@@
+//               N@@@@@@@?^@@@»^@@@@@@@@@@@@@@@@@@@@@@
+//               @@@@@@@@?^@@@».............?@@@@@@@@@É
+//              Ñ@@@@@@@@?^@@@@@@@@@@@@@@@@@@'?@@@@@@@@Ñ
+//              @@@@@@@@@?^@@@»..............»@@@@@@@@@@
+//              @@@@@@@@@?^@@@»^@@@@@@@@@@@@@@@@@@@@@@@@
+//              @@@@@@@@@?^ë@@&.@@@@@@@@@@@@@@@@@@@@@@@@
+//               @@@@@@@@?^´@@@o.%@@@@@@@@@@@@@@@@@@@@©
+//                @@@@@@@?.´@@@@@ë.........*.±@@@@@@@æ
+//                 @@@@@@@@?´.I@@@@@@@@@@@@@@.&@@@@@N
+//                  N@@@@@@@@@@ë.*=????????=?@@@@@Ñ
+//                    @@@@@@@@@@@@@@@@@@@@@@@@@@@¶
+//                        É@@@@@@@@@@@@@@@@Ñ¶
+//                             Næ§@@@ÑÉ©
+
+// Copyright 2020 Chris D'Costa
+// This file is part of Totem Live Accounting.
+// Authors:
+// - Félix Daudré-Vignier   email: felix@totemaccounting.com
+// - Chris D'Costa          email: chris.dcosta@totemaccounting.com
+
+// Totem is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Totem is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Totem.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Totem account codes of the ledgers of the chart of accounts.
+//!
+//! An account code such as `B12_1001_D000` reads as the statement and group (`B12`), the section (`1001`),
+//! the normal balance side (`D` or `C`) and the index within the section (`000`). Control accounts carry the
+//! side after the index (`C60_0001_000D`). The account number is the same code in digits, with the side
+//! written as `1` for debit and `2` for credit: `B12_1001_D000` is `12_1001_1_000`.
+//!
+//! Ledgers that were documented with several codes use the code of the statement they appear in, and ledgers
+//! without a documented code are numbered after the last code of their section.
+
+use super::chart_of_accounts::*;
+use crate::Account;
+
+macro_rules! account_codes {
+	($([$($ledger:tt)*] => $code:literal, $number:literal;)*) => {
+		/// Every ledger of the chart of accounts, in chart order.
+		pub const LEDGERS: &[Ledger] = &[$($($ledger)*),*];
+
+		impl Ledger {
+			/// The account code of the ledger, for example `B10_0001_D000`.
+			pub fn account_code(&self) -> &'static str {
+				match self {
+					$($($ledger)* => $code,)*
+				}
+			}
+
+			/// The account number of the ledger, for example `10_0001_1_000`.
+			pub fn account_number(&self) -> Account {
+				match self {
+					$($($ledger)* => $number,)*
+				}
+			}
+
+			/// The ledger with the given account code.
+			pub fn from_account_code(code: &str) -> Option<Ledger> {
+				match code {
+					$($code => Some($($ledger)*),)*
+					_ => None,
+				}
+			}
+
+			/// The ledger with the given account number.
+			pub fn from_account_number(number: Account) -> Option<Ledger> {
+				match number {
+					$($number => Some($($ledger)*),)*
+					_ => None,
+				}
+			}
+		}
+	};
+}
+
+#[rustfmt::skip]
+account_codes! {
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::BankCurrentAccount)))] => "B10_0001_D000", 10_0001_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::BankSavingsAccount)))] => "B10_0002_D000", 10_0002_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::PettyCash)))] => "B10_0003_D000", 10_0003_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::InternalBalance)))] => "B10_0004_D000", 10_0004_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::InternalReservedBalance)))] => "B10_0004_D001", 10_0004_1_001;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::EscrowDeposit)))] => "B10_0005_D000", 10_0005_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::FixedDeposits)))] => "B10_0006_D000", 10_0006_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::PrepaidExpensesonaccountOperatingExpense)))] => "B10_0007_D000", 10_0007_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::DirectorsLoanAccount)))] => "B10_0008_D000", 10_0008_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::TradeReceivables(Parties::RelatedParties))))] => "B10_0009_D000", 10_0009_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::TradeReceivables(Parties::NonRelatedParties))))] => "B10_0009_D001", 10_0009_1_001;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::AccruedRevenuesReceivables(AccruedRevenuesReceivables::LoanTo(Parties::RelatedParties)))))] => "B10_0010_D000", 10_0010_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::AccruedRevenuesReceivables(AccruedRevenuesReceivables::LoanTo(Parties::NonRelatedParties)))))] => "B10_0010_D001", 10_0010_1_001;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::AccruedRevenuesReceivables(AccruedRevenuesReceivables::Other))))] => "B10_0010_D002", 10_0010_1_002;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::AccruedRevenuesReceivables(AccruedRevenuesReceivables::FinanceLease))))] => "B10_0010_D003", 10_0010_1_003;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::AccruedRevenuesReceivables(AccruedRevenuesReceivables::StaffLoans))))] => "B10_0010_D004", 10_0010_1_004;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::AccruedRevenuesReceivables(AccruedRevenuesReceivables::GovernmentGrant))))] => "B10_0010_D005", 10_0010_1_005;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::ImpairmentLoss(_0036_::FinancialAssets))))] => "B10_0011_C001", 10_0011_2_001;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::ImpairmentLoss(_0036_::ContractAssets))))] => "B10_0012_C000", 10_0012_2_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::ImpairmentLoss(_0036_::Other))))] => "B10_0011_C002", 10_0011_2_002;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::AllowanceForDoubtfulDebts)))] => "B10_0011_C000", 10_0011_2_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::Inventory(Inventory::RawMaterials))))] => "B10_0013_D000", 10_0013_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::Inventory(Inventory::WorkInProgress))))] => "B10_0013_D001", 10_0013_1_001;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::Inventory(Inventory::FinishedGoods))))] => "B10_0013_D002", 10_0013_1_002;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::AdvancestosuppliersTradeSupplies)))] => "B10_0014_D000", 10_0014_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::Contractassets)))] => "B10_0015_D000", 10_0015_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::EquitySecuritiesFVTPL)))] => "B10_0016_D000", 10_0016_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::AssetsOfDisposalGroupHeldForSale)))] => "B10_0017_D000", 10_0017_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::LoanNotesFloatingFixedAmortisedCost)))] => "B10_0018_D000", 10_0018_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::DerivativeFinInstr(Derivatives::InterestRateSwaps))))] => "B10_0019_D000", 10_0019_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::DerivativeFinInstr(Derivatives::CurrencyForwards))))] => "B10_0019_D001", 10_0019_1_001;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::DerivativeFinInstr(Derivatives::CommodityForwards))))] => "B10_0019_D002", 10_0019_1_002;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssetsCrypto(CurrentAssetsCrypto::Coins(Coins::UTXOType))))] => "B11_4001_D000", 11_4001_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssetsCrypto(CurrentAssetsCrypto::Coins(Coins::NonceType))))] => "B11_4001_D001", 11_4001_1_001;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssetsCrypto(CurrentAssetsCrypto::Coins(Coins::ParachainType))))] => "B11_4001_D002", 11_4001_1_002;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssetsCrypto(CurrentAssetsCrypto::Coins(Coins::ThirdPartyCustody))))] => "B11_4001_D003", 11_4001_1_003;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssetsCrypto(CurrentAssetsCrypto::Coins(Coins::Other))))] => "B11_4001_D004", 11_4001_1_004;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssetsCrypto(CurrentAssetsCrypto::Tokens(Tokens::SmartContract(Fungibility::Fungible)))))] => "B11_4002_D000", 11_4002_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssetsCrypto(CurrentAssetsCrypto::Tokens(Tokens::SmartContract(Fungibility::NonFungible)))))] => "B11_4003_D000", 11_4003_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssetsCrypto(CurrentAssetsCrypto::Tokens(Tokens::ThirdParty(Fungibility::Fungible)))))] => "B11_4002_D001", 11_4002_1_001;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssetsCrypto(CurrentAssetsCrypto::Tokens(Tokens::ThirdParty(Fungibility::NonFungible)))))] => "B11_4003_D001", 11_4003_1_001;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssetsCrypto(CurrentAssetsCrypto::Tokens(Tokens::LiquidityPoolPair))))] => "B11_4004_D000", 11_4004_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssetsCrypto(CurrentAssetsCrypto::Tokens(Tokens::CryptoLoanCollateral))))] => "B11_4005_D000", 11_4005_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssetsCrypto(CurrentAssetsCrypto::CoinImpairment(Coins::UTXOType))))] => "B11_4006_C000", 11_4006_2_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssetsCrypto(CurrentAssetsCrypto::CoinImpairment(Coins::NonceType))))] => "B11_4006_C001", 11_4006_2_001;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssetsCrypto(CurrentAssetsCrypto::CoinImpairment(Coins::ParachainType))))] => "B11_4006_C002", 11_4006_2_002;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssetsCrypto(CurrentAssetsCrypto::CoinImpairment(Coins::ThirdPartyCustody))))] => "B11_4006_C003", 11_4006_2_003;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssetsCrypto(CurrentAssetsCrypto::CoinImpairment(Coins::Other))))] => "B11_4006_C004", 11_4006_2_004;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssetsCrypto(CurrentAssetsCrypto::TokenImpairment(Tokens::SmartContract(Fungibility::Fungible)))))] => "B11_4007_C000", 11_4007_2_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssetsCrypto(CurrentAssetsCrypto::TokenImpairment(Tokens::SmartContract(Fungibility::NonFungible)))))] => "B11_4008_C000", 11_4008_2_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssetsCrypto(CurrentAssetsCrypto::TokenImpairment(Tokens::ThirdParty(Fungibility::Fungible)))))] => "B11_4007_C001", 11_4007_2_001;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssetsCrypto(CurrentAssetsCrypto::TokenImpairment(Tokens::ThirdParty(Fungibility::NonFungible)))))] => "B11_4008_C001", 11_4008_2_001;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssetsCrypto(CurrentAssetsCrypto::TokenImpairment(Tokens::LiquidityPoolPair))))] => "B11_4009_C000", 11_4009_2_000;
+	[Ledger::BalanceSheet(B::Assets(A::CurrentAssetsCrypto(CurrentAssetsCrypto::TokenImpairment(Tokens::CryptoLoanCollateral))))] => "B11_4010_C000", 11_4010_2_000;
+	[Ledger::BalanceSheet(B::Assets(A::FixedAssets(FixedAssets::PropPlantEquip(PPE::Land))))] => "B12_1001_D000", 12_1001_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::FixedAssets(FixedAssets::PropPlantEquip(PPE::Buildings))))] => "B12_1001_D001", 12_1001_1_001;
+	[Ledger::BalanceSheet(B::Assets(A::FixedAssets(FixedAssets::PropPlantEquip(PPE::FurnitureFixturesFittings))))] => "B12_1001_D002", 12_1001_1_002;
+	[Ledger::BalanceSheet(B::Assets(A::FixedAssets(FixedAssets::PropPlantEquip(PPE::PlantAndEquipment))))] => "B12_1001_D003", 12_1001_1_003;
+	[Ledger::BalanceSheet(B::Assets(A::FixedAssets(FixedAssets::PropPlantEquip(PPE::MotorVehicles))))] => "B12_1001_D004", 12_1001_1_004;
+	[Ledger::BalanceSheet(B::Assets(A::FixedAssets(FixedAssets::PropPlantEquip(PPE::Supplies))))] => "B12_1001_D005", 12_1001_1_005;
+	[Ledger::BalanceSheet(B::Assets(A::FixedAssets(FixedAssets::PropPlantEquip(PPE::ComputerAndITEquipment))))] => "B12_1001_D006", 12_1001_1_006;
+	[Ledger::BalanceSheet(B::Assets(A::FixedAssets(FixedAssets::PropPlantEquip(PPE::RightOfUseAssets))))] => "B12_1001_D007", 12_1001_1_007;
+	[Ledger::BalanceSheet(B::Assets(A::FixedAssets(FixedAssets::PropPlantEquip(PPE::LeaseholdImprovements))))] => "B12_1001_D009", 12_1001_1_009;
+	[Ledger::BalanceSheet(B::Assets(A::FixedAssets(FixedAssets::PropPlantEquip(PPE::AssetsUnderConstruction))))] => "B12_1001_D008", 12_1001_1_008;
+	[Ledger::BalanceSheet(B::Assets(A::FixedAssets(FixedAssets::AccumulatedDepreciation(PPE::Land))))] => "B12_1002_C000", 12_1002_2_000;
+	[Ledger::BalanceSheet(B::Assets(A::FixedAssets(FixedAssets::AccumulatedDepreciation(PPE::Buildings))))] => "B12_1002_C001", 12_1002_2_001;
+	[Ledger::BalanceSheet(B::Assets(A::FixedAssets(FixedAssets::AccumulatedDepreciation(PPE::FurnitureFixturesFittings))))] => "B12_1002_C002", 12_1002_2_002;
+	[Ledger::BalanceSheet(B::Assets(A::FixedAssets(FixedAssets::AccumulatedDepreciation(PPE::PlantAndEquipment))))] => "B12_1002_C003", 12_1002_2_003;
+	[Ledger::BalanceSheet(B::Assets(A::FixedAssets(FixedAssets::AccumulatedDepreciation(PPE::MotorVehicles))))] => "B12_1002_C004", 12_1002_2_004;
+	[Ledger::BalanceSheet(B::Assets(A::FixedAssets(FixedAssets::AccumulatedDepreciation(PPE::Supplies))))] => "B12_1002_C005", 12_1002_2_005;
+	[Ledger::BalanceSheet(B::Assets(A::FixedAssets(FixedAssets::AccumulatedDepreciation(PPE::ComputerAndITEquipment))))] => "B12_1002_C006", 12_1002_2_006;
+	[Ledger::BalanceSheet(B::Assets(A::FixedAssets(FixedAssets::AccumulatedDepreciation(PPE::RightOfUseAssets))))] => "B12_1002_C007", 12_1002_2_007;
+	[Ledger::BalanceSheet(B::Assets(A::FixedAssets(FixedAssets::AccumulatedDepreciation(PPE::LeaseholdImprovements))))] => "B12_1002_C009", 12_1002_2_009;
+	[Ledger::BalanceSheet(B::Assets(A::FixedAssets(FixedAssets::AccumulatedDepreciation(PPE::AssetsUnderConstruction))))] => "B12_1002_C008", 12_1002_2_008;
+	[Ledger::BalanceSheet(B::Assets(A::IntagibleAssets(IntagibleAssets::Intangibles(IntangibleAssetList::TrademarkAndPatents))))] => "B13_2002_D000", 13_2002_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::IntagibleAssets(IntagibleAssets::Intangibles(IntangibleAssetList::ComputerSoftwareLicences))))] => "B13_2003_D000", 13_2003_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::IntagibleAssets(IntagibleAssets::Intangibles(IntangibleAssetList::OtherIntangibleAssets))))] => "B13_2001_D000", 13_2001_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::IntagibleAssets(IntagibleAssets::ExternalNetworkCrypto)))] => "B13_2004_D000", 13_2004_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::IntagibleAssets(IntagibleAssets::Goodwill)))] => "B13_2005_D000", 13_2005_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::IntagibleAssets(IntagibleAssets::ImpairmentLoss)))] => "B13_2007_C000", 13_2007_2_000;
+	[Ledger::BalanceSheet(B::Assets(A::IntagibleAssets(IntagibleAssets::AccumulatedDepreciationIntangibles(IntangibleAssetList::TrademarkAndPatents))))] => "B13_2006_C000", 13_2006_2_000;
+	[Ledger::BalanceSheet(B::Assets(A::IntagibleAssets(IntagibleAssets::AccumulatedDepreciationIntangibles(IntangibleAssetList::ComputerSoftwareLicences))))] => "B13_2006_C001", 13_2006_2_001;
+	[Ledger::BalanceSheet(B::Assets(A::IntagibleAssets(IntagibleAssets::AccumulatedDepreciationIntangibles(IntangibleAssetList::OtherIntangibleAssets))))] => "B13_2006_C002", 13_2006_2_002;
+	[Ledger::BalanceSheet(B::Assets(A::NonCurrentAssets(NonCurrentAssets::InvestmentIn(II::Subsidiaries))))] => "B14_3001_D000", 14_3001_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::NonCurrentAssets(NonCurrentAssets::InvestmentIn(II::JointVentures))))] => "B14_3002_D000", 14_3002_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::NonCurrentAssets(NonCurrentAssets::InvestmentIn(II::Associates))))] => "B14_3003_D000", 14_3003_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::NonCurrentAssets(NonCurrentAssets::InvestmentIn(II::Properties))))] => "B14_3004_D000", 14_3004_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::NonCurrentAssets(NonCurrentAssets::EquitySecuritiesFVOCI)))] => "B14_3005_D000", 14_3005_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::NonCurrentAssets(NonCurrentAssets::DebtSecuritiesFVOCI)))] => "B14_3005_D001", 14_3005_1_001;
+	[Ledger::BalanceSheet(B::Assets(A::NonCurrentAssets(NonCurrentAssets::DeferredIncomeTax)))] => "B14_3006_D000", 14_3006_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::NonCurrentAssets(NonCurrentAssets::NonListedDebtInstrFVTPL)))] => "B14_3008_D000", 14_3008_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::NonCurrentAssets(NonCurrentAssets::MandatorilyMeasuredAtFVTPL)))] => "B14_3008_D001", 14_3008_1_001;
+	[Ledger::BalanceSheet(B::Assets(A::NonCurrentAssets(NonCurrentAssets::ConvertibleBondsFVTPL)))] => "B14_3008_D002", 14_3008_1_002;
+	[Ledger::BalanceSheet(B::Assets(A::NonCurrentAssets(NonCurrentAssets::OtherReceivables(OtherReceivables::LoansTo(Parties::RelatedParties)))))] => "B14_3009_D001", 14_3009_1_001;
+	[Ledger::BalanceSheet(B::Assets(A::NonCurrentAssets(NonCurrentAssets::OtherReceivables(OtherReceivables::LoansTo(Parties::NonRelatedParties)))))] => "B14_3009_D000", 14_3009_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::NonCurrentAssets(NonCurrentAssets::OtherReceivables(OtherReceivables::FinanceLease))))] => "B14_3009_D003", 14_3009_1_003;
+	[Ledger::BalanceSheet(B::Assets(A::NonCurrentAssets(NonCurrentAssets::OtherReceivables(OtherReceivables::StaffLoans))))] => "B14_3009_D004", 14_3009_1_004;
+	[Ledger::BalanceSheet(B::Assets(A::NonCurrentAssets(NonCurrentAssets::OtherReceivables(OtherReceivables::IndemnificationAssets))))] => "B14_3009_D005", 14_3009_1_005;
+	[Ledger::BalanceSheet(B::Assets(A::NonCurrentAssets(NonCurrentAssets::OtherReceivables(OtherReceivables::Other))))] => "B14_3009_D002", 14_3009_1_002;
+	[Ledger::BalanceSheet(B::Assets(A::NonCurrentAssets(NonCurrentAssets::FinancialInstruments(Derivatives::InterestRateSwaps))))] => "B14_3010_D000", 14_3010_1_000;
+	[Ledger::BalanceSheet(B::Assets(A::NonCurrentAssets(NonCurrentAssets::FinancialInstruments(Derivatives::CurrencyForwards))))] => "B14_3010_D001", 14_3010_1_001;
+	[Ledger::BalanceSheet(B::Assets(A::NonCurrentAssets(NonCurrentAssets::FinancialInstruments(Derivatives::CommodityForwards))))] => "B14_3010_D002", 14_3010_1_002;
+	[Ledger::BalanceSheet(B::Assets(A::NonCurrentAssets(NonCurrentAssets::ImpairmentLossOn(II::Subsidiaries))))] => "B14_3007_C001", 14_3007_2_001;
+	[Ledger::BalanceSheet(B::Assets(A::NonCurrentAssets(NonCurrentAssets::ImpairmentLossOn(II::JointVentures))))] => "B14_3007_C002", 14_3007_2_002;
+	[Ledger::BalanceSheet(B::Assets(A::NonCurrentAssets(NonCurrentAssets::ImpairmentLossOn(II::Associates))))] => "B14_3007_C003", 14_3007_2_003;
+	[Ledger::BalanceSheet(B::Assets(A::NonCurrentAssets(NonCurrentAssets::ImpairmentLossOn(II::Properties))))] => "B14_3007_C004", 14_3007_2_004;
+	[Ledger::BalanceSheet(B::Assets(A::NonCurrentAssets(NonCurrentAssets::ImpairmentOfFixedAssets)))] => "B14_3007_C005", 14_3007_2_005;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::SalesTaxbyJurisdict)))] => "B20_0001_C000", 20_0001_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::FederalStateTaxByJurisdict)))] => "B20_0002_C000", 20_0002_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::AccountsPayableTradeCreditors(Parties::RelatedParties))))] => "B20_0003_C000", 20_0003_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::AccountsPayableTradeCreditors(Parties::NonRelatedParties))))] => "B20_0013_C000", 20_0013_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::ShortTermLoansPayable(Parties::RelatedParties))))] => "B20_0003_C001", 20_0003_2_001;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::ShortTermLoansPayable(Parties::NonRelatedParties))))] => "B20_0013_C001", 20_0013_2_001;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::CurrentPortionOfLongTermDebtNonTrade(Parties::RelatedParties))))] => "B20_0003_C002", 20_0003_2_002;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::CurrentPortionOfLongTermDebtNonTrade(Parties::NonRelatedParties))))] => "B20_0013_C002", 20_0013_2_002;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::SalariesPayable)))] => "B20_0004_C000", 20_0004_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::WagesPayable)))] => "B20_0005_C000", 20_0005_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::CommissionPayable)))] => "B20_0006_C000", 20_0006_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::FreightPayable)))] => "B20_0007_C000", 20_0007_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::OtherAccruedExpensesPayable)))] => "B20_0008_C000", 20_0008_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::PayrollTaxByJurisdict)))] => "B20_0009_C000", 20_0009_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::InterestPayable)))] => "B20_0010_C000", 20_0010_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::AdvancesFromCustomers)))] => "B20_0011_C000", 20_0011_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::LawsuitsLegalCostsPayable)))] => "B20_0012_C000", 20_0012_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::FinGuarantees)))] => "B20_0014_C000", 20_0014_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::ContractLiabilities)))] => "B20_0015_C000", 20_0015_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::BankoverDrafts)))] => "B20_0016_C000", 20_0016_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::CurrentPortionOfLeaseLiabilities)))] => "B20_0017_C000", 20_0017_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::Provisions(Provisions::WarrantyLegalClaimsOthers))))] => "B20_0018_C000", 20_0018_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::Provisions(Provisions::UnusedLeave))))] => "B20_0018_C001", 20_0018_2_001;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::DerivativeFinInstr(Derivatives::InterestRateSwaps))))] => "B20_0019_C000", 20_0019_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::DerivativeFinInstr(Derivatives::CurrencyForwards))))] => "B20_0019_C001", 20_0019_2_001;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::DerivativeFinInstr(Derivatives::CommodityForwards))))] => "B20_0019_C002", 20_0019_2_002;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::DividendPayable)))] => "B20_0020_C000", 20_0020_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(CurrentLiabilities::CurrentPortionOfBankBorrowingsPayable)))] => "B20_0021_C000", 20_0021_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::NonCurrentLiabilities(NonCurrentLiabilities::WitholdingIncomeTaxJurisdictPayable)))] => "B21_1001_C000", 21_1001_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::NonCurrentLiabilities(NonCurrentLiabilities::DeferredOrUnearnedRevenues)))] => "B21_1002_C000", 21_1002_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::NonCurrentLiabilities(NonCurrentLiabilities::BondsPayable)))] => "B21_1003_C000", 21_1003_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::NonCurrentLiabilities(NonCurrentLiabilities::NotesLoansPayable)))] => "B21_1004_C000", 21_1004_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::NonCurrentLiabilities(NonCurrentLiabilities::WarantyLiab)))] => "B21_1005_C000", 21_1005_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::NonCurrentLiabilities(NonCurrentLiabilities::CorpTaxByJurisdictPayableAtPeriodEnd)))] => "B21_1006_C000", 21_1006_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::NonCurrentLiabilities(NonCurrentLiabilities::InstallmentLoansPayable)))] => "B21_1007_C000", 21_1007_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::NonCurrentLiabilities(NonCurrentLiabilities::PortionBankBorrowingsPayable)))] => "B21_1008_C000", 21_1008_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::NonCurrentLiabilities(NonCurrentLiabilities::MortgageOrPropertyLoansPayable)))] => "B21_1009_C000", 21_1009_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::NonCurrentLiabilities(NonCurrentLiabilities::RedeemablePreferenceShares)))] => "B21_1010_C000", 21_1010_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::NonCurrentLiabilities(NonCurrentLiabilities::PortionLeaseLiab)))] => "B21_1011_C000", 21_1011_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::NonCurrentLiabilities(NonCurrentLiabilities::ContingentConsiderationPayable)))] => "B21_1012_C000", 21_1012_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::NonCurrentLiabilities(NonCurrentLiabilities::DeferredIncomeTax)))] => "B21_1013_C000", 21_1013_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::NonCurrentLiabilities(NonCurrentLiabilities::NonCurrentPortionLongTermDebt(Parties::RelatedParties))))] => "B21_1014_C000", 21_1014_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::NonCurrentLiabilities(NonCurrentLiabilities::NonCurrentPortionLongTermDebt(Parties::NonRelatedParties))))] => "B21_1014_C001", 21_1014_2_001;
+	[Ledger::BalanceSheet(B::Liabilities(L::NonCurrentLiabilities(NonCurrentLiabilities::ProvisionsWarrantyLegalClaimsOther)))] => "B21_1015_C000", 21_1015_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::NonCurrentLiabilities(NonCurrentLiabilities::FinancialInstruments(Derivatives::InterestRateSwaps))))] => "B21_1016_C000", 21_1016_2_000;
+	[Ledger::BalanceSheet(B::Liabilities(L::NonCurrentLiabilities(NonCurrentLiabilities::FinancialInstruments(Derivatives::CurrencyForwards))))] => "B21_1016_C001", 21_1016_2_001;
+	[Ledger::BalanceSheet(B::Liabilities(L::NonCurrentLiabilities(NonCurrentLiabilities::FinancialInstruments(Derivatives::CommodityForwards))))] => "B21_1016_C002", 21_1016_2_002;
+	[Ledger::BalanceSheet(B::Equity(E::ShareholdersEquity(ShareholdersEquity::PersonalNetWorth)))] => "B30_0001_C000", 30_0001_2_000;
+	[Ledger::BalanceSheet(B::Equity(E::ShareholdersEquity(ShareholdersEquity::OwnersEquity)))] => "B30_0002_C000", 30_0002_2_000;
+	[Ledger::BalanceSheet(B::Equity(E::OtherEquity(OtherEquity::CorpTaxByJurisdictCalcAfterPL)))] => "B31_1001_C000", 31_1001_2_000;
+	[Ledger::BalanceSheet(B::Equity(E::CapitalStock(CapitalStock::OrdinaryShares)))] => "B32_2001_C000", 32_2001_2_000;
+	[Ledger::BalanceSheet(B::Equity(E::CapitalStock(CapitalStock::PreferenceShares)))] => "B32_2002_C000", 32_2002_2_000;
+	[Ledger::BalanceSheet(B::Equity(E::CapitalStock(CapitalStock::TreasuryShares)))] => "B32_2003_D000", 32_2003_1_000;
+	[Ledger::BalanceSheet(B::Equity(E::OtherReserves(OtherReserves::ShareApplicationMonies)))] => "B33_3001_C000", 33_3001_2_000;
+	[Ledger::BalanceSheet(B::Equity(E::OtherReserves(OtherReserves::ShareOptionReserve)))] => "B33_3002_C000", 33_3002_2_000;
+	[Ledger::BalanceSheet(B::Equity(E::OtherReserves(OtherReserves::FairValueReserve(_3003_::DebtInstrFVOCIGainLoss))))] => "B33_3003_C000", 33_3003_2_000;
+	[Ledger::BalanceSheet(B::Equity(E::OtherReserves(OtherReserves::FairValueReserve(_3003_::EquityInstrFVOCIGainLoss))))] => "B33_3003_C001", 33_3003_2_001;
+	[Ledger::BalanceSheet(B::Equity(E::OtherReserves(OtherReserves::HedgingReserveCFlowHedges)))] => "B33_3004_C000", 33_3004_2_000;
+	[Ledger::BalanceSheet(B::Equity(E::OtherReserves(OtherReserves::EquityCompOfConvertibleBonds)))] => "B33_3005_C000", 33_3005_2_000;
+	[Ledger::BalanceSheet(B::Equity(E::OtherReserves(OtherReserves::AssetRevaluationReserve(PPE::Land))))] => "B33_3006_C000", 33_3006_2_000;
+	[Ledger::BalanceSheet(B::Equity(E::OtherReserves(OtherReserves::AssetRevaluationReserve(PPE::Buildings))))] => "B33_3006_C001", 33_3006_2_001;
+	[Ledger::BalanceSheet(B::Equity(E::OtherReserves(OtherReserves::AssetRevaluationReserve(PPE::FurnitureFixturesFittings))))] => "B33_3006_C002", 33_3006_2_002;
+	[Ledger::BalanceSheet(B::Equity(E::OtherReserves(OtherReserves::AssetRevaluationReserve(PPE::PlantAndEquipment))))] => "B33_3006_C003", 33_3006_2_003;
+	[Ledger::BalanceSheet(B::Equity(E::OtherReserves(OtherReserves::AssetRevaluationReserve(PPE::MotorVehicles))))] => "B33_3006_C004", 33_3006_2_004;
+	[Ledger::BalanceSheet(B::Equity(E::OtherReserves(OtherReserves::AssetRevaluationReserve(PPE::Supplies))))] => "B33_3006_C005", 33_3006_2_005;
+	[Ledger::BalanceSheet(B::Equity(E::OtherReserves(OtherReserves::AssetRevaluationReserve(PPE::ComputerAndITEquipment))))] => "B33_3006_C006", 33_3006_2_006;
+	[Ledger::BalanceSheet(B::Equity(E::OtherReserves(OtherReserves::AssetRevaluationReserve(PPE::RightOfUseAssets))))] => "B33_3006_C007", 33_3006_2_007;
+	[Ledger::BalanceSheet(B::Equity(E::OtherReserves(OtherReserves::AssetRevaluationReserve(PPE::LeaseholdImprovements))))] => "B33_3006_C008", 33_3006_2_008;
+	[Ledger::BalanceSheet(B::Equity(E::OtherReserves(OtherReserves::AssetRevaluationReserve(PPE::AssetsUnderConstruction))))] => "B33_3006_C009", 33_3006_2_009;
+	[Ledger::BalanceSheet(B::Equity(E::RetainedEarnings(RetainedEarnings::DividendPaid)))] => "B34_4001_D000", 34_4001_1_000;
+	[Ledger::BalanceSheet(B::Equity(E::RetainedEarnings(RetainedEarnings::RetainedEarnings)))] => "B34_4002_C000", 34_4002_2_000;
+	[Ledger::BalanceSheet(B::Equity(E::NetworkReserves))] => "B35_5001_C000", 35_5001_2_000;
+	[Ledger::BalanceSheet(B::Equity(E::BalanceAdjustmentSuspense))] => "B35_5002_C000", 35_5002_2_000;
+	[Ledger::ProfitLoss(P::Income(I::Sales(Sales::SalesOfServices)))] => "P40_0001_C000", 40_0001_2_000;
+	[Ledger::ProfitLoss(P::Income(I::Sales(Sales::SalesOfGoods)))] => "P40_0002_C000", 40_0002_2_000;
+	[Ledger::ProfitLoss(P::Income(I::Sales(Sales::SalesReturnsAndAllowances)))] => "P40_0003_C000", 40_0003_2_000;
+	[Ledger::ProfitLoss(P::Income(I::Sales(Sales::SalesDiscounts)))] => "P40_0004_C000", 40_0004_2_000;
+	[Ledger::ProfitLoss(P::Income(I::Sales(Sales::FreightBillable)))] => "P40_0005_C000", 40_0005_2_000;
+	[Ledger::ProfitLoss(P::Income(I::Sales(Sales::CommissionBillable)))] => "P40_0006_C000", 40_0006_2_000;
+	[Ledger::ProfitLoss(P::Income(I::Sales(Sales::MiscellaneousIncome)))] => "P40_0009_C000", 40_0009_2_000;
+	[Ledger::ProfitLoss(P::Income(I::Sales(Sales::Blockchain(InternalIncome::TransactionReceipt))))] => "P40_0010_C000", 40_0010_2_000;
+	[Ledger::ProfitLoss(P::Income(I::Sales(Sales::Blockchain(InternalIncome::NetworkValidationIncome))))] => "P40_0007_C000", 40_0007_2_000;
+	[Ledger::ProfitLoss(P::Income(I::Sales(Sales::Blockchain(InternalIncome::NetworkFeeIncome))))] => "P40_0008_C000", 40_0008_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherIncome(OtherIncome::RoyaltyIncome)))] => "P41_1001_C000", 41_1001_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherIncome(OtherIncome::IntrestIncome(_1002_::FinAssetsMeasuredAmortisedCost))))] => "P41_1002_C000", 41_1002_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherIncome(OtherIncome::IntrestIncome(_1002_::Investments))))] => "P41_1002_C001", 41_1002_2_001;
+	[Ledger::ProfitLoss(P::Income(I::OtherIncome(OtherIncome::IntrestIncome(_1002_::TradeReceivables))))] => "P41_1002_C002", 41_1002_2_002;
+	[Ledger::ProfitLoss(P::Income(I::OtherIncome(OtherIncome::IntrestIncome(_1002_::BankDeposits))))] => "P41_1002_C003", 41_1002_2_003;
+	[Ledger::ProfitLoss(P::Income(I::OtherIncome(OtherIncome::IntrestIncome(_1002_::LoansToAnassociateSubsidiary))))] => "P41_1002_C004", 41_1002_2_004;
+	[Ledger::ProfitLoss(P::Income(I::OtherIncome(OtherIncome::IntrestIncome(_1002_::DebtInvestmentsMeasuredFVOCI))))] => "P41_1002_C005", 41_1002_2_005;
+	[Ledger::ProfitLoss(P::Income(I::OtherIncome(OtherIncome::DebtInvestmentsMeasuredFVOCI)))] => "P41_1002_C006", 41_1002_2_006;
+	[Ledger::ProfitLoss(P::Income(I::OtherIncome(OtherIncome::DividendIncome)))] => "P41_1003_C000", 41_1003_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherIncome(OtherIncome::RentalIncome)))] => "P41_1004_C000", 41_1004_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherIncome(OtherIncome::GrantIncome)))] => "P41_1005_C000", 41_1005_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherIncome(OtherIncome::FVGainLossFinAssetsAndLiabFVTPL)))] => "P41_1006_C000", 41_1006_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherIncome(OtherIncome::FVGainLossDerivativeFinInstr)))] => "P41_1007_C000", 41_1007_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherIncome(OtherIncome::IneffectivenessFVCFlowHedges)))] => "P41_1008_C000", 41_1008_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherIncome(OtherIncome::FinAssetsFVOCI)))] => "P41_1009_C000", 41_1009_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherIncome(OtherIncome::FVGainLossInvestmentProperties)))] => "P41_1010_C000", 41_1010_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherIncome(OtherIncome::FVGainLossContingentConsideration)))] => "P41_1011_C000", 41_1011_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherOperatingIncome(OOPIN::ShareOfProfitLossOf(II::Subsidiaries))))] => "P42_1001_C000", 42_1001_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherOperatingIncome(OOPIN::ShareOfProfitLossOf(II::JointVentures))))] => "P42_1001_C001", 42_1001_2_001;
+	[Ledger::ProfitLoss(P::Income(I::OtherOperatingIncome(OOPIN::ShareOfProfitLossOf(II::Associates))))] => "P42_1001_C002", 42_1001_2_002;
+	[Ledger::ProfitLoss(P::Income(I::OtherOperatingIncome(OOPIN::ShareOfProfitLossOf(II::Properties))))] => "P42_1001_C003", 42_1001_2_003;
+	[Ledger::ProfitLoss(P::Income(I::OtherOperatingIncome(OOPIN::GainLossDisposal(PPE::Land))))] => "P42_1002_C000", 42_1002_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherOperatingIncome(OOPIN::GainLossDisposal(PPE::Buildings))))] => "P42_1002_C001", 42_1002_2_001;
+	[Ledger::ProfitLoss(P::Income(I::OtherOperatingIncome(OOPIN::GainLossDisposal(PPE::FurnitureFixturesFittings))))] => "P42_1002_C002", 42_1002_2_002;
+	[Ledger::ProfitLoss(P::Income(I::OtherOperatingIncome(OOPIN::GainLossDisposal(PPE::PlantAndEquipment))))] => "P42_1002_C003", 42_1002_2_003;
+	[Ledger::ProfitLoss(P::Income(I::OtherOperatingIncome(OOPIN::GainLossDisposal(PPE::MotorVehicles))))] => "P42_1002_C004", 42_1002_2_004;
+	[Ledger::ProfitLoss(P::Income(I::OtherOperatingIncome(OOPIN::GainLossDisposal(PPE::Supplies))))] => "P42_1002_C005", 42_1002_2_005;
+	[Ledger::ProfitLoss(P::Income(I::OtherOperatingIncome(OOPIN::GainLossDisposal(PPE::ComputerAndITEquipment))))] => "P42_1002_C006", 42_1002_2_006;
+	[Ledger::ProfitLoss(P::Income(I::OtherOperatingIncome(OOPIN::GainLossDisposal(PPE::RightOfUseAssets))))] => "P42_1002_C007", 42_1002_2_007;
+	[Ledger::ProfitLoss(P::Income(I::OtherOperatingIncome(OOPIN::GainLossDisposal(PPE::LeaseholdImprovements))))] => "P42_1002_C008", 42_1002_2_008;
+	[Ledger::ProfitLoss(P::Income(I::OtherOperatingIncome(OOPIN::GainLossDisposal(PPE::AssetsUnderConstruction))))] => "P42_1002_C009", 42_1002_2_009;
+	[Ledger::ProfitLoss(P::Income(I::OtherOperatingIncome(OOPIN::GainLossDiscontinuedOperations)))] => "P42_1003_C000", 42_1003_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherOperatingIncome(OOPIN::BlockchainSlashedFundsIncome)))] => "P42_1004_C000", 42_1004_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::DebtInstr)))] => "P43_2001_C000", 43_2001_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::EquityInstr)))] => "P43_2001_C001", 43_2001_2_001;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::FvGainsLossCFlowHedges)))] => "P43_2002_C000", 43_2002_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::ShareOfOCIAssociates)))] => "P43_2003_C000", 43_2003_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::ReclassificationAdjustments)))] => "P43_2004_C000", 43_2004_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::RevaluationGains(PPE::Land))))] => "P43_2005_C000", 43_2005_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::RevaluationGains(PPE::Buildings))))] => "P43_2005_C001", 43_2005_2_001;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::RevaluationGains(PPE::FurnitureFixturesFittings))))] => "P43_2005_C002", 43_2005_2_002;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::RevaluationGains(PPE::PlantAndEquipment))))] => "P43_2005_C003", 43_2005_2_003;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::RevaluationGains(PPE::MotorVehicles))))] => "P43_2005_C004", 43_2005_2_004;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::RevaluationGains(PPE::Supplies))))] => "P43_2005_C005", 43_2005_2_005;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::RevaluationGains(PPE::ComputerAndITEquipment))))] => "P43_2005_C006", 43_2005_2_006;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::RevaluationGains(PPE::RightOfUseAssets))))] => "P43_2005_C007", 43_2005_2_007;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::RevaluationGains(PPE::LeaseholdImprovements))))] => "P43_2005_C008", 43_2005_2_008;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::RevaluationGains(PPE::AssetsUnderConstruction))))] => "P43_2005_C009", 43_2005_2_009;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::FVGainLossHoldingCrypto(Coins::UTXOType))))] => "P43_2006_C000", 43_2006_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::FVGainLossHoldingCrypto(Coins::NonceType))))] => "P43_2006_C001", 43_2006_2_001;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::FVGainLossHoldingCrypto(Coins::ParachainType))))] => "P43_2006_C002", 43_2006_2_002;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::FVGainLossHoldingCrypto(Coins::ThirdPartyCustody))))] => "P43_2006_C003", 43_2006_2_003;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::FVGainLossHoldingCrypto(Coins::Other))))] => "P43_2006_C004", 43_2006_2_004;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::FVGainLossHoldingTokens(Tokens::SmartContract(Fungibility::Fungible)))))] => "P43_2007_C001", 43_2007_2_001;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::FVGainLossHoldingTokens(Tokens::SmartContract(Fungibility::NonFungible)))))] => "P43_2008_C000", 43_2008_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::FVGainLossHoldingTokens(Tokens::ThirdParty(Fungibility::Fungible)))))] => "P43_2009_C001", 43_2009_2_001;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::FVGainLossHoldingTokens(Tokens::ThirdParty(Fungibility::NonFungible)))))] => "P43_2009_C000", 43_2009_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::FVGainLossHoldingTokens(Tokens::LiquidityPoolPair))))] => "P43_2010_C000", 43_2010_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::FVGainLossHoldingTokens(Tokens::CryptoLoanCollateral))))] => "P43_2011_C000", 43_2011_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::GainLossDisposalCrypto(Coins::UTXOType))))] => "P43_2012_C000", 43_2012_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::GainLossDisposalCrypto(Coins::NonceType))))] => "P43_2012_C001", 43_2012_2_001;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::GainLossDisposalCrypto(Coins::ParachainType))))] => "P43_2012_C002", 43_2012_2_002;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::GainLossDisposalCrypto(Coins::ThirdPartyCustody))))] => "P43_2012_C003", 43_2012_2_003;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::GainLossDisposalCrypto(Coins::Other))))] => "P43_2012_C004", 43_2012_2_004;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::GainLossDisposalTokens(Tokens::SmartContract(Fungibility::Fungible)))))] => "P43_2013_C000", 43_2013_2_000;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::GainLossDisposalTokens(Tokens::SmartContract(Fungibility::NonFungible)))))] => "P43_2013_C001", 43_2013_2_001;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::GainLossDisposalTokens(Tokens::ThirdParty(Fungibility::Fungible)))))] => "P43_2013_C002", 43_2013_2_002;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::GainLossDisposalTokens(Tokens::ThirdParty(Fungibility::NonFungible)))))] => "P43_2013_C003", 43_2013_2_003;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::GainLossDisposalTokens(Tokens::LiquidityPoolPair))))] => "P43_2013_C004", 43_2013_2_004;
+	[Ledger::ProfitLoss(P::Income(I::OtherComprehensiveIncome(OCI::GainLossDisposalTokens(Tokens::CryptoLoanCollateral))))] => "P43_2013_C005", 43_2013_2_005;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::CostOfGoodsSold(COGS::ChangesInInventories(CII::RawMaterials)))))] => "P50_0001_D000", 50_0001_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::CostOfGoodsSold(COGS::ChangesInInventories(CII::WorkInProgress)))))] => "P50_0001_D001", 50_0001_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::CostOfGoodsSold(COGS::ChangesInInventories(CII::FinishedGoods)))))] => "P50_0001_D002", 50_0001_1_002;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::CostOfGoodsSold(COGS::PurchasesDirectMaterialCosts))))] => "P50_0001_D003", 50_0001_1_003;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::CostOfGoodsSold(COGS::DirectLabourCosts))))] => "P50_0001_D004", 50_0001_1_004;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::CostOfGoodsSold(COGS::ManufacturingFactoryOverhead))))] => "P50_0001_D005", 50_0001_1_005;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::CostOfGoodsSold(COGS::PurchasesReturnsAndAllowances))))] => "P50_0001_D006", 50_0001_1_006;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::CostOfGoodsSold(COGS::PurchaseDiscount))))] => "P50_0001_D007", 50_0001_1_007;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::CostOfGoodsSold(COGS::InventoryWriteDown))))] => "P50_0001_D008", 50_0001_1_008;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::CostOfGoodsSold(COGS::CryptoBurnWriteDown))))] => "P50_0001_D009", 50_0001_1_009;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::ChargesInOut(_0002_::InterEntityChargeIn))))] => "P50_0002_D000", 50_0002_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::ChargesInOut(_0002_::InterEntityChargeOut))))] => "P50_0002_D001", 50_0002_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::ChargesInOut(_0002_::ITDistribution))))] => "P50_0002_D002", 50_0002_1_002;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::ChargesInOut(_0002_::DistributionToCapital))))] => "P50_0002_D003", 50_0002_1_003;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::ChargesInOut(_0002_::Allocations))))] => "P50_0002_D004", 50_0002_1_004;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::ChargesInOut(_0002_::AllocationByCountry))))] => "P50_0002_D005", 50_0002_1_005;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::ChargesInOut(_0002_::FacilitiesAllocation))))] => "P50_0002_D006", 50_0002_1_006;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::ChargesInOut(_0002_::AssessResearchDevelopmentRecharges))))] => "P50_0002_D007", 50_0002_1_007;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::DepreciationDepletionAmortization(_0003_::GeneralProjectCosts))))] => "P50_0003_D000", 50_0003_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::DepreciationDepletionAmortization(_0003_::GovernmentGrants))))] => "P50_0003_D013", 50_0003_1_013;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::DepreciationDepletionAmortization(_0003_::NonCapitalProjectExpense))))] => "P50_0003_D015", 50_0003_1_015;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::DepreciationDepletionAmortization(_0003_::Depreciation(PPE::Land)))))] => "P50_0003_D001", 50_0003_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::DepreciationDepletionAmortization(_0003_::Depreciation(PPE::Buildings)))))] => "P50_0003_D002", 50_0003_1_002;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::DepreciationDepletionAmortization(_0003_::Depreciation(PPE::FurnitureFixturesFittings)))))] => "P50_0003_D003", 50_0003_1_003;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::DepreciationDepletionAmortization(_0003_::Depreciation(PPE::PlantAndEquipment)))))] => "P50_0003_D004", 50_0003_1_004;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::DepreciationDepletionAmortization(_0003_::Depreciation(PPE::MotorVehicles)))))] => "P50_0003_D005", 50_0003_1_005;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::DepreciationDepletionAmortization(_0003_::Depreciation(PPE::Supplies)))))] => "P50_0003_D006", 50_0003_1_006;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::DepreciationDepletionAmortization(_0003_::Depreciation(PPE::ComputerAndITEquipment)))))] => "P50_0003_D007", 50_0003_1_007;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::DepreciationDepletionAmortization(_0003_::Depreciation(PPE::RightOfUseAssets)))))] => "P50_0003_D008", 50_0003_1_008;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::DepreciationDepletionAmortization(_0003_::Depreciation(PPE::LeaseholdImprovements)))))] => "P50_0003_D009", 50_0003_1_009;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::DepreciationDepletionAmortization(_0003_::Depreciation(PPE::AssetsUnderConstruction)))))] => "P50_0003_D016", 50_0003_1_016;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::DepreciationDepletionAmortization(_0003_::Amortization(IntangibleAssetList::TrademarkAndPatents)))))] => "P50_0003_D011", 50_0003_1_011;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::DepreciationDepletionAmortization(_0003_::Amortization(IntangibleAssetList::ComputerSoftwareLicences)))))] => "P50_0003_D012", 50_0003_1_012;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::DepreciationDepletionAmortization(_0003_::Amortization(IntangibleAssetList::OtherIntangibleAssets)))))] => "P50_0003_D010", 50_0003_1_010;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::DepreciationDepletionAmortization(_0003_::GainLossMiscellaneousSales))))] => "P50_0003_D014", 50_0003_1_014;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::FieldTrials(_0004_::ConsumptionOther))))] => "P50_0004_D000", 50_0004_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::FieldTrials(_0004_::Trials))))] => "P50_0004_D001", 50_0004_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::FieldTrials(_0004_::Samples))))] => "P50_0004_D002", 50_0004_1_002;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Warranties)))] => "P50_0005_D000", 50_0005_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::CorporationTax)))] => "P50_0006_D000", 50_0006_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::TaxFinesPenalties(TFP::EmployeeTax))))] => "P50_0007_D000", 50_0007_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::TaxFinesPenalties(TFP::PropertyTax))))] => "P50_0007_D001", 50_0007_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::TaxFinesPenalties(TFP::TradeLicencesTax))))] => "P50_0007_D002", 50_0007_1_002;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::TaxFinesPenalties(TFP::EnviromentalTax))))] => "P50_0007_D003", 50_0007_1_003;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::TaxFinesPenalties(TFP::RoadTax))))] => "P50_0007_D004", 50_0007_1_004;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::TaxFinesPenalties(TFP::TaxesOther))))] => "P50_0007_D005", 50_0007_1_005;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::TaxFinesPenalties(TFP::TaxesOtherAlternateAcct))))] => "P50_0007_D006", 50_0007_1_006;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::TaxFinesPenalties(TFP::NonDeductibleFinesPenalties))))] => "P50_0007_D007", 50_0007_1_007;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::TaxFinesPenalties(TFP::Penalties))))] => "P50_0007_D008", 50_0007_1_008;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::TaxFinesPenalties(TFP::LicensesFeesCertificatesOtherTaxes))))] => "P50_0007_D009", 50_0007_1_009;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::TaxFinesPenalties(TFP::CarTax))))] => "P50_0007_D010", 50_0007_1_010;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::TaxFinesPenalties(TFP::CapitalGainsTaxonCryptoAssets))))] => "P50_0007_D011", 50_0007_1_011;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::TaxFinesPenalties(TFP::SlashedCoins))))] => "P50_0007_D012", 50_0007_1_012;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Claims(_0008_::Claims))))] => "P50_0008_D000", 50_0008_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Commissions(_0009_::Manual))))] => "P50_0009_D000", 50_0009_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Commissions(_0009_::IntercompanyExpense))))] => "P50_0009_D001", 50_0009_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Commissions(_0009_::IntercompanyIncome))))] => "P50_0009_D002", 50_0009_1_002;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::SamplesConsumptionMarketing))))] => "P50_0010_D000", 50_0010_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::Promos))))] => "P50_0010_D001", 50_0010_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::AdvertisingExpenses))))] => "P50_0010_D002", 50_0010_1_002;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::AdvertisingPromosPlantVisit))))] => "P50_0010_D003", 50_0010_1_003;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::DesignAndArtwork))))] => "P50_0010_D004", 50_0010_1_004;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::AdPromosGifts))))] => "P50_0010_D005", 50_0010_1_005;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::AdPromosMeetingsSeminars))))] => "P50_0010_D006", 50_0010_1_006;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::AdPromosPrintedMaterial))))] => "P50_0010_D007", 50_0010_1_007;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::AdPromosOutdoor))))] => "P50_0010_D008", 50_0010_1_008;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::AdPromosPresentationMaterial))))] => "P50_0010_D009", 50_0010_1_009;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::DistributorEvents))))] => "P50_0010_D010", 50_0010_1_010;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::MarketResearch))))] => "P50_0010_D011", 50_0010_1_011;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::FairsAndExhibitions))))] => "P50_0010_D012", 50_0010_1_012;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::MediaPrint))))] => "P50_0010_D013", 50_0010_1_013;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::MediaRadio))))] => "P50_0010_D014", 50_0010_1_014;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::MediaTelevision))))] => "P50_0010_D015", 50_0010_1_015;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::MediaOther))))] => "P50_0010_D016", 50_0010_1_016;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::Samples))))] => "P50_0010_D017", 50_0010_1_017;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::CustomGiftsTaxable))))] => "P50_0010_D018", 50_0010_1_018;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::CustomGiftsNonTaxable))))] => "P50_0010_D019", 50_0010_1_019;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::TechnicalServiceFees))))] => "P50_0010_D020", 50_0010_1_020;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::Literature))))] => "P50_0010_D021", 50_0010_1_021;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::BrandMerchandise))))] => "P50_0010_D022", 50_0010_1_022;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::TradeShowsExhibitFees))))] => "P50_0010_D023", 50_0010_1_023;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::AgencyFees))))] => "P50_0010_D024", 50_0010_1_024;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::AudioVisuals))))] => "P50_0010_D025", 50_0010_1_025;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::MarketingEventsDaysInTheField))))] => "P50_0010_D026", 50_0010_1_026;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::MarketingMiscellaneousExpense))))] => "P50_0010_D027", 50_0010_1_027;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MarketingPrograms(_0010_::MarketingCostSharing))))] => "P50_0010_D028", 50_0010_1_028;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::ConsultingFees(_0011_::ConsultingFees))))] => "P50_0011_D000", 50_0011_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Services(_0012_::MiscellaneousService))))] => "P50_0012_D000", 50_0012_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Services(_0012_::AnalyticalTesting))))] => "P50_0012_D001", 50_0012_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Services(_0012_::CommunicationsTarifs))))] => "P50_0012_D002", 50_0012_1_002;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Services(_0012_::ExperimentalTesting))))] => "P50_0012_D003", 50_0012_1_003;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Services(_0012_::FinServices))))] => "P50_0012_D004", 50_0012_1_004;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Services(_0012_::FreightExpenses))))] => "P50_0012_D005", 50_0012_1_005;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Services(_0012_::HousekeepingLaundry))))] => "P50_0012_D006", 50_0012_1_006;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Services(_0012_::LanguageTranslationServices))))] => "P50_0012_D007", 50_0012_1_007;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Services(_0012_::MailDeliveryServices))))] => "P50_0012_D008", 50_0012_1_008;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Services(_0012_::MiscellaneousFees))))] => "P50_0012_D009", 50_0012_1_009;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Services(_0012_::Contractors))))] => "P50_0012_D010", 50_0012_1_010;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Services(_0012_::TechnicalAssistance))))] => "P50_0012_D011", 50_0012_1_011;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Services(_0012_::LaboratoryExpense))))] => "P50_0012_D012", 50_0012_1_012;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Services(_0012_::Labour))))] => "P50_0012_D013", 50_0012_1_013;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Services(_0012_::Printing))))] => "P50_0012_D014", 50_0012_1_014;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Services(_0012_::Warehouse))))] => "P50_0012_D015", 50_0012_1_015;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Services(_0012_::PhotographsGraphics))))] => "P50_0012_D016", 50_0012_1_016;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Services(_0012_::ProjectEngineering))))] => "P50_0012_D017", 50_0012_1_017;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Services(_0012_::ResearchAssistance))))] => "P50_0012_D018", 50_0012_1_018;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Services(_0012_::ResearchShoppingExpense))))] => "P50_0012_D019", 50_0012_1_019;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Services(_0012_::OtherServiceForProduction))))] => "P50_0012_D020", 50_0012_1_020;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Services(_0012_::SoftwareMaintenanceFees))))] => "P50_0012_D021", 50_0012_1_021;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Services(_0012_::TaxPreparationFees))))] => "P50_0012_D022", 50_0012_1_022;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Services(_0012_::TestingServices))))] => "P50_0012_D023", 50_0012_1_023;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::TravelExpenses(_0013_::PerDiem))))] => "P50_0013_D000", 50_0013_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::TravelExpenses(_0013_::TravelGeneral))))] => "P50_0013_D001", 50_0013_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::TravelExpenses(_0013_::AirTickets))))] => "P50_0013_D002", 50_0013_1_002;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::TravelExpenses(_0013_::AirTicketsOutsideCountryOfResidence))))] => "P50_0013_D003", 50_0013_1_003;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::TravelExpenses(_0013_::Miles))))] => "P50_0013_D004", 50_0013_1_004;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::TravelExpenses(_0013_::TaxiOtherPersonalTransport))))] => "P50_0013_D005", 50_0013_1_005;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Hotels(_0014_::LodgingHotelCosts))))] => "P50_0014_D000", 50_0014_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MeetingsConferences(_0015_::MeetingsConferences))))] => "P50_0015_D000", 50_0015_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MeetingsConferences(_0015_::OtherMeetings))))] => "P50_0015_D001", 50_0015_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MeetingsConferences(_0015_::KeyDistributorMeetings))))] => "P50_0015_D002", 50_0015_1_002;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MeetingsConferences(_0015_::TravelForeign))))] => "P50_0015_D003", 50_0015_1_003;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MeetingsConferences(_0015_::TravelNonEmployee))))] => "P50_0015_D004", 50_0015_1_004;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MeetingsConferences(_0015_::TravelTechMeetingsConferences))))] => "P50_0015_D005", 50_0015_1_005;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RestaurantMeals(_0016_::Entertainment))))] => "P50_0016_D000", 50_0016_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RestaurantMeals(_0016_::WithinCountryOfResidence))))] => "P50_0016_D001", 50_0016_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RestaurantMeals(_0016_::OutsideCountryOfResidence))))] => "P50_0016_D002", 50_0016_1_002;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RestaurantMeals(_0016_::EntertainmentNonDeductable))))] => "P50_0016_D003", 50_0016_1_003;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RestaurantMeals(_0016_::EntertainmentFieldSales))))] => "P50_0016_D004", 50_0016_1_004;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RestaurantMeals(_0016_::EntertainmentTrainingEducation))))] => "P50_0016_D005", 50_0016_1_005;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RestaurantMeals(_0016_::EntertainmentBusinessDevelopmentNoMeeting))))] => "P50_0016_D006", 50_0016_1_006;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RestaurantMeals(_0016_::EntertainmentBusinessDevelopment))))] => "P50_0016_D007", 50_0016_1_007;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::OtherTravelExpenses(_0017_::OtherTravelExpenses))))] => "P50_0017_D000", 50_0017_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::CostPooling(_0018_::PublicRelations))))] => "P50_0018_D000", 50_0018_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::CarExpenses(_0019_::Other))))] => "P50_0019_D000", 50_0019_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::CarExpenses(_0019_::Parking))))] => "P50_0019_D001", 50_0019_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::CarExpenses(_0019_::CompanyCar))))] => "P50_0019_D002", 50_0019_1_002;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::CarExpenses(_0019_::Telephone))))] => "P50_0019_D003", 50_0019_1_003;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::CarExpenses(_0019_::Rental))))] => "P50_0019_D004", 50_0019_1_004;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::CarExpenses(_0019_::Repairs))))] => "P50_0019_D005", 50_0019_1_005;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::CarExpenses(_0019_::CarFinancing))))] => "P50_0019_D006", 50_0019_1_006;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::CarExpenses(_0019_::Fuel))))] => "P50_0019_D007", 50_0019_1_007;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::CarExpenses(_0019_::LeasedAutos))))] => "P50_0019_D008", 50_0019_1_008;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Equipment(_0020_::Equipment))))] => "P50_0020_D000", 50_0020_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::PlantMaintenance(_0021_::PlantMaintenance))))] => "P50_0021_D000", 50_0021_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::PhonesTelecom(_0022_::CellularUsage))))] => "P50_0022_D000", 50_0022_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::PhonesTelecom(_0022_::CommunicationRemoteAccess))))] => "P50_0022_D001", 50_0022_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::PhonesTelecom(_0022_::MiscellaneousDataCommunications))))] => "P50_0022_D002", 50_0022_1_002;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::PhonesTelecom(_0022_::TelephoneFacsimiles))))] => "P50_0022_D003", 50_0022_1_003;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RentalLeases(_0023_::Warehouse))))] => "P50_0023_D000", 50_0023_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RentalLeases(_0023_::NonLeaseRental))))] => "P50_0023_D001", 50_0023_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RentalLeases(_0023_::BuildingOffice))))] => "P50_0023_D002", 50_0023_1_002;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RentalLeases(_0023_::Land))))] => "P50_0023_D003", 50_0023_1_003;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RentalLeases(_0023_::Equipment))))] => "P50_0023_D004", 50_0023_1_004;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RentalLeases(_0023_::SubLeaseIncome))))] => "P50_0023_D005", 50_0023_1_005;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RentalLeases(_0023_::OtherFacilities))))] => "P50_0023_D006", 50_0023_1_006;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RepairMaintenance(_0024_::OfficeLabFurniture))))] => "P50_0024_D000", 50_0024_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RepairMaintenance(_0024_::ServiceContracts))))] => "P50_0024_D001", 50_0024_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RepairMaintenance(_0024_::Repairs))))] => "P50_0024_D002", 50_0024_1_002;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RepairMaintenance(_0024_::RepairsEquipment))))] => "P50_0024_D003", 50_0024_1_003;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RepairMaintenance(_0024_::ITMaintenance))))] => "P50_0024_D004", 50_0024_1_004;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RepairMaintenance(_0024_::Maintenance))))] => "P50_0024_D005", 50_0024_1_005;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RepairMaintenance(_0024_::MaintenanceEquip))))] => "P50_0024_D006", 50_0024_1_006;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RepairMaintenance(_0024_::MaintOther))))] => "P50_0024_D007", 50_0024_1_007;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RepairMaintenance(_0024_::MaintenanceOtherMiscellaneousHardware))))] => "P50_0024_D008", 50_0024_1_008;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RepairMaintenance(_0024_::MaintenanceOtherOfficeCleaning))))] => "P50_0024_D009", 50_0024_1_009;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RepairMaintenance(_0024_::MaintenanceBuilding))))] => "P50_0024_D010", 50_0024_1_010;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RepairMaintenance(_0024_::Tools))))] => "P50_0024_D011", 50_0024_1_011;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RepairMaintenance(_0024_::Services))))] => "P50_0024_D012", 50_0024_1_012;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RepairMaintenance(_0024_::PurchasedSupplies))))] => "P50_0024_D013", 50_0024_1_013;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RepairMaintenance(_0024_::PlntMaintEquipmentNonCapital))))] => "P50_0024_D014", 50_0024_1_014;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RepairMaintenance(_0024_::MiscellaneousMaintenance))))] => "P50_0024_D015", 50_0024_1_015;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Supplies(_0025_::Other))))] => "P50_0025_D000", 50_0025_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Supplies(_0025_::Office))))] => "P50_0025_D001", 50_0025_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Supplies(_0025_::PhotcopyOrDocumentHandling))))] => "P50_0025_D002", 50_0025_1_002;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Supplies(_0025_::Lab))))] => "P50_0025_D003", 50_0025_1_003;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Supplies(_0025_::Safety))))] => "P50_0025_D004", 50_0025_1_004;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Supplies(_0025_::Production))))] => "P50_0025_D005", 50_0025_1_005;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Supplies(_0025_::Maintenance))))] => "P50_0025_D006", 50_0025_1_006;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Supplies(_0025_::IndustrialChemicals))))] => "P50_0025_D007", 50_0025_1_007;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Supplies(_0025_::Cleaning))))] => "P50_0025_D008", 50_0025_1_008;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Utilities(_0026_::ElectricalEquipment))))] => "P50_0026_D000", 50_0026_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Utilities(_0026_::ComputerRelatedEquipment))))] => "P50_0026_D001", 50_0026_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Utilities(_0026_::SoftwareLicenses))))] => "P50_0026_D002", 50_0026_1_002;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Utilities(_0026_::Electricity))))] => "P50_0026_D003", 50_0026_1_003;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Utilities(_0026_::Water))))] => "P50_0026_D004", 50_0026_1_004;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Utilities(_0026_::Gas))))] => "P50_0026_D005", 50_0026_1_005;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Utilities(_0026_::Fuel))))] => "P50_0026_D006", 50_0026_1_006;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Utilities(_0026_::UtilitiesCombined))))] => "P50_0026_D007", 50_0026_1_007;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Utilities(_0026_::CloudStorage))))] => "P50_0026_D008", 50_0026_1_008;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Utilities(_0026_::DataCenters))))] => "P50_0026_D009", 50_0026_1_009;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Insurance(_0027_::Other))))] => "P50_0027_D000", 50_0027_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Insurance(_0027_::ExportCredit))))] => "P50_0027_D001", 50_0027_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Insurance(_0027_::SelfInsurance))))] => "P50_0027_D002", 50_0027_1_002;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Insurance(_0027_::Motorvehicle))))] => "P50_0027_D003", 50_0027_1_003;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Insurance(_0027_::Medical))))] => "P50_0027_D004", 50_0027_1_004;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Insurance(_0027_::Life))))] => "P50_0027_D005", 50_0027_1_005;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Insurance(_0027_::Travel))))] => "P50_0027_D006", 50_0027_1_006;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::CorporateGovernance(_0028_::NonExecDirFeesOtherGovernanceCosts))))] => "P50_0028_D000", 50_0028_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::LegalFees(_0029_::ExternalCorporateSecretarialFees))))] => "P50_0029_D000", 50_0029_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::LegalFees(_0029_::Litigation))))] => "P50_0029_D001", 50_0029_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::LegalFees(_0029_::OutsideCounselFees))))] => "P50_0029_D002", 50_0029_1_002;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::LegalFees(_0029_::LicensesPermits))))] => "P50_0029_D003", 50_0029_1_003;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::LegalFees(_0029_::LegalFeesInternational))))] => "P50_0029_D004", 50_0029_1_004;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::LegalFees(_0029_::LegalFeesOther))))] => "P50_0029_D005", 50_0029_1_005;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::LegalFees(_0029_::FCPAGifts))))] => "P50_0029_D006", 50_0029_1_006;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::LegalFees(_0029_::FCPATravel))))] => "P50_0029_D007", 50_0029_1_007;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::LegalFees(_0029_::FCPAMealsEntertainment))))] => "P50_0029_D008", 50_0029_1_008;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::LegalFees(_0029_::BusinessContributions))))] => "P50_0029_D009", 50_0029_1_009;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::LegalFees(_0029_::CharitableContributions))))] => "P50_0029_D010", 50_0029_1_010;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::LegalFees(_0029_::DonationsDisallowed))))] => "P50_0029_D011", 50_0029_1_011;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::LegalFees(_0029_::LocalTax))))] => "P50_0029_D012", 50_0029_1_012;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::LegalFees(_0029_::AuditFeesExternal))))] => "P50_0029_D013", 50_0029_1_013;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::LegalFees(_0029_::AuditorFirmTaxFees))))] => "P50_0029_D014", 50_0029_1_014;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::LegalFees(_0029_::AuditorFirmOtherFees))))] => "P50_0029_D015", 50_0029_1_015;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::LegalFees(_0029_::AuditFees))))] => "P50_0029_D016", 50_0029_1_016;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::LegalFees(_0029_::Patents))))] => "P50_0029_D017", 50_0029_1_017;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::LegalFees(_0029_::ProductRegistrations))))] => "P50_0029_D018", 50_0029_1_018;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::LegalFees(_0029_::TrademarkAndPatents))))] => "P50_0029_D019", 50_0029_1_019;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::LegalFees(_0029_::DesignRights))))] => "P50_0029_D020", 50_0029_1_020;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::Blockchain(InternalAccounting::NetworkTransaction)))))] => "P50_0030_D028", 50_0030_1_028;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::Blockchain(InternalAccounting::Crowdloan)))))] => "P50_0030_D029", 50_0030_1_029;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::Blockchain(InternalAccounting::NetworkTransactionFees)))))] => "P50_0030_D000", 50_0030_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::Blockchain(InternalAccounting::NetworkValidationReward)))))] => "P50_0009_D003", 50_0009_1_003;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::BankCharges))))] => "P50_0030_D001", 50_0030_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::AdministrationBusinessTax))))] => "P50_0030_D002", 50_0030_1_002;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::CorporateMembership))))] => "P50_0030_D003", 50_0030_1_003;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::MembershipsSubscriptions))))] => "P50_0030_D004", 50_0030_1_004;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::OtherNonDeductible))))] => "P50_0030_D005", 50_0030_1_005;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::Postage))))] => "P50_0030_D006", 50_0030_1_006;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::Courrier))))] => "P50_0030_D007", 50_0030_1_007;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::ManagementAdjustment))))] => "P50_0030_D008", 50_0030_1_008;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::CollectionExpense))))] => "P50_0030_D009", 50_0030_1_009;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::CreditReports))))] => "P50_0030_D010", 50_0030_1_010;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::OtherMiscellaneousExpense))))] => "P50_0030_D011", 50_0030_1_011;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::MiscellaneousMonthEndAccruals))))] => "P50_0030_D012", 50_0030_1_012;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::Sponsorship))))] => "P50_0030_D013", 50_0030_1_013;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::BooksLibrary))))] => "P50_0030_D014", 50_0030_1_014;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::NonCapitalised(NonCapitalised::Equipment)))))] => "P50_0030_D015", 50_0030_1_015;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::NonCapitalised(NonCapitalised::Software)))))] => "P50_0030_D016", 50_0030_1_016;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::MiscellaneousReimbursement))))] => "P50_0030_D017", 50_0030_1_017;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::RecoveredExpenses(RecoveredExpenses::CanteenReceipts)))))] => "P50_0030_D018", 50_0030_1_018;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::RecoveredExpenses(RecoveredExpenses::MiscellaneousChargeOut)))))] => "P50_0030_D019", 50_0030_1_019;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::RecoveredExpenses(RecoveredExpenses::Insurance)))))] => "P50_0030_D020", 50_0030_1_020;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::RecoveredExpenses(RecoveredExpenses::CarContract)))))] => "P50_0030_D021", 50_0030_1_021;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::RecoveredExpenses(RecoveredExpenses::Other)))))] => "P50_0030_D022", 50_0030_1_022;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::RecoveredExpenses(RecoveredExpenses::Salaries)))))] => "P50_0030_D023", 50_0030_1_023;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::RecoveredExpenses(RecoveredExpenses::Wages)))))] => "P50_0030_D024", 50_0030_1_024;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::ProcurementCardPurchases))))] => "P50_0030_D025", 50_0030_1_025;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::AdministrationServiceFees))))] => "P50_0030_D026", 50_0030_1_026;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Admin(AdminCosts::OtherMiscellaneousIncomeExpense))))] => "P50_0030_D027", 50_0030_1_027;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::BadDebts(_0031_::BadDebtExpenseReversal))))] => "P50_0031_D000", 50_0031_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MiscellaneousExpenses(_0032_::ShippingCost))))] => "P50_0032_D000", 50_0032_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MiscellaneousExpenses(_0032_::OtherCosts))))] => "P50_0032_D001", 50_0032_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MiscellaneousExpenses(_0032_::StandardCostIntercompany))))] => "P50_0032_D002", 50_0032_1_002;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MiscellaneousExpenses(_0032_::FinishedGoodsConsumption))))] => "P50_0032_D003", 50_0032_1_003;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MiscellaneousExpenses(_0032_::SamplesConsumption))))] => "P50_0032_D004", 50_0032_1_004;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MiscellaneousExpenses(_0032_::SemiFinishedConsumption))))] => "P50_0032_D005", 50_0032_1_005;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MiscellaneousExpenses(_0032_::FreightNonCustomer))))] => "P50_0032_D006", 50_0032_1_006;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MiscellaneousExpenses(_0032_::FreightFromToWarehouse))))] => "P50_0032_D007", 50_0032_1_007;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MiscellaneousExpenses(_0032_::DutiesPurchases))))] => "P50_0032_D008", 50_0032_1_008;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MiscellaneousExpenses(_0032_::HandlingWarehouse))))] => "P50_0032_D009", 50_0032_1_009;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MiscellaneousExpenses(_0032_::Transportation))))] => "P50_0032_D010", 50_0032_1_010;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MiscellaneousExpenses(_0032_::WasteDisposal))))] => "P50_0032_D011", 50_0032_1_011;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MiscellaneousExpenses(_0032_::CustomsFees))))] => "P50_0032_D013", 50_0032_1_013;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::MiscellaneousExpenses(_0032_::SponsorshipDonations))))] => "P50_0032_D014", 50_0032_1_014;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::FinGuaranteeFees(_0033_::FinancialGuaranteeFees))))] => "P50_0033_D000", 50_0033_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::RoyaltyExpenses(_0034_::RoyaltyExpenses))))] => "P50_0034_D000", 50_0034_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::ExtraordinaryExpenses(_0035_::ExtraordinaryExpenses))))] => "P50_0035_D000", 50_0035_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::ImpairmentLoss(_0036_::FinancialAssets))))] => "P50_0036_D000", 50_0036_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::ImpairmentLoss(_0036_::ContractAssets))))] => "P50_0037_D000", 50_0037_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::ImpairmentLoss(_0036_::Other))))] => "P50_0036_D001", 50_0036_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Provisions(_0038_::WarrantyLegalClaimsOther))))] => "P50_0038_D000", 50_0038_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::Provisions(_0038_::UnusedLeave))))] => "P50_0038_D001", 50_0038_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::WriteOff(PPE::Land))))] => "P50_0039_D000", 50_0039_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::WriteOff(PPE::Buildings))))] => "P50_0039_D001", 50_0039_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::WriteOff(PPE::FurnitureFixturesFittings))))] => "P50_0039_D002", 50_0039_1_002;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::WriteOff(PPE::PlantAndEquipment))))] => "P50_0039_D003", 50_0039_1_003;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::WriteOff(PPE::MotorVehicles))))] => "P50_0039_D004", 50_0039_1_004;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::WriteOff(PPE::Supplies))))] => "P50_0039_D005", 50_0039_1_005;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::WriteOff(PPE::ComputerAndITEquipment))))] => "P50_0039_D006", 50_0039_1_006;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::WriteOff(PPE::RightOfUseAssets))))] => "P50_0039_D007", 50_0039_1_007;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::WriteOff(PPE::LeaseholdImprovements))))] => "P50_0039_D008", 50_0039_1_008;
+	[Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(OPEX::WriteOff(PPE::AssetsUnderConstruction))))] => "P50_0039_D009", 50_0039_1_009;
+	[Ledger::ProfitLoss(P::Expenses(X::OtherOperatingExpenses(OOPEX::MiscellaneousCharges(OMC::ITMiscellaneous))))] => "P51_1001_D000", 51_1001_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::OtherOperatingExpenses(OOPEX::MiscellaneousCharges(OMC::SecondaryCosts))))] => "P51_1001_D001", 51_1001_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::OtherOperatingExpenses(OOPEX::MiscellaneousCharges(OMC::ReportingAdjustment))))] => "P51_1001_D002", 51_1001_1_002;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::Salaries))))] => "P52_2001_D000", 52_2001_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::Extra13thMonth))))] => "P52_2001_D001", 52_2001_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::AchievementAwards))))] => "P52_2001_D002", 52_2001_1_002;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::RepresentationAllowance))))] => "P52_2001_D003", 52_2001_1_003;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::Fees))))] => "P52_2001_D004", 52_2001_1_004;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::SalariedOperations))))] => "P52_2001_D005", 52_2001_1_005;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::HourlyWages13thMonth))))] => "P52_2001_D006", 52_2001_1_006;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::PayrollFees))))] => "P52_2001_D007", 52_2001_1_007;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::PayrollLuncheonVouchers))))] => "P52_2001_D008", 52_2001_1_008;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::SocialSecurity(SocialSecurity::EmployerContribution)))))] => "P52_2001_D009", 52_2001_1_009;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::SocialSecurity(SocialSecurity::Provision)))))] => "P52_2001_D012", 52_2001_1_012;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::SocialSecurity(SocialSecurity::Payroll)))))] => "P52_2001_D013", 52_2001_1_013;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::SocialSecurity(SocialSecurity::Accruals13thMonth)))))] => "P52_2001_D014", 52_2001_1_014;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::SocialSecurity(SocialSecurity::AccrualsHolidayPay)))))] => "P52_2001_D015", 52_2001_1_015;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::SocialSecurity(SocialSecurity::Other)))))] => "P52_2001_D011", 52_2001_1_011;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::FringesVariable))))] => "P52_2001_D010", 52_2001_1_010;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::HousingTax))))] => "P52_2001_D016", 52_2001_1_016;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::ApprenticeshipTax))))] => "P52_2001_D017", 52_2001_1_017;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::WorkCouncil))))] => "P52_2001_D018", 52_2001_1_018;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::SeverencePay))))] => "P52_2001_D019", 52_2001_1_019;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::TransportationSubsidy))))] => "P52_2001_D020", 52_2001_1_020;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::HoulyWages))))] => "P52_2001_D021", 52_2001_1_021;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::HourlyWagesPerDiem))))] => "P52_2001_D022", 52_2001_1_022;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::OvertimePayments))))] => "P52_2001_D023", 52_2001_1_023;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::SickPay))))] => "P52_2001_D024", 52_2001_1_024;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::HolidayPay))))] => "P52_2001_D025", 52_2001_1_025;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::VacationPay))))] => "P52_2001_D026", 52_2001_1_026;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::OtherEmployeeCompensation))))] => "P52_2001_D027", 52_2001_1_027;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::SavingsPlan))))] => "P52_2001_D028", 52_2001_1_028;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::FieldIncentivePlan))))] => "P52_2001_D029", 52_2001_1_029;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::Pension))))] => "P52_2001_D030", 52_2001_1_030;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::InsurancePlanLiveMedical))))] => "P52_2001_D031", 52_2001_1_031;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::InsuranceWorkAccident))))] => "P52_2001_D032", 52_2001_1_032;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::InsurancePrivateCollective))))] => "P52_2001_D033", 52_2001_1_033;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::PensionCostsCompanyPortion))))] => "P52_2001_D034", 52_2001_1_034;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::PrivatePatientPlan))))] => "P52_2001_D035", 52_2001_1_035;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::EarlyRetirementPlans))))] => "P52_2001_D036", 52_2001_1_036;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::Salaries(_2001_::EmployeeShareOptionExpense))))] => "P52_2001_D037", 52_2001_1_037;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::ExpatExpenses(_2002_::SchoolExpenses))))] => "P52_2002_D000", 52_2002_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::ExpatExpenses(_2002_::HomeLeaveExpatexpenses))))] => "P52_2002_D001", 52_2002_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::ExpatExpenses(_2002_::ExpatCost))))] => "P52_2002_D002", 52_2002_1_002;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::ExpatExpenses(_2002_::AssigneeAllowancesOther))))] => "P52_2002_D003", 52_2002_1_003;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::ExpatExpenses(_2002_::EmployeLanguageLessons))))] => "P52_2002_D004", 52_2002_1_004;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::ExpatExpenses(_2002_::EmployeeMovingExpense))))] => "P52_2002_D005", 52_2002_1_005;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::ExpatExpenses(_2002_::EmployeeAssistanceProgram))))] => "P52_2002_D006", 52_2002_1_006;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::ExpatExpenses(_2002_::EmployeeHousingExpense))))] => "P52_2002_D007", 52_2002_1_007;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::ExpatExpenses(_2002_::ExpatOtherBenefits))))] => "P52_2002_D008", 52_2002_1_008;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::ExpatExpenses(_2002_::AssigneeTax))))] => "P52_2002_D009", 52_2002_1_009;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::IncentivePlan(_2003_::IncentivePlan))))] => "P52_2003_D000", 52_2003_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::IncentivePlanOverhead(_2004_::IncentivePlanOverhead))))] => "P52_2004_D000", 52_2004_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::EmployeeServices(_2005_::EmployeeServiceAwards))))] => "P52_2005_D000", 52_2005_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::EmployeeServices(_2005_::SecurityServices))))] => "P52_2005_D001", 52_2005_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::EmployeeServices(_2005_::HealthSafety))))] => "P52_2005_D002", 52_2005_1_002;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::EmployeeServices(_2005_::Cleaning))))] => "P52_2005_D003", 52_2005_1_003;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::EmployeeServices(_2005_::CafeteriaService))))] => "P52_2005_D004", 52_2005_1_004;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::EmployeeServices(_2005_::MedicalBenefits))))] => "P52_2005_D005", 52_2005_1_005;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::EmployeeServices(_2005_::MedicalServicesAndSupplies))))] => "P52_2005_D006", 52_2005_1_006;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::EmployeeServices(_2005_::RecreationSportsSocial))))] => "P52_2005_D007", 52_2005_1_007;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::EmployeeServices(_2005_::Recruiting))))] => "P52_2005_D008", 52_2005_1_008;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::EmployeeServices(_2005_::EmployeeAgencyFees))))] => "P52_2005_D009", 52_2005_1_009;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::EmployeeServices(_2005_::TransportOfPersonnel))))] => "P52_2005_D010", 52_2005_1_010;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::EmployeeServices(_2005_::EducationTraining))))] => "P52_2005_D011", 52_2005_1_011;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::EmployeeServices(_2005_::EducationTrainingAssociatedTravelCosts))))] => "P52_2005_D012", 52_2005_1_012;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::EmployeeServices(_2005_::TrainingTax))))] => "P52_2005_D013", 52_2005_1_013;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::EmployeeServices(_2005_::Tuition))))] => "P52_2005_D014", 52_2005_1_014;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::EmployeeServices(_2005_::TuitionTaxable))))] => "P52_2005_D015", 52_2005_1_015;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::EmployeeServices(_2005_::LaundryClothing))))] => "P52_2005_D016", 52_2005_1_016;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::EmployeeServices(_2005_::MealAllowance))))] => "P52_2005_D017", 52_2005_1_017;
+	[Ledger::ProfitLoss(P::Expenses(X::PersonnelCosts(PersonnelCosts::EmployeeServices(_2005_::Celebrations))))] => "P52_2005_D018", 52_2005_1_018;
+	[Ledger::ProfitLoss(P::Expenses(X::FinanceCosts(FinanceCosts::InterestExpense(_3001_::BankBorrowings))))] => "P53_3001_D000", 53_3001_1_000;
+	[Ledger::ProfitLoss(P::Expenses(X::FinanceCosts(FinanceCosts::InterestExpense(_3001_::ConvertibleBonds))))] => "P53_3001_D001", 53_3001_1_001;
+	[Ledger::ProfitLoss(P::Expenses(X::FinanceCosts(FinanceCosts::InterestExpense(_3001_::DividendsOnRedeemablePreferenceShares))))] => "P53_3001_D002", 53_3001_1_002;
+	[Ledger::ProfitLoss(P::Expenses(X::FinanceCosts(FinanceCosts::InterestExpense(_3001_::LeaseLiabilities))))] => "P53_3001_D003", 53_3001_1_003;
+	[Ledger::ProfitLoss(P::Expenses(X::FinanceCosts(FinanceCosts::InterestExpense(_3001_::UnwindingOfDiscountOnProvisions))))] => "P53_3001_D004", 53_3001_1_004;
+	[Ledger::ControlAccounts(ControlAccounts::PurchaseControl)] => "C60_0001_000D", 60_0001_1_000;
+	[Ledger::ControlAccounts(ControlAccounts::SalesControl)] => "C60_0002_000D", 60_0002_1_000;
+	[Ledger::ControlAccounts(ControlAccounts::TaxControl)] => "C60_0003_000D", 60_0003_1_000;
+	[Ledger::ControlAccounts(ControlAccounts::EscrowedFundsControl)] => "C60_0004_000D", 60_0004_1_000;
+	[Ledger::ControlAccounts(ControlAccounts::BorrowingsControl)] => "C60_0005_000D", 60_0005_1_000;
+	[Ledger::ControlAccounts(ControlAccounts::DefiBorrowingsControl)] => "C60_0006_000D", 60_0006_1_000;
+	[Ledger::ControlAccounts(ControlAccounts::LiquidityPoolControl)] => "C60_0007_000D", 60_0007_1_000;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::accounting::Indicator;
+
+	#[test]
+	fn account_codes_map_back_to_their_ledger() {
+		for ledger in LEDGERS {
+			assert_eq!(Ledger::from_account_code(ledger.account_code()), Some(*ledger));
+			assert_eq!(Ledger::from_account_number(ledger.account_number()), Some(*ledger));
+		}
+	}
+
+	#[test]
+	fn account_numbers_spell_the_account_code() {
+		for ledger in LEDGERS {
+			let code = ledger.account_code();
+			let (side, index) = match code.ends_with(|c| c == 'D' || c == 'C') {
+				true => (&code[12..], &code[9..12]),
+				false => (&code[9..10], &code[10..]),
+			};
+			let side = match side {
+				"D" => "1",
+				_ => "2",
+			};
+			let digits = [&code[1..3], &code[4..8], side, index].concat();
+
+			assert_eq!(ledger.account_number().to_string(), digits, "{}", code);
+		}
+	}
+
+	#[test]
+	fn account_codes_carry_the_normal_balance() {
+		for ledger in LEDGERS {
+			let code = ledger.account_code();
+			let side = match code.contains("_D") || code.ends_with('D') {
+				true => Indicator::Debit,
+				false => Indicator::Credit,
+			};
+
+			assert_eq!(ledger.normal_balance(), side, "{}", code);
+		}
+	}
+
+	#[test]
+	fn documented_codes_are_kept() {
+		assert_eq!(
+			Ledger::from_account_code("B12_1002_C000"),
+			Some(Ledger::BalanceSheet(B::Assets(A::FixedAssets(
+				FixedAssets::AccumulatedDepreciation(PPE::Land)
+			))))
+		);
+		assert_eq!(
+			Ledger::from_account_code("P50_0003_D001"),
+			Some(Ledger::ProfitLoss(P::Expenses(X::OperatingExpenses(
+				OPEX::DepreciationDepletionAmortization(_0003_::Depreciation(PPE::Land))
+			))))
+		);
+		assert_eq!(
+			Ledger::from_account_number(60_0002_1_000),
+			Some(Ledger::ControlAccounts(ControlAccounts::SalesControl))
+		);
+	}
+
+	#[test]
+	fn unknown_account_codes_are_rejected() {
+		assert_eq!(Ledger::from_account_code("B10_0001_D999"), None);
+		assert_eq!(Ledger::from_account_code("b10_0001_d000"), None);
+		assert_eq!(Ledger::from_account_number(0), None);
+	}
+}
@@ -35,12 +35,17 @@
 // You should have received a copy of the GNU General Public License
 // along with Totem.  If not, see <http://www.gnu.org/licenses/>.
 
+use super::Indicator;
+use crate::LedgerBalance;
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::dispatch::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 // use scale_info::TypeInfo;
 /// Blockchain Specific Functionality - Expenses
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum InternalAccounting {
 	/// Used for generalised network transactions
@@ -55,6 +60,7 @@ pub enum InternalAccounting {
 /// Blockchain Specific Functionality - Receipts
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum InternalIncome {
 	/// Used for generalised network transactions
@@ -67,6 +73,7 @@ pub enum InternalIncome {
 /// ChangesInInventories
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum CII {
 	/// P50_0001_D000,
@@ -79,6 +86,7 @@ pub enum CII {
 /// Cost Of Goods Sold
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum COGS {
 	ChangesInInventories(CII),
@@ -93,13 +101,14 @@ pub enum COGS {
 	/// P50_0001_D007,
 	PurchaseDiscount,
 	/// P50_0001_D008,
-	/// P50_0001_D009,
 	InventoryWriteDown,
+	/// P50_0001_D009,
 	CryptoBurnWriteDown,
 }
 /// Charges In Out
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0002_ {
 	/// P50_0002_D000,
@@ -122,6 +131,7 @@ pub enum _0002_ {
 /// PropertyPlantEquipment
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum PPE {
 	/// P50_0003_D001,
@@ -167,6 +177,7 @@ pub enum PPE {
 /// Primary Intangibles
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum IntangibleAssetList {
 	/// P50_0003_D011,
@@ -185,6 +196,7 @@ pub enum IntangibleAssetList {
 /// Depreciation Depletion Amortization
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0003_ {
 	/// P50_0003_D000,
@@ -201,6 +213,7 @@ pub enum _0003_ {
 /// Field Trials
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0004_ {
 	/// P50_0004_D000,
@@ -214,6 +227,7 @@ pub enum _0004_ {
 /// Tax Fines And Penalties
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum TFP {
 	/// P50_0007_D000,
@@ -246,6 +260,7 @@ pub enum TFP {
 /// Claims
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0008_ {
 	/// P50_0008_D000,
@@ -254,6 +269,7 @@ pub enum _0008_ {
 /// Commissions
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0009_ {
 	/// P50_0009_D000,
@@ -266,6 +282,7 @@ pub enum _0009_ {
 /// Marketing Programs
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0010_ {
 	/// P50_0010_D000,
@@ -330,6 +347,7 @@ pub enum _0010_ {
 /// Consulting Fees
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0011_ {
 	/// P50_0011_D000,
@@ -338,6 +356,7 @@ pub enum _0011_ {
 /// Services
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0012_ {
 	/// P50_0012_D000,
@@ -392,6 +411,7 @@ pub enum _0012_ {
 /// Travel Expenses
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0013_ {
 	/// P50_0013_D000,
@@ -410,6 +430,7 @@ pub enum _0013_ {
 /// Hotels
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0014_ {
 	/// P50_0014_D000,
@@ -418,6 +439,7 @@ pub enum _0014_ {
 /// Meetings Conferences
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0015_ {
 	/// P50_0015_D000,
@@ -436,6 +458,7 @@ pub enum _0015_ {
 /// Restaurant Meals
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0016_ {
 	/// P50_0016_D000,
@@ -458,6 +481,7 @@ pub enum _0016_ {
 /// Other Travel Expenses
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0017_ {
 	/// P50_0017_D000,
@@ -466,6 +490,7 @@ pub enum _0017_ {
 /// Cost Pooling
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0018_ {
 	/// P50_0018_D000,
@@ -474,6 +499,7 @@ pub enum _0018_ {
 /// Car Expenses
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0019_ {
 	/// P50_0019_D000,
@@ -498,6 +524,7 @@ pub enum _0019_ {
 /// Equipment
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0020_ {
 	/// P50_0020_D000,
@@ -506,6 +533,7 @@ pub enum _0020_ {
 /// Plant Maintenance
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0021_ {
 	/// P50_0021_D000,
@@ -514,6 +542,7 @@ pub enum _0021_ {
 /// Phones Telecom
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0022_ {
 	/// P50_0022_D000,
@@ -528,6 +557,7 @@ pub enum _0022_ {
 /// Rental Leases
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0023_ {
 	/// P50_0023_D000,
@@ -548,6 +578,7 @@ pub enum _0023_ {
 /// Repair Maintenance
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0024_ {
 	/// P50_0024_D000,
@@ -586,6 +617,7 @@ pub enum _0024_ {
 /// Supplies
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0025_ {
 	/// P50_0025_D000,
@@ -610,6 +642,7 @@ pub enum _0025_ {
 /// Utilities
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0026_ {
 	/// P50_0026_D000,
@@ -636,6 +669,7 @@ pub enum _0026_ {
 /// Insurance
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0027_ {
 	/// P50_0027_D000,
@@ -656,6 +690,7 @@ pub enum _0027_ {
 /// Corporate Governance
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0028_ {
 	/// P50_0028_D000,
@@ -664,6 +699,7 @@ pub enum _0028_ {
 /// Legal Fees
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0029_ {
 	/// P50_0029_D000,
@@ -712,6 +748,7 @@ pub enum _0029_ {
 /// Non Capitalised Expenses
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum NonCapitalised {
 	/// P50_0030_D015,
@@ -722,6 +759,7 @@ pub enum NonCapitalised {
 /// Recovered Expenses
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum RecoveredExpenses {
 	/// P50_0030_D018,
@@ -742,6 +780,7 @@ pub enum RecoveredExpenses {
 /// Administration Cost
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum AdminCosts {
 	/// Blockchain specific expense classification
@@ -788,6 +827,7 @@ pub enum AdminCosts {
 }
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0031_ {
 	/// P50_0031_D000,
@@ -796,6 +836,7 @@ pub enum _0031_ {
 /// Miscellaneous Expenses
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0032_ {
 	/// P50_0032_D000,
@@ -830,6 +871,7 @@ pub enum _0032_ {
 /// Financial Guarantee Fees
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0033_ {
 	/// P50_0033_D000,
@@ -838,6 +880,7 @@ pub enum _0033_ {
 /// Royalty Expenses
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0034_ {
 	/// P50_0034_D000,
@@ -846,6 +889,7 @@ pub enum _0034_ {
 /// Extraordinary Expenses
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0035_ {
 	/// P50_0035_D000,
@@ -854,6 +898,7 @@ pub enum _0035_ {
 /// Impairmentlossonfinassets
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0036_ {
 	/// P50_0036_D000,
@@ -865,6 +910,7 @@ pub enum _0036_ {
 /// Provisions
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _0038_ {
 	/// P50_0038_D000,
@@ -875,6 +921,7 @@ pub enum _0038_ {
 /// Other Miscellaneous Charges
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum OMC {
 	/// P51_1001_D000,
@@ -887,6 +934,7 @@ pub enum OMC {
 /// Social Security
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum SocialSecurity {
 	/// P52_2001_D009,
@@ -905,6 +953,7 @@ pub enum SocialSecurity {
 /// Salaries
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _2001_ {
 	/// P52_2001_D000,
@@ -976,6 +1025,7 @@ pub enum _2001_ {
 /// Expat Expenses
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _2002_ {
 	/// P52_2002_D000,
@@ -1002,6 +1052,7 @@ pub enum _2002_ {
 /// Incentive Plan
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _2003_ {
 	/// P52_2003_D000,
@@ -1010,6 +1061,7 @@ pub enum _2003_ {
 /// Incentive Plan Overhead
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _2004_ {
 	/// P52_2004_D000,
@@ -1018,6 +1070,7 @@ pub enum _2004_ {
 /// Employee Services
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _2005_ {
 	/// P52_2005_D000,
@@ -1062,6 +1115,7 @@ pub enum _2005_ {
 /// Interest Expense
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _3001_ {
 	/// P53_3001_D000,
@@ -1078,6 +1132,7 @@ pub enum _3001_ {
 /// Accrued Reveues Receivalbles
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum AccruedRevenuesReceivables {
 	/// B10_0010_D000,
@@ -1095,6 +1150,7 @@ pub enum AccruedRevenuesReceivables {
 /// Inventory
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum Inventory {
 	/// B10_0013_D000,
@@ -1106,6 +1162,7 @@ pub enum Inventory {
 }
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum CurrentAssets {
 	/// B10_0001_D000,
@@ -1153,6 +1210,7 @@ pub enum CurrentAssets {
 /// CryptoCurrency
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum Coins {
 	/// B11_4001_D000,
@@ -1174,6 +1232,7 @@ pub enum Coins {
 /// Fungibility
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum Fungibility {
 	Fungible,
@@ -1182,18 +1241,19 @@ pub enum Fungibility {
 /// Tokens
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum Tokens {
 	/// B11_4002_D000,
 	/// B11_4003_D000,
 	/// B11_4007_C000,
-	SmartContract(Fungibility),
 	/// B11_4008_C000,
+	SmartContract(Fungibility),
 	/// B11_4002_D001,
 	/// B11_4003_D001,
 	/// B11_4007_C001,
-	ThirdParty(Fungibility),
 	/// B11_4008_C001,
+	ThirdParty(Fungibility),
 	/// B11_4004_D000,
 	/// B11_4009_C000,
 	LiquidityPoolPair,
@@ -1203,6 +1263,7 @@ pub enum Tokens {
 }
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum CurrentAssetsCrypto {
 	Coins(Coins),
@@ -1212,6 +1273,7 @@ pub enum CurrentAssetsCrypto {
 }
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum FixedAssets {
 	PropPlantEquip(PPE),
@@ -1219,6 +1281,7 @@ pub enum FixedAssets {
 }
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum IntagibleAssets {
 	Intangibles(IntangibleAssetList),
@@ -1232,6 +1295,7 @@ pub enum IntagibleAssets {
 }
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum OtherReceivables {
 	LoansTo(Parties),
@@ -1247,6 +1311,7 @@ pub enum OtherReceivables {
 /// Investments In third parties
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum II {
 	/// B14_3001_D000,
@@ -1263,6 +1328,7 @@ pub enum II {
 }
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum NonCurrentAssets {
 	InvestmentIn(II),
@@ -1286,6 +1352,7 @@ pub enum NonCurrentAssets {
 }
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum Provisions {
 	/// B20_0018_C000,
@@ -1295,6 +1362,7 @@ pub enum Provisions {
 }
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum CurrentLiabilities {
 	/// B20_0001_C000,
@@ -1340,6 +1408,7 @@ pub enum CurrentLiabilities {
 /// Related Or Non Related Parties
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum Parties {
 	/// B14_3009_D001,
@@ -1359,6 +1428,7 @@ pub enum Parties {
 /// Used In Current Liabilities And Non Current Liabilities
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum Derivatives {
 	/// B14_3010_D000,
@@ -1376,6 +1446,7 @@ pub enum Derivatives {
 }
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum NonCurrentLiabilities {
 	/// B21_1001_C000,
@@ -1411,6 +1482,7 @@ pub enum NonCurrentLiabilities {
 }
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum ShareholdersEquity {
 	/// B30_0001_C000,
@@ -1420,6 +1492,7 @@ pub enum ShareholdersEquity {
 }
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum OtherEquity {
 	/// B31_1001_C000,
@@ -1427,6 +1500,7 @@ pub enum OtherEquity {
 }
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum CapitalStock {
 	/// B32_2001_C000,
@@ -1439,6 +1513,7 @@ pub enum CapitalStock {
 /// Fair Value Gains or Losses on Financial Assets at FVOCI
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _3003_ {
 	/// B33_3003_C000,
@@ -1448,6 +1523,7 @@ pub enum _3003_ {
 }
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum OtherReserves {
 	/// B33_3001_C000,
@@ -1465,6 +1541,7 @@ pub enum OtherReserves {
 }
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum RetainedEarnings {
 	/// B34_4001_D000,
@@ -1474,6 +1551,7 @@ pub enum RetainedEarnings {
 }
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum Sales {
 	/// P40_0001_C000,
@@ -1496,6 +1574,7 @@ pub enum Sales {
 /// Other Income Interest Income
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum _1002_ {
 	/// P41_1002_C000,
@@ -1513,6 +1592,7 @@ pub enum _1002_ {
 }
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum OtherIncome {
 	/// P41_1001_C000,
@@ -1542,6 +1622,7 @@ pub enum OtherIncome {
 }
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum OOPIN {
 	/// P42_1001_C000,
@@ -1554,6 +1635,7 @@ pub enum OOPIN {
 }
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum OCI {
 	/// P43_2001_C000,
@@ -1587,6 +1669,7 @@ pub enum OCI {
 }
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum OPEX {
 	/// _0001_(Cogs),
@@ -1668,6 +1751,7 @@ pub enum OPEX {
 }
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum OOPEX {
 	/// _1001_(_1001_),
@@ -1675,6 +1759,7 @@ pub enum OOPEX {
 }
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum PersonnelCosts {
 	/// _2001_(_2001_),
@@ -1690,6 +1775,7 @@ pub enum PersonnelCosts {
 }
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum FinanceCosts {
 	/// _3001_(_3001_),
@@ -1697,6 +1783,7 @@ pub enum FinanceCosts {
 }
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum ControlAccounts {
 	/// C60_0001_000D,
@@ -1717,6 +1804,7 @@ pub enum ControlAccounts {
 /// Assets
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum A {
 	CurrentAssets(CurrentAssets),
@@ -1728,6 +1816,7 @@ pub enum A {
 /// Liabilities
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum L {
 	CurrentLiabilities(CurrentLiabilities),
@@ -1736,6 +1825,7 @@ pub enum L {
 /// Equity
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum E {
 	ShareholdersEquity(ShareholdersEquity),
@@ -1745,10 +1835,13 @@ pub enum E {
 	RetainedEarnings(RetainedEarnings),
 	/// A special type of equity for the creation of the internal currency.
 	NetworkReserves,
+	/// The counterpart of the adjustments bringing the internal balance ledgers in line with the balances.
+	BalanceAdjustmentSuspense,
 }
 /// Income
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum I {
 	Sales(Sales),
@@ -1759,6 +1852,7 @@ pub enum I {
 /// Expenses
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum X {
 	OperatingExpenses(OPEX),
@@ -1769,6 +1863,7 @@ pub enum X {
 /// Profit And Loss
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum P {
 	Income(I),
@@ -1777,6 +1872,7 @@ pub enum P {
 /// Balance Sheet
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum B {
 	Assets(A),
@@ -1786,9 +1882,161 @@ pub enum B {
 /// Ledger
 #[allow(non_camel_case_types)]
 #[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[scale_info(capture_docs = "always")]
 pub enum Ledger {
 	BalanceSheet(B),
 	ProfitLoss(P),
 	ControlAccounts(ControlAccounts),
 }
+/// Class of Account
+#[derive(MaxEncodedLen, Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[scale_info(capture_docs = "always")]
+pub enum AccountClass {
+	/// Balance Sheet Assets
+	Asset,
+	/// Balance Sheet Liabilities
+	Liability,
+	/// Balance Sheet Equity
+	Equity,
+	/// Profit And Loss Income
+	Income,
+	/// Profit And Loss Expenses
+	Expense,
+	/// Control Accounts
+	Control,
+}
+
+impl AccountClass {
+	/// The side on which the balances of the class increase.
+	pub fn normal_balance(&self) -> Indicator {
+		match self {
+			Self::Asset | Self::Expense | Self::Control => Indicator::Debit,
+			Self::Liability | Self::Equity | Self::Income => Indicator::Credit,
+		}
+	}
+}
+
+impl Ledger {
+	/// The class of the ledger.
+	pub fn class(&self) -> AccountClass {
+		match self {
+			Ledger::BalanceSheet(B::Assets(_)) => AccountClass::Asset,
+			Ledger::BalanceSheet(B::Liabilities(_)) => AccountClass::Liability,
+			Ledger::BalanceSheet(B::Equity(_)) => AccountClass::Equity,
+			Ledger::ProfitLoss(P::Income(_)) => AccountClass::Income,
+			Ledger::ProfitLoss(P::Expenses(_)) => AccountClass::Expense,
+			Ledger::ControlAccounts(_) => AccountClass::Control,
+		}
+	}
+
+	/// Contra accounts reduce the other ledgers of their class, and so have the opposite normal balance.
+	/// For example the accumulated depreciation of an asset is credit-normal (B12_1002_C000).
+	/// Returns, discounts and recoveries in the profit and loss are not contra accounts: their account codes
+	/// keep the side of their class (P40_0004_C000).
+	pub fn is_contra(&self) -> bool {
+		matches!(
+			self,
+			Ledger::BalanceSheet(B::Assets(
+				A::CurrentAssets(
+					CurrentAssets::ImpairmentLoss(_) | CurrentAssets::AllowanceForDoubtfulDebts
+				) | A::CurrentAssetsCrypto(
+					CurrentAssetsCrypto::CoinImpairment(_) |
+						CurrentAssetsCrypto::TokenImpairment(_)
+				) | A::FixedAssets(FixedAssets::AccumulatedDepreciation(_)) |
+					A::IntagibleAssets(
+						IntagibleAssets::ImpairmentLoss |
+							IntagibleAssets::AccumulatedDepreciationIntangibles(_)
+					) | A::NonCurrentAssets(
+					NonCurrentAssets::ImpairmentLossOn(_) |
+						NonCurrentAssets::ImpairmentOfFixedAssets
+				)
+			)) | Ledger::BalanceSheet(B::Equity(
+				E::CapitalStock(CapitalStock::TreasuryShares) |
+					E::RetainedEarnings(RetainedEarnings::DividendPaid)
+			))
+		)
+	}
+
+	/// The side on which the balance of the ledger increases.
+	/// Assets, expenses and control accounts increase with a debit, liabilities, equity and income with a
+	/// credit, and contra accounts on the opposite side of their class.
+	pub fn normal_balance(&self) -> Indicator {
+		match self.is_contra() {
+			true => self.class().normal_balance().reverse(),
+			false => self.class().normal_balance(),
+		}
+	}
+
+	/// The side on which a balance of the ledger stands, and its absolute amount. The balance is signed as
+	/// an increase or a decrease of the ledger, so a negative balance is abnormal and stands on the side
+	/// opposite to the normal balance, for example an overdrawn bank account.
+	pub fn balance_side(&self, balance: LedgerBalance) -> (Indicator, LedgerBalance) {
+		match balance.is_negative() {
+			true => (self.normal_balance().reverse(), balance.saturating_abs()),
+			false => (self.normal_balance(), balance),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn classes_have_their_normal_balance() {
+		let bank =
+			Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::BankCurrentAccount)));
+		let wages = Ledger::BalanceSheet(B::Liabilities(L::CurrentLiabilities(
+			CurrentLiabilities::WagesPayable,
+		)));
+		let sales = Ledger::ProfitLoss(P::Income(I::Sales(Sales::SalesOfServices)));
+		let control = Ledger::ControlAccounts(ControlAccounts::SalesControl);
+
+		assert_eq!(bank.class(), AccountClass::Asset);
+		assert_eq!(bank.normal_balance(), Indicator::Debit);
+		assert_eq!(wages.class(), AccountClass::Liability);
+		assert_eq!(wages.normal_balance(), Indicator::Credit);
+		assert_eq!(
+			Ledger::BalanceSheet(B::Equity(E::NetworkReserves)).class(),
+			AccountClass::Equity
+		);
+		assert_eq!(sales.class(), AccountClass::Income);
+		assert_eq!(sales.normal_balance(), Indicator::Credit);
+		assert_eq!(control.class(), AccountClass::Control);
+		assert_eq!(control.normal_balance(), Indicator::Debit);
+	}
+
+	#[test]
+	fn contra_accounts_have_the_opposite_normal_balance() {
+		let depreciation = Ledger::BalanceSheet(B::Assets(A::FixedAssets(
+			FixedAssets::AccumulatedDepreciation(PPE::Buildings),
+		)));
+		let treasury_shares =
+			Ledger::BalanceSheet(B::Equity(E::CapitalStock(CapitalStock::TreasuryShares)));
+		let discounts = Ledger::ProfitLoss(P::Income(I::Sales(Sales::SalesDiscounts)));
+
+		assert!(depreciation.is_contra());
+		assert_eq!(depreciation.class(), AccountClass::Asset);
+		assert_eq!(depreciation.normal_balance(), Indicator::Credit);
+		assert!(treasury_shares.is_contra());
+		assert_eq!(treasury_shares.normal_balance(), Indicator::Debit);
+		assert!(!discounts.is_contra());
+		assert_eq!(discounts.normal_balance(), Indicator::Credit);
+		assert!(!Ledger::BalanceSheet(B::Assets(A::FixedAssets(FixedAssets::PropPlantEquip(
+			PPE::Buildings
+		))))
+		.is_contra());
+	}
+
+	#[test]
+	fn negative_balances_stand_on_the_opposite_side() {
+		let bank =
+			Ledger::BalanceSheet(B::Assets(A::CurrentAssets(CurrentAssets::BankCurrentAccount)));
+
+		assert_eq!(bank.balance_side(100), (Indicator::Debit, 100));
+		assert_eq!(bank.balance_side(-100), (Indicator::Credit, 100));
+		assert_eq!(bank.balance_side(0), (Indicator::Debit, 0));
+	}
+}
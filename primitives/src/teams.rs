@@ -37,6 +37,8 @@
 
 use frame_support::pallet_prelude::*;
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 
 pub trait Validating<AccountId, Hash> {
 	fn is_project_owner(o: AccountId, h: Hash) -> bool;
@@ -44,13 +46,97 @@ pub trait Validating<AccountId, Hash> {
 	fn is_project_valid(h: Hash) -> bool;
 
 	fn is_owner_and_project_valid(o: AccountId, h: Hash) -> bool;
+
+	/// Whether the account is in the team of the project, the owner included.
+	fn is_project_member(o: AccountId, h: Hash) -> bool;
+
+	/// The role of the account in the team of the project, if it is in it.
+	fn role_of(o: AccountId, h: Hash) -> Option<ProjectRole>;
+
+	/// Whether the account is banned from the project.
+	fn is_banned(o: AccountId, h: Hash) -> bool;
 }
 
-/// Reference supplied externally.
-//TODO make an enum
-pub type ProjectStatus = u16;
+/// The lifecycle of a project. Only open and reopened projects accept work.
+#[derive(MaxEncodedLen, Debug, Clone, Copy, Decode, Encode, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum ProjectStatus {
+	Open,
+	Reopened,
+	OnHold,
+	Abandoned,
+	Closed,
+	Archived,
+	Deleted,
+}
+
+impl ProjectStatus {
+	/// Every status, in the order of their encoding.
+	pub const ALL: [ProjectStatus; 7] = [
+		ProjectStatus::Open,
+		ProjectStatus::Reopened,
+		ProjectStatus::OnHold,
+		ProjectStatus::Abandoned,
+		ProjectStatus::Closed,
+		ProjectStatus::Archived,
+		ProjectStatus::Deleted,
+	];
+}
 
-#[derive(PartialEq, Eq, Clone, Debug, Encode, Decode, TypeInfo)]
+/// Whether a project can move from the status `from` to the status `to`.
+///
+/// A project that is in progress can be put on hold, abandoned, closed or deleted. A project that is on
+/// hold, abandoned or closed can be reopened, and an abandoned or closed project can be archived. An archived
+/// project can only be deleted, and a deleted project cannot change. A status never moves to itself.
+pub fn is_transition_allowed(from: ProjectStatus, to: ProjectStatus) -> bool {
+	use ProjectStatus::*;
+
+	match from {
+		Open | Reopened => matches!(to, OnHold | Abandoned | Closed | Deleted),
+		OnHold => matches!(to, Reopened | Abandoned | Closed | Deleted),
+		Abandoned | Closed => matches!(to, Reopened | Archived | Deleted),
+		Archived => matches!(to, Deleted),
+		Deleted => false,
+	}
+}
+
+/// The role of an account in the team of a project, from the highest to the lowest.
+#[derive(MaxEncodedLen, Debug, Clone, Copy, Decode, Encode, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum ProjectRole {
+	/// The owner of the project. There is exactly one, and the role is never granted by invitation.
+	Owner,
+	/// Invites, removes and bans the members below them.
+	Manager,
+	/// Works on the project.
+	Member,
+	/// Follows the project without working on it.
+	Viewer,
+}
+
+impl ProjectRole {
+	fn rank(self) -> u8 {
+		match self {
+			ProjectRole::Owner => 3,
+			ProjectRole::Manager => 2,
+			ProjectRole::Member => 1,
+			ProjectRole::Viewer => 0,
+		}
+	}
+
+	/// Whether the role is above `other`.
+	pub fn outranks(self, other: ProjectRole) -> bool {
+		self.rank() > other.rank()
+	}
+
+	/// Whether the role invites, removes and bans members.
+	pub fn manages_members(self) -> bool {
+		matches!(self, ProjectRole::Owner | ProjectRole::Manager)
+	}
+}
+
+/// A project that has been deleted. Its hash cannot be registered again.
+#[derive(PartialEq, Eq, Clone, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
 pub struct DeletedProject<AccountId, ProjectStatus> {
 	pub owned_by: AccountId,
 	pub deleted_by: AccountId,
@@ -70,4 +156,94 @@ impl<AccountId, Hash> Validating<AccountId, Hash> for () {
 	fn is_owner_and_project_valid(_o: AccountId, _h: Hash) -> bool {
 		unimplemented!("Used as a mock, shouldn't be called")
 	}
+
+	fn is_project_member(_o: AccountId, _h: Hash) -> bool {
+		unimplemented!("Used as a mock, shouldn't be called")
+	}
+
+	fn role_of(_o: AccountId, _h: Hash) -> Option<ProjectRole> {
+		unimplemented!("Used as a mock, shouldn't be called")
+	}
+
+	fn is_banned(_o: AccountId, _h: Hash) -> bool {
+		unimplemented!("Used as a mock, shouldn't be called")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{ProjectStatus::*, *};
+
+	/// The allowed transitions, written out pair by pair.
+	const ALLOWED: [(ProjectStatus, ProjectStatus); 19] = [
+		(Open, OnHold),
+		(Open, Abandoned),
+		(Open, Closed),
+		(Open, Deleted),
+		(Reopened, OnHold),
+		(Reopened, Abandoned),
+		(Reopened, Closed),
+		(Reopened, Deleted),
+		(OnHold, Reopened),
+		(OnHold, Abandoned),
+		(OnHold, Closed),
+		(OnHold, Deleted),
+		(Abandoned, Reopened),
+		(Abandoned, Archived),
+		(Abandoned, Deleted),
+		(Closed, Reopened),
+		(Closed, Archived),
+		(Closed, Deleted),
+		(Archived, Deleted),
+	];
+
+	#[test]
+	fn all_lists_every_status_in_the_order_of_their_encoding() {
+		for (index, status) in ProjectStatus::ALL.into_iter().enumerate() {
+			// Fails to compile when a status is added without being listed here.
+			match status {
+				Open | Reopened | OnHold | Abandoned | Closed | Archived | Deleted => (),
+			}
+			assert_eq!(status.encode(), vec![index as u8]);
+		}
+	}
+
+	#[test]
+	fn only_the_listed_transitions_are_allowed() {
+		for from in ProjectStatus::ALL {
+			for to in ProjectStatus::ALL {
+				assert_eq!(
+					is_transition_allowed(from, to),
+					ALLOWED.contains(&(from, to)),
+					"transition from {from:?} to {to:?}"
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn every_status_but_deleted_can_be_deleted() {
+		for status in ProjectStatus::ALL {
+			assert_eq!(is_transition_allowed(status, Deleted), status != Deleted);
+		}
+	}
+
+	#[test]
+	fn a_deleted_project_cannot_change() {
+		assert!(ProjectStatus::ALL.into_iter().all(|to| !is_transition_allowed(Deleted, to)));
+	}
+
+	#[test]
+	fn roles_outrank_the_roles_below_them() {
+		use ProjectRole::*;
+
+		let roles = [Owner, Manager, Member, Viewer];
+		for (i, role) in roles.into_iter().enumerate() {
+			for (j, other) in roles.into_iter().enumerate() {
+				assert_eq!(role.outranks(other), i < j, "{role:?} and {other:?}");
+			}
+		}
+		assert!(Owner.manages_members() && Manager.manages_members());
+		assert!(!Member.manages_members() && !Viewer.manages_members());
+	}
 }
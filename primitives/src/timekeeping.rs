@@ -39,8 +39,11 @@ use frame_support::pallet_prelude::*;
 use scale_info::TypeInfo;
 
 pub trait Validating<AccountId, Hash> {
+	/// Whether the account is the worker of the time record.
 	fn is_time_record_owner(o: AccountId, h: Hash) -> bool;
 
+	/// Archives (`a` true) or restores (`a` false) a time record of the worker `o`, returning whether it
+	/// did.
 	fn validate_and_archive(o: AccountId, h: Hash, a: bool) -> bool;
 }
 
@@ -75,7 +78,9 @@ pub type ReasonCodeType = u16;
 /// Ban status (default is false).
 pub type BanStatus = bool;
 
-#[derive(Clone, Copy, Debug, PartialEq, Encode, Decode, TypeInfo)]
+/// The review status of a time record. The worker keeps a record as a draft or submits it, and the owner of the
+/// project accepts, rejects or disputes it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
 pub enum StatusOfTimeRecord {
 	Draft,
 	Submitted,
@@ -87,15 +92,15 @@ pub enum StatusOfTimeRecord {
 }
 
 /// Reason why the code changes.
-#[derive(PartialEq, Eq, Clone, Debug, Encode, Decode, Default, TypeInfo)]
+#[derive(PartialEq, Eq, Clone, Debug, Encode, Decode, Default, MaxEncodedLen, TypeInfo)]
 pub struct ReasonCodeStruct(pub ReasonCode, pub ReasonCodeType);
 
 /// Status of the code changes.
-#[derive(PartialEq, Eq, Clone, Debug, Encode, Decode, Default, TypeInfo)]
-pub struct BannedStruct(BanStatus, ReasonCodeStruct);
+#[derive(PartialEq, Eq, Clone, Debug, Encode, Decode, Default, MaxEncodedLen, TypeInfo)]
+pub struct BannedStruct(pub BanStatus, pub ReasonCodeStruct);
 
 /// The individual time record.
-#[derive(PartialEq, Eq, Copy, Clone, Debug, Encode, Decode, Default, TypeInfo)]
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Encode, Decode, Default, MaxEncodedLen, TypeInfo)]
 pub struct Timekeeper<
 	AccountId,
 	ReferenceHash,
@@ -115,6 +115,9 @@ pub type UncheckedExtrinsic =
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
 
+/// Migrations to apply on runtime upgrade.
+pub type Migrations = (pallet_accounting::migration::v2::ReconcileBalancesMultiBlock<Runtime>,);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -122,6 +125,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 /// Handles converting a weight scalar to a fee value, based on the scale and granularity of the
@@ -671,16 +675,15 @@ construct_runtime!(
 		// TemplatePallet: pallet_template::{Pallet, Call, Storage, Event<T>}  = 40,
 
 		// Totem
-		// Accounting: pallet_accounting::{Pallet, Storage, Event<T>, Config<T>} = 40,
-		Accounting: pallet_accounting::{Pallet, Storage, Config<T>} = 40,
+		Accounting: pallet_accounting::{Pallet, Call, Storage, Event<T>, Config<T>} = 40,
 		// Archive: pallet_archive::{Pallet, Call, Storage, Event<T>} = 41,
 		// Bonsai: pallet_bonsai::{Pallet, Call, Storage, Event<T>} = 42,
 		// Escrow: pallet_escrow::{Pallet, Call, Storage, Event<T>} = 43,
 		// Funding: pallet_funding::{Pallet, Call, Storage, Event<T>, Config<T>} = 44,
 		// Orders: pallet_orders::{Pallet, Call, Storage, Event<T>} = 45,
 		// Prefunding: pallet_prefunding::{Pallet, Call, Storage, Event<T>} = 46,
-		// Teams: pallet_teams::{Pallet, Call, Storage, Event<T>} = 47,
-		// Timekeeping: pallet_timekeeping::{Pallet, Call, Storage, Event<T>} = 48,
+		Teams: pallet_teams::{Pallet, Call, Storage, Event<T>} = 47,
+		Timekeeping: pallet_timekeeping::{Pallet, Call, Storage, Event<T>} = 48,
 		// Transfer: pallet_transfer::{Pallet, Call, Storage, Event<T>} = 49,
 
 		// Spambot: cumulus_ping::{Pallet, Call, Storage, Event<T>} = 99,
@@ -695,8 +698,11 @@ extern crate frame_benchmarking;
 mod benches {
 	define_benchmarks!(
 		[frame_system, SystemBench::<Runtime>]
+		[pallet_accounting, Accounting]
 		[pallet_balances_totem, Balances]
 		[pallet_session, SessionBench::<Runtime>]
+		[pallet_teams, Teams]
+		[pallet_timekeeping, Timekeeping]
 		[pallet_timestamp, Timestamp]
 		[pallet_collator_selection, CollatorSelection]
 		[cumulus_pallet_xcmp_queue, XcmpQueue]
@@ -821,6 +827,38 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_accounting_rpc_runtime_api::AccountingApi<Block, AccountId, Hash, BlockNumber> for Runtime {
+		fn balance(
+			account: AccountId,
+			ledger: pallet_accounting_rpc_runtime_api::Ledger,
+		) -> Option<pallet_accounting_rpc_runtime_api::LedgerBalance> {
+			Accounting::balance_by_ledger(account, ledger)
+		}
+		fn balances(
+			account: AccountId,
+		) -> Vec<(pallet_accounting_rpc_runtime_api::Ledger, pallet_accounting_rpc_runtime_api::LedgerBalance)> {
+			Accounting::balances_of(&account)
+		}
+		fn trial_balance(account: AccountId) -> pallet_accounting_rpc_runtime_api::TrialBalance {
+			Accounting::trial_balance(&account)
+		}
+		fn global_ledger(
+		) -> Vec<(pallet_accounting_rpc_runtime_api::Ledger, pallet_accounting_rpc_runtime_api::LedgerBalance)> {
+			Accounting::global_ledger_balances()
+		}
+		fn postings(
+			account: AccountId,
+			ledger: pallet_accounting_rpc_runtime_api::Ledger,
+			start_after: Option<pallet_accounting_rpc_runtime_api::PostingIndex>,
+			limit: u32,
+		) -> Vec<(
+			pallet_accounting_rpc_runtime_api::PostingIndex,
+			pallet_accounting_rpc_runtime_api::Detail<AccountId, Hash, BlockNumber>,
+		)> {
+			Accounting::postings(account, ledger, start_after, limit)
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)
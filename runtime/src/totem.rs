@@ -1,11 +1,16 @@
 use super::*;
+use frame_support::traits::ConstBool;
 use totem_common::converter::Converter;
 
 impl pallet_accounting::Config for Runtime {
-	// type RuntimeEvent = RuntimeEvent;
+	type RuntimeEvent = RuntimeEvent;
 	type AccountingConverter = Converter;
 	type Currency = Balances;
-	type RandomThing = RandomnessCollectiveFlip;
+	type EnforcePartyBalance = ConstBool<true>;
+	type MaxJournalLines = ConstU32<50>;
+	type MaxDeferredPostingsPerBlock = ConstU32<20>;
+	type MaxReconciledAccountsPerBlock = ConstU32<50>;
+	type WeightInfo = pallet_accounting::weights::SubstrateWeight<Runtime>;
 }
 
 // impl pallet_archive::Config for Runtime {
@@ -48,14 +53,22 @@ impl pallet_accounting::Config for Runtime {
 //     type Escrowable = pallet_escrow::Pallet<Self>;
 // }
 
-// impl pallet_teams::Config for Runtime {
-//     type RuntimeEvent = RuntimeEvent;
-// }
+impl pallet_teams::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxProjectsPerOwner = ConstU32<500>;
+	type MaxMembersPerProject = ConstU32<100>;
+	type WeightInfo = pallet_teams::weights::SubstrateWeight<Runtime>;
+}
 
-// impl pallet_timekeeping::Config for Runtime {
-//     type RuntimeEvent = RuntimeEvent;
-//     type Projects = Teams;
-// }
+impl pallet_timekeeping::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Projects = Teams;
+	type Accounting = Accounting;
+	type Currency = Balances;
+	type TimekeepingConverter = Converter;
+	type BlocksPerHour = ConstU64<{ HOURS as u64 }>;
+	type WeightInfo = pallet_timekeeping::weights::SubstrateWeight<Runtime>;
+}
 
 // impl pallet_transfer::Config for Runtime {
 //     type RuntimeEvent = RuntimeEvent;
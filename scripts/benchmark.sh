@@ -16,7 +16,10 @@ benchmarkOutput=./weights
 # Set the pallets to benchmark
 # Should include all pallets in the runtime
 pallets=(
+    pallet_accounting
     pallet_balances_totem
+    pallet_teams
+    pallet_timekeeping
 )
 
 # Loop through the pallets and run the benchmark